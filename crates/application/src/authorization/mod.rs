@@ -0,0 +1,5 @@
+// crates/application/src/authorization/mod.rs
+
+mod policy;
+
+pub use policy::{Actor, Policy};
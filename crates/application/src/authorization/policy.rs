@@ -0,0 +1,161 @@
+// crates/application/src/authorization/policy.rs
+
+use domain::comments::Comment;
+use domain::common::error::DomainError;
+use domain::posts::Post;
+use domain::users::Role;
+use uuid::Uuid;
+
+/// The authenticated user performing an action
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub user_id: Uuid,
+    pub role: Role,
+    pub email_verified: bool,
+}
+
+impl Actor {
+    pub fn new(user_id: Uuid, role: Role, email_verified: bool) -> Self {
+        Self {
+            user_id,
+            role,
+            email_verified,
+        }
+    }
+}
+
+/// Authorization rules shared by the use cases
+pub struct Policy;
+
+impl Policy {
+    /// Creating posts and comments may be limited to users with a verified email
+    pub fn create_content(actor: &Actor, require_verified_email: bool) -> Result<(), DomainError> {
+        if require_verified_email && !actor.email_verified {
+            return Err(DomainError::Forbidden(
+                "Verify your email address before posting".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Only the author may edit a post
+    pub fn edit_post(actor: &Actor, post: &Post) -> Result<(), DomainError> {
+        post.ensure_author(actor.user_id)
+    }
+
+    /// The author, moderators and admins may delete a post
+    pub fn delete_post(actor: &Actor, post: &Post) -> Result<(), DomainError> {
+        if actor.role.can_moderate() {
+            return Ok(());
+        }
+        post.ensure_author(actor.user_id)
+    }
+
+    /// The author, moderators and admins may read a post's revision history
+    pub fn view_post_history(actor: &Actor, post: &Post) -> Result<(), DomainError> {
+        if actor.role.can_moderate() || post.author_id == actor.user_id {
+            return Ok(());
+        }
+        Err(DomainError::Forbidden(
+            "Only the author or a moderator can view this post's history".into(),
+        ))
+    }
+
+    /// The author, moderators and admins may delete a comment
+    pub fn delete_comment(actor: &Actor, comment: &Comment) -> Result<(), DomainError> {
+        if actor.role.can_moderate() || comment.author_id == actor.user_id {
+            return Ok(());
+        }
+        Err(DomainError::Forbidden(
+            "Only the author or a moderator can delete this comment".into(),
+        ))
+    }
+
+    /// Only admins may change roles, and not their own (so the last admin can't lock
+    /// everyone out)
+    pub fn change_role(actor: &Actor, target_user_id: Uuid) -> Result<(), DomainError> {
+        if actor.role != Role::Admin {
+            return Err(DomainError::Forbidden(
+                "Only admins can change roles".into(),
+            ));
+        }
+        if actor.user_id == target_user_id {
+            return Err(DomainError::Forbidden(
+                "Admins cannot change their own role".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::comments::CommentContent;
+    use domain::posts::{PostContent, PostTitle};
+
+    fn post(author_id: Uuid) -> Post {
+        Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            author_id,
+        )
+    }
+
+    fn comment(author_id: Uuid) -> Comment {
+        Comment::new(
+            CommentContent::new("Comment").unwrap(),
+            Uuid::new_v4(),
+            author_id,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_moderator_can_delete_but_not_edit_others_posts() {
+        let post = post(Uuid::new_v4());
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+
+        assert!(Policy::delete_post(&moderator, &post).is_ok());
+        assert!(matches!(
+            Policy::edit_post(&moderator, &post).unwrap_err(),
+            DomainError::Forbidden(_)
+        ));
+    }
+
+    #[test]
+    fn test_delete_comment() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+        let other = Actor::new(Uuid::new_v4(), Role::User, true);
+        let comment = comment(author.user_id);
+
+        assert!(Policy::delete_comment(&author, &comment).is_ok());
+        assert!(Policy::delete_comment(&moderator, &comment).is_ok());
+        assert!(matches!(
+            Policy::delete_comment(&other, &comment).unwrap_err(),
+            DomainError::Forbidden(_)
+        ));
+    }
+
+    #[test]
+    fn test_create_content_requires_verified_email_when_enabled() {
+        let unverified = Actor::new(Uuid::new_v4(), Role::User, false);
+
+        assert!(Policy::create_content(&unverified, false).is_ok());
+        assert!(matches!(
+            Policy::create_content(&unverified, true).unwrap_err(),
+            DomainError::Forbidden(_)
+        ));
+    }
+
+    #[test]
+    fn test_change_role() {
+        let admin = Actor::new(Uuid::new_v4(), Role::Admin, true);
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+
+        assert!(Policy::change_role(&admin, Uuid::new_v4()).is_ok());
+        assert!(Policy::change_role(&admin, admin.user_id).is_err());
+        assert!(Policy::change_role(&moderator, Uuid::new_v4()).is_err());
+    }
+}
@@ -34,17 +34,33 @@ where
         post_id: Uuid,
         author_id: Uuid,
     ) -> Result<CommentResponse, DomainError> {
-        // Verify that the post exists
+        // Verify that the post exists and the commenter can see it
         self.post_repository
             .find_by_id(post_id)
             .await?
+            .filter(|post| post.is_visible_to(Some(author_id)))
             .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
 
+        // Verify that the parent comment exists and belongs to the same post
+        if let Some(parent_id) = request.parent_id {
+            let parent = self
+                .comment_repository
+                .find_by_id(parent_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound("Parent comment not found".into()))?;
+
+            if parent.post_id != post_id {
+                return Err(DomainError::Validation(
+                    "Parent comment belongs to a different post".into(),
+                ));
+            }
+        }
+
         // Validate content
         let content = CommentContent::new(request.content)?;
 
         // Create comment
-        let comment = Comment::new(content, post_id, author_id);
+        let comment = Comment::new(content, post_id, author_id, request.parent_id);
 
         // Save comment
         self.comment_repository.save(comment.clone()).await?;
@@ -54,6 +70,8 @@ where
             content: comment.content.as_str().to_string(),
             post_id: comment.post_id.to_string(),
             author_id: comment.author_id.to_string(),
+            author: None,
+            parent_id: comment.parent_id.map(|id| id.to_string()),
             created_at: comment.created_at.to_rfc3339(),
         })
     }
@@ -62,121 +80,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
-    use domain::posts::{Post, PostContent, PostTitle};
-    use std::sync::{Arc, Mutex};
-
-    struct MockCommentRepository {
-        comments: Arc<Mutex<Vec<Comment>>>,
-    }
-
-    impl MockCommentRepository {
-        fn new() -> Self {
-            Self {
-                comments: Arc::new(Mutex::new(Vec::new())),
-            }
-        }
-    }
+    use crate::test_support::{MockCommentRepository, MockPostRepository};
 
-    impl Clone for MockCommentRepository {
-        fn clone(&self) -> Self {
-            Self {
-                comments: Arc::clone(&self.comments),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl CommentRepository for MockCommentRepository {
-        async fn save(&self, comment: Comment) -> Result<(), DomainError> {
-            self.comments.lock().unwrap().push(comment);
-            Ok(())
-        }
-
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, DomainError> {
-            Ok(self
-                .comments
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|c| c.id == id)
-                .cloned())
-        }
-
-        async fn find_by_post(
-            &self,
-            post_id: Uuid,
-            _limit: i64,
-            _offset: i64,
-        ) -> Result<Vec<Comment>, DomainError> {
-            Ok(self
-                .comments
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|c| c.post_id == post_id)
-                .cloned()
-                .collect())
-        }
-    }
-
-    struct MockPostRepository {
-        posts: Arc<Mutex<Vec<Post>>>,
-    }
-
-    impl MockPostRepository {
-        fn new_with_post(post: Post) -> Self {
-            Self {
-                posts: Arc::new(Mutex::new(vec![post])),
-            }
-        }
-    }
-
-    impl Clone for MockPostRepository {
-        fn clone(&self) -> Self {
-            Self {
-                posts: Arc::clone(&self.posts),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl PostRepository for MockPostRepository {
-        async fn save(&self, post: Post) -> Result<(), DomainError> {
-            self.posts.lock().unwrap().push(post);
-            Ok(())
-        }
-
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError> {
-            Ok(self
-                .posts
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|p| p.id == id)
-                .cloned())
-        }
-
-        async fn find_all(&self, _limit: i64, _offset: i64) -> Result<Vec<Post>, DomainError> {
-            Ok(self.posts.lock().unwrap().clone())
-        }
-
-        async fn find_by_author(
-            &self,
-            author_id: Uuid,
-            _limit: i64,
-            _offset: i64,
-        ) -> Result<Vec<Post>, DomainError> {
-            Ok(self
-                .posts
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|p| p.author_id == author_id)
-                .cloned()
-                .collect())
-        }
-    }
+    use domain::posts::{Post, PostContent, PostTitle};
 
     #[tokio::test]
     async fn test_create_comment_success() {
@@ -187,12 +93,13 @@ mod tests {
         let post_id = post.id;
 
         let use_case = CreateCommentUseCase::new(
-            MockCommentRepository::new(),
-            MockPostRepository::new_with_post(post),
+            MockCommentRepository::default(),
+            MockPostRepository::with_post(post),
         );
 
         let request = CreateCommentRequest {
             content: "This is a test comment".to_string(),
+            parent_id: None,
         };
 
         let result = use_case.execute(request, post_id, author_id).await;
@@ -204,6 +111,29 @@ mod tests {
         assert_eq!(response.author_id, author_id.to_string());
     }
 
+    #[tokio::test]
+    async fn test_create_comment_on_others_draft_is_not_found() {
+        let post = Post::new(
+            PostTitle::new("Test Post").unwrap(),
+            PostContent::new("Test content").unwrap(),
+            Uuid::new_v4(),
+        );
+        let post_id = post.id;
+
+        let use_case = CreateCommentUseCase::new(
+            MockCommentRepository::default(),
+            MockPostRepository::with_post(post),
+        );
+
+        let request = CreateCommentRequest {
+            content: "Early comment".to_string(),
+            parent_id: None,
+        };
+
+        let result = use_case.execute(request, post_id, Uuid::new_v4()).await;
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+
     #[tokio::test]
     async fn test_create_comment_empty_content() {
         let author_id = Uuid::new_v4();
@@ -213,16 +143,91 @@ mod tests {
         let post_id = post.id;
 
         let use_case = CreateCommentUseCase::new(
-            MockCommentRepository::new(),
-            MockPostRepository::new_with_post(post),
+            MockCommentRepository::default(),
+            MockPostRepository::with_post(post),
         );
 
         let request = CreateCommentRequest {
             content: "".to_string(),
+            parent_id: None,
         };
 
         let result = use_case.execute(request, post_id, author_id).await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
     }
+
+    #[tokio::test]
+    async fn test_create_reply_success() {
+        let author_id = Uuid::new_v4();
+        let post = Post::new(
+            PostTitle::new("Test Post").unwrap(),
+            PostContent::new("Test content").unwrap(),
+            author_id,
+        );
+        let post_id = post.id;
+
+        let use_case = CreateCommentUseCase::new(
+            MockCommentRepository::default(),
+            MockPostRepository::with_post(post),
+        );
+
+        let parent = use_case
+            .execute(
+                CreateCommentRequest {
+                    content: "Parent".to_string(),
+                    parent_id: None,
+                },
+                post_id,
+                author_id,
+            )
+            .await
+            .unwrap();
+        let parent_id = Uuid::parse_str(&parent.id).unwrap();
+
+        let reply = use_case
+            .execute(
+                CreateCommentRequest {
+                    content: "Reply".to_string(),
+                    parent_id: Some(parent_id),
+                },
+                post_id,
+                author_id,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reply.parent_id, Some(parent.id));
+    }
+
+    #[tokio::test]
+    async fn test_create_reply_parent_on_other_post() {
+        let author_id = Uuid::new_v4();
+        let post = Post::new(
+            PostTitle::new("Test Post").unwrap(),
+            PostContent::new("Test content").unwrap(),
+            author_id,
+        );
+        let post_id = post.id;
+
+        let comments = MockCommentRepository::default();
+        let foreign_parent = Comment::new(
+            CommentContent::new("Elsewhere").unwrap(),
+            Uuid::new_v4(),
+            author_id,
+            None,
+        );
+        let foreign_parent_id = foreign_parent.id;
+        comments.save(foreign_parent).await.unwrap();
+
+        let use_case = CreateCommentUseCase::new(comments, MockPostRepository::with_post(post));
+
+        let request = CreateCommentRequest {
+            content: "Reply".to_string(),
+            parent_id: Some(foreign_parent_id),
+        };
+
+        let result = use_case.execute(request, post_id, author_id).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
 }
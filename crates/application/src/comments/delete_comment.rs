@@ -0,0 +1,102 @@
+// crates/application/src/comments/delete_comment.rs
+
+use domain::comments::CommentRepository;
+use domain::common::error::DomainError;
+use uuid::Uuid;
+
+use crate::authorization::{Actor, Policy};
+
+pub struct DeleteCommentUseCase<R>
+where
+    R: CommentRepository,
+{
+    comment_repository: R,
+}
+
+impl<R> DeleteCommentUseCase<R>
+where
+    R: CommentRepository,
+{
+    pub fn new(comment_repository: R) -> Self {
+        Self { comment_repository }
+    }
+
+    pub async fn execute(&self, comment_id: Uuid, actor: &Actor) -> Result<(), DomainError> {
+        let comment = self
+            .comment_repository
+            .find_by_id(comment_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Comment not found".into()))?;
+
+        // The author or a moderator may delete
+        Policy::delete_comment(actor, &comment)?;
+
+        self.comment_repository.delete(comment.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockCommentRepository;
+    use domain::comments::{Comment, CommentContent};
+    use domain::users::Role;
+
+    fn setup(author_id: Uuid) -> (MockCommentRepository, Comment) {
+        let comment = Comment::new(
+            CommentContent::new("Comment").unwrap(),
+            Uuid::new_v4(),
+            author_id,
+            None,
+        );
+        let comments = MockCommentRepository::default();
+        comments.comments.lock().unwrap().push(comment.clone());
+        (comments, comment)
+    }
+
+    #[tokio::test]
+    async fn test_author_can_delete_comment() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let (comments, comment) = setup(author.user_id);
+        let use_case = DeleteCommentUseCase::new(comments.clone());
+
+        use_case.execute(comment.id, &author).await.unwrap();
+
+        assert!(comments.comments.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_other_user_cannot_delete_comment() {
+        let (comments, comment) = setup(Uuid::new_v4());
+        let use_case = DeleteCommentUseCase::new(comments.clone());
+        let other = Actor::new(Uuid::new_v4(), Role::User, true);
+
+        let result = use_case.execute(comment.id, &other).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+        assert_eq!(comments.comments.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_moderator_and_admin_can_delete_others_comments() {
+        for role in [Role::Moderator, Role::Admin] {
+            let (comments, comment) = setup(Uuid::new_v4());
+            let use_case = DeleteCommentUseCase::new(comments.clone());
+            let actor = Actor::new(Uuid::new_v4(), role, true);
+
+            use_case.execute(comment.id, &actor).await.unwrap();
+
+            assert!(comments.comments.lock().unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_comment_not_found() {
+        let use_case = DeleteCommentUseCase::new(MockCommentRepository::default());
+        let actor = Actor::new(Uuid::new_v4(), Role::Admin, true);
+
+        let result = use_case.execute(Uuid::new_v4(), &actor).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+}
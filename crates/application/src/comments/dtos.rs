@@ -1,11 +1,16 @@
 // crates/application/src/comments/dtos.rs
 
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::users::ProfileResponse;
 
 // Create Comment DTOs
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateCommentRequest {
     pub content: String,
+    #[serde(default)]
+    pub parent_id: Option<Uuid>, // Set when replying to another comment
 }
 
 #[derive(Debug, Serialize)]
@@ -14,6 +19,9 @@ pub struct CommentResponse {
     pub content: String,
     pub post_id: String,
     pub author_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<ProfileResponse>, // Filled in by read use cases
+    pub parent_id: Option<String>,
     pub created_at: String,
 }
 
@@ -21,5 +29,32 @@ pub struct CommentResponse {
 #[derive(Debug, Serialize)]
 pub struct ListCommentsResponse {
     pub comments: Vec<CommentResponse>,
-    pub count: usize,
+    pub count: usize, // On this page
+    pub total: i64,   // Across all pages
+    pub limit: i64,
+    pub offset: Option<i64>,         // Unset when paging by cursor
+    pub has_more: bool,              // Whether pages follow this one
+    pub next_cursor: Option<String>, // Pass as `after` for the following page
+    pub prev_cursor: Option<String>, // Pass as `before` for the preceding page
+}
+
+// Threaded Comments Response
+#[derive(Debug, Serialize)]
+pub struct CommentNode {
+    #[serde(flatten)]
+    pub comment: CommentResponse,
+    pub depth: usize,
+    pub replies: Vec<CommentNode>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommentThreadResponse {
+    pub comments: Vec<CommentNode>,
+    pub count: usize, // Total number of comments in the returned threads
+    pub total: i64,   // Threads across all pages
+    pub limit: i64,
+    pub offset: Option<i64>,         // Unset when paging by cursor
+    pub has_more: bool,              // Whether threads follow these
+    pub next_cursor: Option<String>, // Pass as `after` for the following threads
+    pub prev_cursor: Option<String>, // Pass as `before` for the preceding threads
 }
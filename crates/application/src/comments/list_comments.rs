@@ -1,41 +1,449 @@
 // crates/application/src/comments/list_comments.rs
 
-use domain::comments::CommentRepository;
+use std::collections::{HashMap, HashSet};
+
+use domain::comments::{Comment, CommentRepository};
 use domain::common::error::DomainError;
+use domain::common::pagination::{PageKey, PageRequest};
+use domain::posts::PostRepository;
+use domain::users::UserRepository;
 use uuid::Uuid;
 
-use super::dtos::{CommentResponse, ListCommentsResponse};
+use super::dtos::{CommentNode, CommentResponse, CommentThreadResponse, ListCommentsResponse};
+use crate::pagination::{Page, paginate};
+use crate::users::{ProfileResponse, load_profiles};
+
+type Authors = HashMap<Uuid, ProfileResponse>;
 
-pub struct ListCommentsUseCase<R>
+pub struct ListCommentsUseCase<R, P, U>
 where
     R: CommentRepository,
+    P: PostRepository,
+    U: UserRepository,
 {
     comment_repository: R,
+    post_repository: P,
+    user_repository: U,
 }
 
-impl<R> ListCommentsUseCase<R>
+impl<R, P, U> ListCommentsUseCase<R, P, U>
 where
     R: CommentRepository,
+    P: PostRepository,
+    U: UserRepository,
 {
-    pub fn new(comment_repository: R) -> Self {
-        Self { comment_repository }
+    pub fn new(comment_repository: R, post_repository: P, user_repository: U) -> Self {
+        Self {
+            comment_repository,
+            post_repository,
+            user_repository,
+        }
     }
 
-    pub async fn execute(&self, post_id: Uuid, limit: i64, offset: i64) -> Result<ListCommentsResponse, DomainError> {
-        let comments = self.comment_repository.find_by_post(post_id, limit, offset).await?;
+    /// A page of the post's comments as seen by `viewer`, oldest first. Only the author
+    /// can read the comments of a post that is not published.
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        viewer: Option<Uuid>,
+        request: PageRequest,
+    ) -> Result<ListCommentsResponse, DomainError> {
+        self.ensure_post_visible(post_id, viewer).await?;
+
+        let comments = self
+            .comment_repository
+            .find_by_post(post_id, &request.with_lookahead())
+            .await?;
+        let total = self.comment_repository.count_by_post(post_id).await?;
+        let page = paginate(&request, comments, |comment| {
+            PageKey::new(comment.created_at, comment.id)
+        });
+        let comments = page.items;
         let count = comments.len();
+        let authors = self.load_authors(&comments).await?;
 
         let comments = comments
             .into_iter()
-            .map(|comment| CommentResponse {
-                id: comment.id.to_string(),
-                content: comment.content.as_str().to_string(),
-                post_id: comment.post_id.to_string(),
-                author_id: comment.author_id.to_string(),
-                created_at: comment.created_at.to_rfc3339(),
+            .map(|comment| to_response(comment, &authors))
+            .collect();
+
+        Ok(ListCommentsResponse {
+            comments,
+            count,
+            total,
+            limit: request.limit(),
+            offset: request.offset(),
+            has_more: page.has_more,
+            next_cursor: page.next_cursor,
+            prev_cursor: page.prev_cursor,
+        })
+    }
+
+    /// List comments as reply threads, with the same visibility as [`Self::execute`].
+    ///
+    /// `page` is an offset or cursor over top-level comments, whose cursors the response
+    /// carries. Replies nested deeper than `max_depth` (top-level comments are depth 0)
+    /// are flattened into the deepest allowed level, in chronological order.
+    pub async fn execute_tree(
+        &self,
+        post_id: Uuid,
+        viewer: Option<Uuid>,
+        page: PageRequest,
+        max_depth: usize,
+    ) -> Result<CommentThreadResponse, DomainError> {
+        self.ensure_post_visible(post_id, viewer).await?;
+
+        let comments = self
+            .comment_repository
+            .find_thread(post_id, &page.with_lookahead())
+            .await?;
+        let total = self.comment_repository.count_threads(post_id).await?;
+        let comments = page_threads(&page, comments);
+        let count = comments.items.len();
+        let authors = self.load_authors(&comments.items).await?;
+
+        Ok(CommentThreadResponse {
+            comments: build_tree(comments.items, max_depth, &authors),
+            count,
+            total,
+            limit: page.limit(),
+            offset: page.offset(),
+            has_more: comments.has_more,
+            next_cursor: comments.next_cursor,
+            prev_cursor: comments.prev_cursor,
+        })
+    }
+
+    async fn ensure_post_visible(
+        &self,
+        post_id: Uuid,
+        viewer: Option<Uuid>,
+    ) -> Result<(), DomainError> {
+        self.post_repository
+            .find_by_id(post_id)
+            .await?
+            .filter(|post| post.is_visible_to(viewer))
+            .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+        Ok(())
+    }
+
+    async fn load_authors(&self, comments: &[Comment]) -> Result<Authors, DomainError> {
+        load_profiles(&self.user_repository, comments.iter().map(|c| c.author_id)).await
+    }
+}
+
+fn to_response(comment: Comment, authors: &Authors) -> CommentResponse {
+    CommentResponse {
+        id: comment.id.to_string(),
+        content: comment.content.as_str().to_string(),
+        post_id: comment.post_id.to_string(),
+        author_id: comment.author_id.to_string(),
+        author: authors.get(&comment.author_id).cloned(),
+        parent_id: comment.parent_id.map(|id| id.to_string()),
+        created_at: comment.created_at.to_rfc3339(),
+    }
+}
+
+/// Cut threads read for `page.with_lookahead()` down to `page`. The lookahead is a whole
+/// extra thread, so its root goes together with the replies under it.
+fn page_threads(page: &PageRequest, comments: Vec<Comment>) -> Page<Comment> {
+    let (roots, replies): (Vec<Comment>, Vec<Comment>) =
+        comments.into_iter().partition(|c| c.parent_id.is_none());
+    let roots = paginate(page, roots, |comment| {
+        PageKey::new(comment.created_at, comment.id)
+    });
+
+    // Replies are never older than their parent, so the parent of each one is decided
+    // before it in chronological order
+    let mut kept: HashSet<Uuid> = roots.items.iter().map(|c| c.id).collect();
+    let mut comments = roots.items;
+    for reply in replies {
+        if reply.parent_id.is_some_and(|parent| kept.contains(&parent)) {
+            kept.insert(reply.id);
+            comments.push(reply);
+        }
+    }
+    comments.sort_by_key(|c| PageKey::new(c.created_at, c.id));
+
+    Page {
+        items: comments,
+        ..roots
+    }
+}
+
+/// Arrange a flat, chronologically ordered list of comments into threads
+fn build_tree(comments: Vec<Comment>, max_depth: usize, authors: &Authors) -> Vec<CommentNode> {
+    let known: HashSet<Uuid> = comments.iter().map(|c| c.id).collect();
+
+    // Comments whose parent is missing from the set are treated as top-level
+    let mut roots = Vec::new();
+    let mut children: HashMap<Uuid, Vec<Comment>> = HashMap::new();
+    for comment in comments {
+        match comment.parent_id {
+            Some(parent_id) if known.contains(&parent_id) => {
+                children.entry(parent_id).or_default().push(comment)
+            }
+            _ => roots.push(comment),
+        }
+    }
+
+    attach(roots, 0, max_depth, &mut children, authors)
+}
+
+fn attach(
+    comments: Vec<Comment>,
+    depth: usize,
+    max_depth: usize,
+    children: &mut HashMap<Uuid, Vec<Comment>>,
+    authors: &Authors,
+) -> Vec<CommentNode> {
+    if depth >= max_depth {
+        // Flatten the remaining descendants into this level
+        let mut flattened = Vec::new();
+        for comment in comments {
+            collect_descendants(comment, children, &mut flattened);
+        }
+        flattened.sort_by_key(|c| PageKey::new(c.created_at, c.id));
+
+        return flattened
+            .into_iter()
+            .map(|comment| CommentNode {
+                comment: to_response(comment, authors),
+                depth,
+                replies: Vec::new(),
             })
             .collect();
+    }
+
+    comments
+        .into_iter()
+        .map(|comment| {
+            let replies = children.remove(&comment.id).unwrap_or_default();
+            let replies = attach(replies, depth + 1, max_depth, children, authors);
+            CommentNode {
+                comment: to_response(comment, authors),
+                depth,
+                replies,
+            }
+        })
+        .collect()
+}
 
-        Ok(ListCommentsResponse { comments, count })
+fn collect_descendants(
+    comment: Comment,
+    children: &mut HashMap<Uuid, Vec<Comment>>,
+    out: &mut Vec<Comment>,
+) {
+    let replies = children.remove(&comment.id).unwrap_or_default();
+    out.push(comment);
+    for reply in replies {
+        collect_descendants(reply, children, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockCommentRepository, MockPostRepository, MockUserRepository};
+    use chrono::{SubsecRound, Utc};
+    use domain::comments::CommentContent;
+    use domain::posts::{Post, PostContent, PostTitle};
+
+    fn draft(author_id: Uuid) -> Post {
+        Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            author_id,
+        )
+    }
+
+    fn published() -> Post {
+        let mut post = draft(Uuid::new_v4());
+        post.publish(Utc::now()).unwrap();
+        post
+    }
+
+    fn comment(post_id: Uuid, parent_id: Option<Uuid>, offset_secs: i64) -> Comment {
+        let mut comment = Comment::new(
+            CommentContent::new("text").unwrap(),
+            post_id,
+            Uuid::new_v4(),
+            parent_id,
+        );
+        // Cursors keep times to the microsecond
+        comment.created_at =
+            comment.created_at.trunc_subsecs(6) + chrono::Duration::seconds(offset_secs);
+        comment
+    }
+
+    #[tokio::test]
+    async fn test_execute_tree_pages_threads_by_cursor() {
+        let post = published();
+        let post_id = post.id;
+        let first = comment(post_id, None, 0);
+        let second = comment(post_id, None, 1);
+        let reply_to_first = comment(post_id, Some(first.id), 2);
+        let third = comment(post_id, None, 3);
+        let reply_to_third = comment(post_id, Some(third.id), 4);
+        let comments = MockCommentRepository::default();
+        comments.comments.lock().unwrap().extend([
+            first.clone(),
+            second.clone(),
+            reply_to_first.clone(),
+            third.clone(),
+            reply_to_third.clone(),
+        ]);
+        let use_case = ListCommentsUseCase::new(
+            comments,
+            MockPostRepository::with_post(post),
+            MockUserRepository::default(),
+        );
+
+        let page = use_case
+            .execute_tree(post_id, None, PageRequest::first(2), 5)
+            .await
+            .unwrap();
+        let roots: Vec<&str> = page
+            .comments
+            .iter()
+            .map(|n| n.comment.id.as_str())
+            .collect();
+        assert_eq!(roots, vec![first.id.to_string(), second.id.to_string()]);
+        assert_eq!(
+            page.comments[0].replies[0].comment.id,
+            reply_to_first.id.to_string()
+        );
+        // The lookahead thread's reply is not counted
+        assert_eq!(page.count, 3);
+        assert_eq!(page.total, 3);
+        assert!(page.has_more);
+        assert_eq!(page.prev_cursor, None);
+
+        let cursor = PageKey::decode(page.next_cursor.as_deref().unwrap()).unwrap();
+        let page = use_case
+            .execute_tree(post_id, None, PageRequest::after(cursor, 2), 5)
+            .await
+            .unwrap();
+        assert_eq!(page.comments.len(), 1);
+        assert_eq!(page.comments[0].comment.id, third.id.to_string());
+        assert_eq!(
+            page.comments[0].replies[0].comment.id,
+            reply_to_third.id.to_string()
+        );
+        assert!(!page.has_more);
+        assert_eq!(page.next_cursor, None);
+        assert!(page.prev_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_draft_comments_are_only_listed_for_the_author() {
+        let author_id = Uuid::new_v4();
+        let post = draft(author_id);
+        let comments = MockCommentRepository::default();
+        comments
+            .comments
+            .lock()
+            .unwrap()
+            .push(comment(post.id, None, 0));
+        let use_case = ListCommentsUseCase::new(
+            comments,
+            MockPostRepository::with_post(post.clone()),
+            MockUserRepository::default(),
+        );
+
+        for viewer in [None, Some(Uuid::new_v4())] {
+            let result = use_case
+                .execute(post.id, viewer, PageRequest::first(10))
+                .await;
+            assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+            let result = use_case
+                .execute_tree(post.id, viewer, PageRequest::first(10), 5)
+                .await;
+            assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+        }
+
+        let response = use_case
+            .execute(post.id, Some(author_id), PageRequest::first(10))
+            .await
+            .unwrap();
+        assert_eq!(response.count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_comments_of_missing_post() {
+        let use_case = ListCommentsUseCase::new(
+            MockCommentRepository::default(),
+            MockPostRepository::default(),
+            MockUserRepository::default(),
+        );
+
+        let result = use_case
+            .execute(Uuid::new_v4(), None, PageRequest::first(10))
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+
+    #[test]
+    fn test_build_tree_nests_replies() {
+        let post_id = Uuid::new_v4();
+        let root = comment(post_id, None, 0);
+        let reply = comment(post_id, Some(root.id), 1);
+        let nested = comment(post_id, Some(reply.id), 2);
+        let (root_id, reply_id, nested_id) = (root.id, reply.id, nested.id);
+
+        let tree = build_tree(vec![root, reply, nested], 5, &HashMap::new());
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].comment.id, root_id.to_string());
+        assert_eq!(tree[0].replies[0].comment.id, reply_id.to_string());
+        assert_eq!(
+            tree[0].replies[0].replies[0].comment.id,
+            nested_id.to_string()
+        );
+        assert_eq!(tree[0].replies[0].replies[0].depth, 2);
+    }
+
+    #[test]
+    fn test_build_tree_flattens_beyond_max_depth() {
+        let post_id = Uuid::new_v4();
+        let root = comment(post_id, None, 0);
+        let reply = comment(post_id, Some(root.id), 1);
+        let nested = comment(post_id, Some(reply.id), 2);
+        let sibling = comment(post_id, Some(root.id), 3);
+
+        let tree = build_tree(vec![root, reply, nested, sibling], 1, &HashMap::new());
+
+        assert_eq!(tree.len(), 1);
+        let depths: Vec<usize> = tree[0].replies.iter().map(|n| n.depth).collect();
+        assert_eq!(depths, vec![1, 1, 1]);
+        assert!(tree[0].replies.iter().all(|n| n.replies.is_empty()));
+    }
+
+    #[test]
+    fn test_build_tree_flattens_replies_at_the_same_time_in_page_order() {
+        let post_id = Uuid::new_v4();
+        let root = comment(post_id, None, 0);
+        let replies = [
+            comment(post_id, Some(root.id), 1),
+            comment(post_id, Some(root.id), 1),
+        ];
+        let nested = comment(post_id, Some(replies[0].id), 1);
+        let mut expected = [replies[0].id, replies[1].id, nested.id];
+        expected.sort();
+        let at = nested.created_at;
+        let [mut first, mut second] = replies;
+        (first.created_at, second.created_at) = (at, at);
+
+        let tree = build_tree(vec![root, first, nested, second], 1, &HashMap::new());
+
+        // Ties are broken by id, as in the pages
+        let ids: Vec<&str> = tree[0]
+            .replies
+            .iter()
+            .map(|n| n.comment.id.as_str())
+            .collect();
+        let expected: Vec<String> = expected.iter().map(Uuid::to_string).collect();
+        assert_eq!(ids, expected);
     }
 }
@@ -1,9 +1,13 @@
 // crates/application/src/comments/mod.rs
 
 mod create_comment;
+mod delete_comment;
 mod dtos;
 mod list_comments;
 
 pub use create_comment::CreateCommentUseCase;
-pub use dtos::{CommentResponse, CreateCommentRequest, ListCommentsResponse};
+pub use delete_comment::DeleteCommentUseCase;
+pub use dtos::{
+    CommentNode, CommentResponse, CommentThreadResponse, CreateCommentRequest, ListCommentsResponse,
+};
 pub use list_comments::ListCommentsUseCase;
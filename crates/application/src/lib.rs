@@ -1,6 +1,10 @@
 // crates/application/src/lib.rs
 
+pub mod authorization;
 pub mod comments;
+mod pagination;
 pub mod posts;
 pub mod users;
 
+#[cfg(test)]
+pub(crate) mod test_support;
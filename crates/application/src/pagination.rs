@@ -0,0 +1,118 @@
+// crates/application/src/pagination.rs
+
+use domain::common::pagination::{PageKey, PagePosition, PageRequest};
+
+/// A page of items with the cursors of the pages either side of it, if there are any
+pub(crate) struct Page<T> {
+    pub items: Vec<T>,
+    pub has_more: bool, // Whether the listing goes on after this page
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+/// Cut `items`, read for `page.with_lookahead()`, down to `page` and work out its cursors
+pub(crate) fn paginate<T>(
+    page: &PageRequest,
+    mut items: Vec<T>,
+    key: impl Fn(&T) -> PageKey,
+) -> Page<T> {
+    let limit = page.limit() as usize;
+    let more = items.len() > limit;
+
+    // The extra item lies past the end of the page, or before its start when reading
+    // backwards from a cursor
+    let (has_next, has_prev) = match page.position() {
+        PagePosition::Offset(offset) => {
+            items.truncate(limit);
+            (more, offset > 0)
+        }
+        PagePosition::After(_) => {
+            items.truncate(limit);
+            (more, true)
+        }
+        PagePosition::Before(_) => {
+            if more {
+                items.drain(..items.len() - limit);
+            }
+            (true, more)
+        }
+    };
+
+    Page {
+        has_more: has_next,
+        next_cursor: items
+            .last()
+            .filter(|_| has_next)
+            .map(|item| key(item).encode()),
+        prev_cursor: items
+            .first()
+            .filter(|_| has_prev)
+            .map(|item| key(item).encode()),
+        items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    fn keys(count: usize) -> Vec<PageKey> {
+        // Cursors keep times to the microsecond
+        let now = DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap();
+        (0..count)
+            .map(|i| PageKey::new(now + Duration::seconds(i as i64), Uuid::new_v4()))
+            .collect()
+    }
+
+    #[test]
+    fn test_first_page_has_only_next_cursor() {
+        let items = keys(3);
+        let page = paginate(&PageRequest::first(2), items.clone(), |key| *key);
+
+        assert_eq!(page.items, items[..2]);
+        assert!(page.has_more);
+        assert_eq!(page.next_cursor, Some(items[1].encode()));
+        assert_eq!(page.prev_cursor, None);
+    }
+
+    #[test]
+    fn test_last_page_after_cursor_has_only_prev_cursor() {
+        let items = keys(2);
+        let page = paginate(
+            &PageRequest::after(items[0], 2),
+            items[1..].to_vec(),
+            |key| *key,
+        );
+
+        assert_eq!(page.items, items[1..]);
+        assert!(!page.has_more);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(page.prev_cursor, Some(items[1].encode()));
+    }
+
+    #[test]
+    fn test_page_before_cursor_drops_lookahead_from_the_front() {
+        let items = keys(4);
+        let cursor = PageKey::new(Utc::now() + Duration::days(1), Uuid::new_v4()).encode();
+        let request = PageRequest::parse(2, 100, 0, None, Some(&cursor)).unwrap();
+
+        let page = paginate(&request, items[1..].to_vec(), |key| *key);
+
+        assert_eq!(page.items, items[2..]);
+        assert_eq!(page.next_cursor, Some(items[3].encode()));
+        assert_eq!(page.prev_cursor, Some(items[2].encode()));
+    }
+
+    #[test]
+    fn test_parse_clamps_limit_and_decodes_cursors() {
+        let key = keys(1)[0];
+        let request = PageRequest::parse(1000, 100, 0, Some(&key.encode()), None).unwrap();
+
+        assert_eq!(request.limit(), 100);
+        assert_eq!(request.position(), PagePosition::After(key));
+        assert!(PageRequest::parse(20, 100, 0, Some("not a cursor"), None).is_err());
+        assert!(PageRequest::parse(20, 100, 5, Some(&key.encode()), None).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+// crates/application/src/posts/backfill_post_slugs.rs
+
+use domain::common::error::DomainError;
+use domain::posts::PostRepository;
+
+use super::slugs::assign_slug;
+
+// Posts given a slug per database round trip
+const BACKFILL_BATCH_SIZE: i64 = 100;
+
+pub struct BackfillPostSlugsUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> BackfillPostSlugsUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Give every post from before slugs existed the slug it would get if it were created
+    /// now, oldest first; returns how many were given one. A post that fails is logged and
+    /// left for the next run.
+    pub async fn execute(&self) -> Result<usize, DomainError> {
+        let mut backfilled = 0;
+        loop {
+            let posts = self
+                .post_repository
+                .find_without_slug(BACKFILL_BATCH_SIZE)
+                .await?;
+            let batch_size = posts.len();
+
+            let mut backfilled_in_batch = 0;
+            for mut post in posts {
+                // Later posts in the batch see the slugs stored for earlier ones
+                let result = match assign_slug(&self.post_repository, &mut post).await {
+                    Ok(()) => self.post_repository.backfill_slug(&post).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(true) => backfilled_in_batch += 1,
+                    // Another instance got to it first
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!(
+                        error = %e,
+                        post_id = %post.id,
+                        "Failed to give post a slug"
+                    ),
+                }
+            }
+            backfilled += backfilled_in_batch;
+
+            // A batch that changed nothing would only come back the same
+            if (batch_size as i64) < BACKFILL_BATCH_SIZE || backfilled_in_batch == 0 {
+                return Ok(backfilled);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use chrono::Duration;
+    use domain::posts::{Post, PostContent, PostTitle};
+    use uuid::Uuid;
+
+    fn post(title: &str, age_minutes: i64) -> Post {
+        let mut post = Post::new(
+            PostTitle::new(title).unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        );
+        post.created_at -= Duration::minutes(age_minutes);
+        post
+    }
+
+    #[tokio::test]
+    async fn test_backfill_gives_old_posts_the_slugs_of_new_ones() {
+        let repo = MockPostRepository::default();
+        let taken = post("Café Ünïcode", 10);
+        repo.save(taken.clone()).await.unwrap();
+        let older = post("Café Ünïcode!", 30);
+        let newer = post("Cafe Unicode", 20);
+        let long = post(&format!("{} tail", "word ".repeat(20)), 5);
+        for post in [&newer, &long, &older] {
+            repo.save_without_slug(post.clone());
+        }
+
+        let backfilled = BackfillPostSlugsUseCase::new(repo.clone())
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(backfilled, 3);
+        // Transliterated, and numbered oldest first after the slug already in use
+        let slug = |post: &Post| repo.get(post.id).unwrap().slug;
+        assert_eq!(slug(&taken).as_str(), "cafe-unicode");
+        assert_eq!(slug(&older).as_str(), "cafe-unicode-2");
+        assert_eq!(repo.get(older.id).unwrap().slug_suffix, Some(2));
+        assert_eq!(slug(&newer).as_str(), "cafe-unicode-3");
+        // Cut at a word boundary, as for new posts
+        assert_eq!(slug(&long).as_str(), format!("{}word", "word-".repeat(15)));
+        assert!(repo.find_without_slug(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_without_old_posts() {
+        let repo = MockPostRepository::default();
+        repo.save(post("Title", 0)).await.unwrap();
+
+        let backfilled = BackfillPostSlugsUseCase::new(repo).execute().await.unwrap();
+
+        assert_eq!(backfilled, 0);
+    }
+}
@@ -0,0 +1,192 @@
+// crates/application/src/posts/change_post_status.rs
+
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::posts::{Post, PostRepository, PostStatus};
+use uuid::Uuid;
+
+use super::dtos::{ChangePostStatusRequest, PostResponse, post_response};
+use crate::authorization::{Actor, Policy};
+
+pub struct ChangePostStatusUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> ChangePostStatusUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Move a post to another status (author only): publish or schedule a draft,
+    /// call off a scheduled publication, or archive a published post
+    pub async fn execute(
+        &self,
+        request: ChangePostStatusRequest,
+        post_id: Uuid,
+        actor: &Actor,
+    ) -> Result<PostResponse, DomainError> {
+        let mut post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+
+        // Only the author may change the status
+        Policy::edit_post(actor, &post)?;
+
+        let status = PostStatus::parse(&request.status)?;
+        let publish_at = request
+            .publish_at
+            .as_deref()
+            .map(parse_publish_at)
+            .transpose()?;
+        apply_status(&mut post, status, publish_at, Utc::now())?;
+
+        self.post_repository.update(post.clone()).await?;
+
+        Ok(post_response(&post, None))
+    }
+}
+
+/// Carry out the transition to `status`; `publish_at` only goes with scheduling
+pub(super) fn apply_status(
+    post: &mut Post,
+    status: PostStatus,
+    publish_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<(), DomainError> {
+    match (status, publish_at) {
+        (PostStatus::Scheduled, Some(at)) => post.schedule(at, now),
+        (PostStatus::Scheduled, None) => Err(DomainError::Validation(
+            "publish_at is required to schedule a post".into(),
+        )),
+        (_, Some(_)) => Err(DomainError::Validation(
+            "publish_at can only be given when scheduling a post".into(),
+        )),
+        (PostStatus::Draft, None) => post.unschedule(now),
+        (PostStatus::Published, None) => post.publish(now),
+        (PostStatus::Archived, None) => post.archive(now),
+    }
+}
+
+pub(super) fn parse_publish_at(publish_at: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(publish_at)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|_| DomainError::Validation("publish_at must be an RFC 3339 timestamp".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use domain::posts::{PostContent, PostTitle};
+    use domain::users::Role;
+
+    fn sample_post(author_id: Uuid) -> Post {
+        Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            author_id,
+        )
+    }
+
+    fn request(status: &str, publish_at: Option<DateTime<Utc>>) -> ChangePostStatusRequest {
+        ChangePostStatusRequest {
+            status: status.to_string(),
+            publish_at: publish_at.map(|at| at.to_rfc3339()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_lifecycle() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let post = sample_post(author.user_id);
+        let post_id = post.id;
+        let repo = MockPostRepository::with_post(post);
+        let use_case = ChangePostStatusUseCase::new(repo.clone());
+
+        let publish_at = Utc::now() + chrono::Duration::days(1);
+        let scheduled = use_case
+            .execute(request("scheduled", Some(publish_at)), post_id, &author)
+            .await
+            .unwrap();
+        assert_eq!(scheduled.status, "scheduled");
+
+        let draft = use_case
+            .execute(request("draft", None), post_id, &author)
+            .await
+            .unwrap();
+        assert_eq!(draft.status, "draft");
+        assert!(draft.published_at.is_none());
+
+        let published = use_case
+            .execute(request("published", None), post_id, &author)
+            .await
+            .unwrap();
+        assert_eq!(published.status, "published");
+        assert!(published.published_at.is_some());
+
+        let archived = use_case
+            .execute(request("archived", None), post_id, &author)
+            .await
+            .unwrap();
+        assert_eq!(archived.status, "archived");
+        assert_eq!(archived.published_at, published.published_at);
+
+        let stored = repo.find_by_id(post_id).await.unwrap().unwrap();
+        assert_eq!(stored.status, PostStatus::Archived);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_transitions() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let post = sample_post(author.user_id);
+        let post_id = post.id;
+        let use_case = ChangePostStatusUseCase::new(MockPostRepository::with_post(post));
+
+        // A draft was never published, so it cannot be archived
+        let result = use_case
+            .execute(request("archived", None), post_id, &author)
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+
+        // publish_at only goes with scheduling
+        let result = use_case
+            .execute(request("published", Some(Utc::now())), post_id, &author)
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+
+        use_case
+            .execute(request("published", None), post_id, &author)
+            .await
+            .unwrap();
+        let result = use_case
+            .execute(
+                request("scheduled", Some(Utc::now() + chrono::Duration::days(1))),
+                post_id,
+                &author,
+            )
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_change_status_not_author() {
+        let post = sample_post(Uuid::new_v4());
+        let post_id = post.id;
+        let use_case = ChangePostStatusUseCase::new(MockPostRepository::with_post(post));
+
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+        let result = use_case
+            .execute(request("published", None), post_id, &moderator)
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+    }
+}
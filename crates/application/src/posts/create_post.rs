@@ -1,10 +1,13 @@
 // crates/application/src/posts/create_post.rs
 
+use chrono::Utc;
 use domain::common::error::DomainError;
-use domain::posts::{Post, PostContent, PostRepository, PostTitle};
+use domain::posts::{Post, PostContent, PostRepository, PostStatus, PostTitle, Tag};
 use uuid::Uuid;
 
-use super::dtos::{CreatePostRequest, PostResponse};
+use super::change_post_status::{apply_status, parse_publish_at};
+use super::dtos::{CreatePostRequest, PostResponse, post_response};
+use super::slugs::assign_slug;
 
 pub struct CreatePostUseCase<R>
 where
@@ -29,96 +32,60 @@ where
         // Validate title and content
         let title = PostTitle::new(request.title)?;
         let content = PostContent::new(request.content)?;
+        let tags = request
+            .tags
+            .unwrap_or_default()
+            .into_iter()
+            .map(Tag::new)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        // Create post
-        let post = Post::new(title, content, author_id);
+        let status = request
+            .status
+            .as_deref()
+            .map(PostStatus::parse)
+            .transpose()?
+            .unwrap_or(PostStatus::Published);
+        let publish_at = request
+            .publish_at
+            .as_deref()
+            .map(parse_publish_at)
+            .transpose()?;
 
-        // Save post
+        // Create post; it starts as a draft and moves on to the requested status
+        let mut post = Post::new(title, content, author_id);
+        assign_slug(&self.post_repository, &mut post).await?;
+        post.set_tags(tags)?;
+        if status != PostStatus::Draft || publish_at.is_some() {
+            apply_status(&mut post, status, publish_at, Utc::now())?;
+        }
+
+        // Save post; its first revision is the post as created
         self.post_repository.save(post.clone()).await?;
 
-        Ok(PostResponse {
-            id: post.id.to_string(),
-            title: post.title.as_str().to_string(),
-            content: post.content.as_str().to_string(),
-            author_id: post.author_id.to_string(),
-            created_at: post.created_at.to_rfc3339(),
-            updated_at: post.updated_at.to_rfc3339(),
-        })
+        Ok(post_response(&post, None))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
-    use std::sync::{Arc, Mutex};
-
-    struct MockPostRepository {
-        posts: Arc<Mutex<Vec<Post>>>,
-    }
-
-    impl MockPostRepository {
-        fn new() -> Self {
-            Self {
-                posts: Arc::new(Mutex::new(Vec::new())),
-            }
-        }
-    }
-
-    impl Clone for MockPostRepository {
-        fn clone(&self) -> Self {
-            Self {
-                posts: Arc::clone(&self.posts),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl PostRepository for MockPostRepository {
-        async fn save(&self, post: Post) -> Result<(), DomainError> {
-            self.posts.lock().unwrap().push(post);
-            Ok(())
-        }
-
-        async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError> {
-            Ok(self
-                .posts
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|p| p.id == id)
-                .cloned())
-        }
-
-        async fn find_all(&self, _limit: i64, _offset: i64) -> Result<Vec<Post>, DomainError> {
-            Ok(self.posts.lock().unwrap().clone())
-        }
+    use crate::posts::ListTagsUseCase;
+    use crate::test_support::MockPostRepository;
 
-        async fn find_by_author(
-            &self,
-            author_id: Uuid,
-            _limit: i64,
-            _offset: i64,
-        ) -> Result<Vec<Post>, DomainError> {
-            Ok(self
-                .posts
-                .lock()
-                .unwrap()
-                .iter()
-                .filter(|p| p.author_id == author_id)
-                .cloned()
-                .collect())
-        }
-    }
+    use domain::common::pagination::PageRequest;
 
     #[tokio::test]
     async fn test_create_post_success() {
-        let use_case = CreatePostUseCase::new(MockPostRepository::new());
+        let repo = MockPostRepository::default();
+        let use_case = CreatePostUseCase::new(repo.clone());
         let author_id = Uuid::new_v4();
 
         let request = CreatePostRequest {
             title: "Test Post".to_string(),
             content: "This is a test post content".to_string(),
+            tags: None,
+            status: None,
+            publish_at: None,
         };
 
         let result = use_case.execute(request, author_id).await;
@@ -128,16 +95,169 @@ mod tests {
         assert_eq!(response.title, "Test Post");
         assert_eq!(response.content, "This is a test post content");
         assert_eq!(response.author_id, author_id.to_string());
+        assert_eq!(response.status, "published");
+        assert!(response.published_at.is_some());
+
+        let revisions = repo.revisions.lock().unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].number, 1);
+        assert_eq!(revisions[0].title.as_str(), "Test Post");
+    }
+
+    #[tokio::test]
+    async fn test_create_post_slugs_are_transliterated_and_unique() {
+        let use_case = CreatePostUseCase::new(MockPostRepository::default());
+        let author_id = Uuid::new_v4();
+
+        let mut slugs = Vec::new();
+        for _ in 0..3 {
+            let request = CreatePostRequest {
+                title: "Größe & Über: Crème brûlée".to_string(),
+                content: "Dessert".to_string(),
+                tags: None,
+                status: None,
+                publish_at: None,
+            };
+            slugs.push(use_case.execute(request, author_id).await.unwrap().slug);
+        }
+
+        assert_eq!(
+            slugs,
+            vec![
+                "grosse-uber-creme-brulee",
+                "grosse-uber-creme-brulee-2",
+                "grosse-uber-creme-brulee-3",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_post_with_tags() {
+        let repo = MockPostRepository::default();
+        let use_case = CreatePostUseCase::new(repo.clone());
+        let author_id = Uuid::new_v4();
+
+        let response = use_case
+            .execute(
+                CreatePostRequest {
+                    title: "Tagged".to_string(),
+                    content: "About Rust".to_string(),
+                    tags: Some(vec![
+                        "Rust Lang".to_string(),
+                        "rust_lang".to_string(),
+                        "  Café--Crème ".to_string(),
+                        "web".to_string(),
+                    ]),
+                    status: None,
+                    publish_at: None,
+                },
+                author_id,
+            )
+            .await
+            .unwrap();
+        // Normalised like slugs, sorted and without duplicates
+        assert_eq!(response.tags, vec!["cafe-creme", "rust-lang", "web"]);
+
+        let tags = ListTagsUseCase::new(repo).execute().await.unwrap();
+        assert_eq!(tags.count, 3);
+        assert_eq!(tags.tags[0].name, "cafe-creme");
+        assert_eq!(tags.tags[0].post_count, 1);
+
+        let result = use_case
+            .execute(
+                CreatePostRequest {
+                    title: "Tagged".to_string(),
+                    content: "About C++".to_string(),
+                    tags: Some(vec!["c++".to_string()]),
+                    status: None,
+                    publish_at: None,
+                },
+                author_id,
+            )
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_create_draft_and_scheduled_posts() {
+        let repo = MockPostRepository::default();
+        let use_case = CreatePostUseCase::new(repo.clone());
+        let author_id = Uuid::new_v4();
+
+        let draft = use_case
+            .execute(
+                CreatePostRequest {
+                    title: "Draft".to_string(),
+                    content: "Not ready yet".to_string(),
+                    tags: None,
+                    status: Some("draft".to_string()),
+                    publish_at: None,
+                },
+                author_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(draft.status, "draft");
+        assert!(draft.published_at.is_none());
+
+        let publish_at = (Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+        let scheduled = use_case
+            .execute(
+                CreatePostRequest {
+                    title: "Scheduled".to_string(),
+                    content: "Out tomorrow".to_string(),
+                    tags: None,
+                    status: Some("scheduled".to_string()),
+                    publish_at: Some(publish_at),
+                },
+                author_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(scheduled.status, "scheduled");
+        assert!(scheduled.published_at.is_some());
+
+        assert!(
+            repo.find_published(None, &PageRequest::first(20))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_scheduled_post_requires_future_publish_at() {
+        let use_case = CreatePostUseCase::new(MockPostRepository::default());
+        let author_id = Uuid::new_v4();
+
+        for publish_at in [
+            None,
+            Some((Utc::now() - chrono::Duration::hours(1)).to_rfc3339()),
+        ] {
+            let request = CreatePostRequest {
+                title: "Scheduled".to_string(),
+                content: "Out tomorrow".to_string(),
+                tags: None,
+                status: Some("scheduled".to_string()),
+                publish_at,
+            };
+
+            let result = use_case.execute(request, author_id).await;
+            assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        }
     }
 
     #[tokio::test]
     async fn test_create_post_empty_title() {
-        let use_case = CreatePostUseCase::new(MockPostRepository::new());
+        let use_case = CreatePostUseCase::new(MockPostRepository::default());
         let author_id = Uuid::new_v4();
 
         let request = CreatePostRequest {
             title: "".to_string(),
             content: "This is a test post content".to_string(),
+            tags: None,
+            status: None,
+            publish_at: None,
         };
 
         let result = use_case.execute(request, author_id).await;
@@ -147,12 +267,15 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_post_empty_content() {
-        let use_case = CreatePostUseCase::new(MockPostRepository::new());
+        let use_case = CreatePostUseCase::new(MockPostRepository::default());
         let author_id = Uuid::new_v4();
 
         let request = CreatePostRequest {
             title: "Test Post".to_string(),
             content: "".to_string(),
+            tags: None,
+            status: None,
+            publish_at: None,
         };
 
         let result = use_case.execute(request, author_id).await;
@@ -0,0 +1,99 @@
+// crates/application/src/posts/delete_post.rs
+
+use domain::common::error::DomainError;
+use domain::posts::PostRepository;
+use uuid::Uuid;
+
+use crate::authorization::{Actor, Policy};
+
+pub struct DeletePostUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> DeletePostUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    pub async fn execute(&self, post_id: Uuid, actor: &Actor) -> Result<(), DomainError> {
+        let post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+
+        // The author or a moderator may delete
+        Policy::delete_post(actor, &post)?;
+
+        self.post_repository.delete(post.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+    use domain::posts::{Post, PostContent, PostTitle};
+    use domain::users::Role;
+
+    fn post(author_id: Uuid) -> Post {
+        Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            author_id,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_author_can_delete_post() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let post = post(author.user_id);
+        let posts = MockPostRepository::with_post(post.clone());
+        let use_case = DeletePostUseCase::new(posts.clone());
+
+        use_case.execute(post.id, &author).await.unwrap();
+
+        assert!(posts.get(post.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_other_user_cannot_delete_post() {
+        let post = post(Uuid::new_v4());
+        let posts = MockPostRepository::with_post(post.clone());
+        let use_case = DeletePostUseCase::new(posts.clone());
+        let other = Actor::new(Uuid::new_v4(), Role::User, true);
+
+        let result = use_case.execute(post.id, &other).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+        assert!(posts.get(post.id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_moderator_can_delete_others_posts() {
+        let post = post(Uuid::new_v4());
+        let posts = MockPostRepository::with_post(post.clone());
+        let use_case = DeletePostUseCase::new(posts.clone());
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+
+        use_case.execute(post.id, &moderator).await.unwrap();
+
+        assert!(posts.get(post.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_delete_post_not_found() {
+        let use_case = DeletePostUseCase::new(MockPostRepository::default());
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+
+        let result = use_case.execute(Uuid::new_v4(), &author).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+}
@@ -1,27 +1,165 @@
 // crates/application/src/posts/dtos.rs
 
+use domain::posts::Post;
 use serde::{Deserialize, Serialize};
 
+use crate::users::ProfileResponse;
+
 // Create Post DTOs
+// Without a status the post is published right away; `publish_at` (RFC 3339) is
+// required when the status is "scheduled"
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreatePostRequest {
     pub title: String,
     pub content: String,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub publish_at: Option<String>,
+}
+
+// Update Post DTOs
+// Fields left as None keep their current value (PATCH semantics)
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdatePostRequest {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    // Replaces all of the post's tags when given
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct PostResponse {
     pub id: String,
     pub title: String,
+    pub slug: String,
     pub content: String,
     pub author_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<ProfileResponse>, // Filled in by read use cases
+    pub tags: Vec<String>,
+    pub status: String,
+    pub published_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+pub(crate) fn post_response(post: &Post, author: Option<ProfileResponse>) -> PostResponse {
+    PostResponse {
+        id: post.id.to_string(),
+        title: post.title.as_str().to_string(),
+        slug: post.slug.as_str().to_string(),
+        content: post.content.as_str().to_string(),
+        author_id: post.author_id.to_string(),
+        author,
+        tags: post
+            .tags
+            .iter()
+            .map(|tag| tag.as_str().to_string())
+            .collect(),
+        status: post.status.as_str().to_string(),
+        published_at: post.published_at.map(|at| at.to_rfc3339()),
+        created_at: post.created_at.to_rfc3339(),
+        updated_at: post.updated_at.to_rfc3339(),
+    }
+}
+
+// Looking a post up by slug either finds it or finds the slug it has moved to
+#[derive(Debug)]
+pub enum PostBySlugResponse {
+    Found(Box<PostResponse>),
+    Moved { slug: String },
+}
+
+// Change Post Status DTOs
+// `publish_at` (RFC 3339) is required when the status is "scheduled"
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangePostStatusRequest {
+    pub status: String,
+    #[serde(default)]
+    pub publish_at: Option<String>,
+}
+
 // List Posts Response
 #[derive(Debug, Serialize)]
 pub struct ListPostsResponse {
     pub posts: Vec<PostResponse>,
+    pub count: usize, // On this page
+    pub total: i64,   // Across all pages
+    pub limit: i64,
+    pub offset: Option<i64>,         // Unset when paging by cursor
+    pub has_more: bool,              // Whether pages follow this one
+    pub next_cursor: Option<String>, // Pass as `after` for the following page
+    pub prev_cursor: Option<String>, // Pass as `before` for the preceding page
+}
+
+// Search Posts Response; snippets are HTML-escaped text with matches wrapped in <mark>
+#[derive(Debug, Serialize)]
+pub struct SearchResultResponse {
+    pub post: PostResponse,
+    pub rank: f32,
+    pub title_snippet: String,
+    pub content_snippet: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchPostsResponse {
+    pub query: String,
+    pub results: Vec<SearchResultResponse>,
+    pub count: usize, // On this page
+    pub total: i64,   // Across all pages
+    pub limit: i64,
+    pub offset: i64,
+    pub has_more: bool, // Whether pages follow this one
+}
+
+// List Tags Response
+#[derive(Debug, Serialize)]
+pub struct TagResponse {
+    pub name: String,
+    pub post_count: i64, // Published posts carrying the tag
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTagsResponse {
+    pub tags: Vec<TagResponse>,
     pub count: usize,
 }
+
+// Post Revision DTOs
+#[derive(Debug, Serialize)]
+pub struct PostRevisionResponse {
+    pub number: i32,
+    pub title: String,
+    pub content: String,
+    pub editor_id: String,
+    pub restored_from: Option<i32>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListPostRevisionsResponse {
+    pub revisions: Vec<PostRevisionResponse>,
+    pub count: usize,
+}
+
+// One line of a revision diff; `op` is "unchanged", "added" or "removed" and the line
+// numbers refer to the `from` and `to` revisions respectively
+#[derive(Debug, Serialize)]
+pub struct DiffLineResponse {
+    pub op: String,
+    pub text: String,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostRevisionDiffResponse {
+    pub post_id: String,
+    pub from: i32,
+    pub to: i32,
+    pub title: Vec<DiffLineResponse>,
+    pub content: Vec<DiffLineResponse>,
+}
@@ -2,39 +2,49 @@
 
 use domain::common::error::DomainError;
 use domain::posts::PostRepository;
+use domain::users::UserRepository;
 use uuid::Uuid;
 
-use super::dtos::PostResponse;
+use super::dtos::{PostResponse, post_response};
+use crate::users::load_profiles;
 
-pub struct GetPostUseCase<R>
+pub struct GetPostUseCase<R, U>
 where
     R: PostRepository,
+    U: UserRepository,
 {
     post_repository: R,
+    user_repository: U,
 }
 
-impl<R> GetPostUseCase<R>
+impl<R, U> GetPostUseCase<R, U>
 where
     R: PostRepository,
+    U: UserRepository,
 {
-    pub fn new(post_repository: R) -> Self {
-        Self { post_repository }
+    pub fn new(post_repository: R, user_repository: U) -> Self {
+        Self {
+            post_repository,
+            user_repository,
+        }
     }
 
-    pub async fn execute(&self, post_id: Uuid) -> Result<PostResponse, DomainError> {
+    /// A post as seen by `viewer`; posts that are not published are only found by
+    /// their author
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        viewer: Option<Uuid>,
+    ) -> Result<PostResponse, DomainError> {
         let post = self
             .post_repository
             .find_by_id(post_id)
             .await?
+            .filter(|post| post.is_visible_to(viewer))
             .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
 
-        Ok(PostResponse {
-            id: post.id.to_string(),
-            title: post.title.as_str().to_string(),
-            content: post.content.as_str().to_string(),
-            author_id: post.author_id.to_string(),
-            created_at: post.created_at.to_rfc3339(),
-            updated_at: post.updated_at.to_rfc3339(),
-        })
+        let mut authors = load_profiles(&self.user_repository, [post.author_id]).await?;
+
+        Ok(post_response(&post, authors.remove(&post.author_id)))
     }
 }
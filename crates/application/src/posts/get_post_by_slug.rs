@@ -0,0 +1,150 @@
+// crates/application/src/posts/get_post_by_slug.rs
+
+use domain::common::error::DomainError;
+use domain::posts::{PostRepository, PostSlug};
+use domain::users::UserRepository;
+use uuid::Uuid;
+
+use super::dtos::{PostBySlugResponse, post_response};
+use crate::users::load_profiles;
+
+pub struct GetPostBySlugUseCase<R, U>
+where
+    R: PostRepository,
+    U: UserRepository,
+{
+    post_repository: R,
+    user_repository: U,
+}
+
+impl<R, U> GetPostBySlugUseCase<R, U>
+where
+    R: PostRepository,
+    U: UserRepository,
+{
+    pub fn new(post_repository: R, user_repository: U) -> Self {
+        Self {
+            post_repository,
+            user_repository,
+        }
+    }
+
+    /// A post as seen by `viewer`, by its slug. A slug the post had before its title
+    /// changed gives the current slug instead. Like by id, posts that are not published
+    /// are only found by their author.
+    pub async fn execute(
+        &self,
+        slug: &str,
+        viewer: Option<Uuid>,
+    ) -> Result<PostBySlugResponse, DomainError> {
+        let not_found = || DomainError::NotFound("Post not found".into());
+        // Malformed slugs can't belong to any post
+        let slug = PostSlug::new(slug).map_err(|_| not_found())?;
+
+        let post = match self.post_repository.find_by_slug(&slug).await? {
+            Some(post) => post,
+            None => {
+                let post = self
+                    .post_repository
+                    .find_by_previous_slug(&slug)
+                    .await?
+                    .filter(|post| post.is_visible_to(viewer))
+                    .ok_or_else(not_found)?;
+                return Ok(PostBySlugResponse::Moved {
+                    slug: post.slug.as_str().to_string(),
+                });
+            }
+        };
+        if !post.is_visible_to(viewer) {
+            return Err(not_found());
+        }
+
+        let mut authors = load_profiles(&self.user_repository, [post.author_id]).await?;
+
+        Ok(PostBySlugResponse::Found(Box::new(post_response(
+            &post,
+            authors.remove(&post.author_id),
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::posts::PostBySlugResponse;
+    use crate::test_support::{MockPostRepository, MockUserRepository};
+
+    use chrono::Utc;
+    use domain::posts::{Post, PostContent, PostTitle};
+
+    fn post(title: &str) -> Post {
+        Post::new(
+            PostTitle::new(title).unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        )
+    }
+
+    fn use_case(
+        repo: &MockPostRepository,
+    ) -> GetPostBySlugUseCase<MockPostRepository, MockUserRepository> {
+        GetPostBySlugUseCase::new(repo.clone(), MockUserRepository::default())
+    }
+
+    #[tokio::test]
+    async fn test_get_post_by_current_slug() {
+        let mut post = post("Hello World");
+        post.publish(Utc::now()).unwrap();
+        let repo = MockPostRepository::with_post(post.clone());
+
+        let response = use_case(&repo).execute("hello-world", None).await.unwrap();
+
+        let PostBySlugResponse::Found(found) = response else {
+            panic!("expected the post to be found");
+        };
+        assert_eq!(found.id, post.id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_previous_slug_moves_to_current_one() {
+        let mut post = post("Hello World");
+        post.publish(Utc::now()).unwrap();
+        let repo = MockPostRepository::with_post(post.clone());
+        post.set_slug(&PostSlug::new("goodbye-world").unwrap(), None);
+        repo.update(post).await.unwrap();
+
+        let response = use_case(&repo).execute("hello-world", None).await.unwrap();
+
+        assert!(matches!(
+            response,
+            PostBySlugResponse::Moved { slug } if slug == "goodbye-world"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unpublished_post_is_found_by_author_only() {
+        let mut post = post("Draft");
+        let repo = MockPostRepository::with_post(post.clone());
+        post.set_slug(&PostSlug::new("still-a-draft").unwrap(), None);
+        repo.update(post.clone()).await.unwrap();
+
+        for slug in ["still-a-draft", "draft"] {
+            let result = use_case(&repo).execute(slug, None).await;
+            assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+        }
+        let response = use_case(&repo)
+            .execute("draft", Some(post.author_id))
+            .await
+            .unwrap();
+        assert!(matches!(response, PostBySlugResponse::Moved { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_slug_is_not_found() {
+        let repo = MockPostRepository::default();
+
+        let result = use_case(&repo).execute("Not A Slug", None).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+}
@@ -0,0 +1,187 @@
+// crates/application/src/posts/line_diff.rs
+
+// Line-level diff of two texts, computed from their longest common subsequence of lines
+
+/// Above this many cells the LCS table is not built and the changed middle of the texts
+/// is reported as removed and re-added wholesale
+const MAX_TABLE_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+impl DiffOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiffOp::Unchanged => "unchanged",
+            DiffOp::Added => "added",
+            DiffOp::Removed => "removed",
+        }
+    }
+}
+
+/// One line of a diff. Line numbers are 1-based; `old_line` is set for unchanged and
+/// removed lines, `new_line` for unchanged and added ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine<'a> {
+    pub op: DiffOp,
+    pub text: &'a str,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+}
+
+/// Diff `old` against `new`, line by line, in document order. Within a changed block
+/// removed lines come before added ones.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Lines shared at both ends need no table
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut ops = vec![DiffOp::Unchanged; prefix];
+    ops.extend(middle_ops(old_middle, new_middle));
+    ops.extend(std::iter::repeat_n(DiffOp::Unchanged, suffix));
+
+    let (mut old_index, mut new_index) = (0, 0);
+    ops.into_iter()
+        .map(|op| {
+            let line = match op {
+                DiffOp::Unchanged => DiffLine {
+                    op,
+                    text: old[old_index],
+                    old_line: Some(old_index + 1),
+                    new_line: Some(new_index + 1),
+                },
+                DiffOp::Removed => DiffLine {
+                    op,
+                    text: old[old_index],
+                    old_line: Some(old_index + 1),
+                    new_line: None,
+                },
+                DiffOp::Added => DiffLine {
+                    op,
+                    text: new[new_index],
+                    old_line: None,
+                    new_line: Some(new_index + 1),
+                },
+            };
+            if op != DiffOp::Added {
+                old_index += 1;
+            }
+            if op != DiffOp::Removed {
+                new_index += 1;
+            }
+            line
+        })
+        .collect()
+}
+
+// The operations turning `old` into `new`, for texts that differ in their first and
+// last lines
+fn middle_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let (n, m) = (old.len(), new.len());
+    if n == 0 || m == 0 || (n + 1) * (m + 1) > MAX_TABLE_CELLS {
+        let mut ops = vec![DiffOp::Removed; n];
+        ops.extend(std::iter::repeat_n(DiffOp::Added, m));
+        return ops;
+    }
+
+    // lcs[i][j]: length of the longest common subsequence of old[i..] and new[j..]
+    let width = m + 1;
+    let mut lcs = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Unchanged);
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            ops.push(DiffOp::Removed);
+            i += 1;
+        } else {
+            ops.push(DiffOp::Added);
+            j += 1;
+        }
+    }
+    ops.extend(std::iter::repeat_n(DiffOp::Removed, n - i));
+    ops.extend(std::iter::repeat_n(DiffOp::Added, m - j));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lines: &[DiffLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                let marker = match line.op {
+                    DiffOp::Unchanged => ' ',
+                    DiffOp::Added => '+',
+                    DiffOp::Removed => '-',
+                };
+                format!("{}{}", marker, line.text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_texts_are_unchanged() {
+        let lines = diff_lines("one\ntwo", "one\ntwo");
+        assert_eq!(render(&lines), vec![" one", " two"]);
+        assert_eq!(lines[1].old_line, Some(2));
+        assert_eq!(lines[1].new_line, Some(2));
+    }
+
+    #[test]
+    fn test_changed_line_in_the_middle() {
+        let lines = diff_lines("a\nb\nc\nd", "a\nB\nc\nd\ne");
+        assert_eq!(render(&lines), vec![" a", "-b", "+B", " c", " d", "+e"]);
+        assert_eq!(lines[2].old_line, None);
+        assert_eq!(lines[2].new_line, Some(2));
+        assert_eq!(lines[3].old_line, Some(3));
+        assert_eq!(lines[5].new_line, Some(5));
+    }
+
+    #[test]
+    fn test_keeps_longest_common_subsequence() {
+        let lines = diff_lines("x\na\nb\nc\ny", "z\nb\nc\na\nw");
+        let unchanged: Vec<_> = lines
+            .iter()
+            .filter(|line| line.op == DiffOp::Unchanged)
+            .map(|line| line.text)
+            .collect();
+        assert_eq!(unchanged, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_empty_sides() {
+        assert_eq!(render(&diff_lines("", "new")), vec!["+new"]);
+        assert_eq!(render(&diff_lines("old", "")), vec!["-old"]);
+        assert!(diff_lines("", "").is_empty());
+    }
+}
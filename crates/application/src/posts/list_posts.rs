@@ -1,41 +1,206 @@
 // crates/application/src/posts/list_posts.rs
 
 use domain::common::error::DomainError;
-use domain::posts::PostRepository;
+use domain::common::pagination::{PageKey, PageRequest};
+use domain::posts::{Post, PostRepository, Tag, TagFilter, TagMatch};
+use domain::users::UserRepository;
+use uuid::Uuid;
 
-use super::dtos::{ListPostsResponse, PostResponse};
+use super::dtos::{ListPostsResponse, post_response};
+use crate::pagination::paginate;
+use crate::users::load_profiles;
 
-pub struct ListPostsUseCase<R>
+pub struct ListPostsUseCase<R, U>
 where
     R: PostRepository,
+    U: UserRepository,
 {
     post_repository: R,
+    user_repository: U,
 }
 
-impl<R> ListPostsUseCase<R>
+impl<R, U> ListPostsUseCase<R, U>
 where
     R: PostRepository,
+    U: UserRepository,
 {
-    pub fn new(post_repository: R) -> Self {
-        Self { post_repository }
+    pub fn new(post_repository: R, user_repository: U) -> Self {
+        Self {
+            post_repository,
+            user_repository,
+        }
     }
 
-    pub async fn execute(&self, limit: i64, offset: i64) -> Result<ListPostsResponse, DomainError> {
-        let posts = self.post_repository.find_all(limit, offset).await?;
+    /// Published posts, newest first. When `tags` are given only posts with any of them
+    /// are listed, or with all of them if `tag_match` is "all".
+    pub async fn execute(
+        &self,
+        tags: Vec<String>,
+        tag_match: Option<String>,
+        page: PageRequest,
+    ) -> Result<ListPostsResponse, DomainError> {
+        let mode = tag_match
+            .as_deref()
+            .map(TagMatch::parse)
+            .transpose()?
+            .unwrap_or_default();
+        let filter = if tags.is_empty() {
+            None
+        } else {
+            let tags = tags
+                .into_iter()
+                .map(Tag::new)
+                .collect::<Result<Vec<_>, _>>()?;
+            Some(TagFilter::new(tags, mode)?)
+        };
+
+        let posts = self
+            .post_repository
+            .find_published(filter.as_ref(), &page.with_lookahead())
+            .await?;
+        let total = self
+            .post_repository
+            .count_published(filter.as_ref())
+            .await?;
+        self.to_response(&page, posts, total).await
+    }
+
+    /// All of the author's own posts, drafts and scheduled ones included
+    pub async fn execute_for_author(
+        &self,
+        author_id: Uuid,
+        page: PageRequest,
+    ) -> Result<ListPostsResponse, DomainError> {
+        let posts = self
+            .post_repository
+            .find_by_author(author_id, &page.with_lookahead())
+            .await?;
+        let total = self.post_repository.count_by_author(author_id).await?;
+        self.to_response(&page, posts, total).await
+    }
+
+    async fn to_response(
+        &self,
+        request: &PageRequest,
+        posts: Vec<Post>,
+        total: i64,
+    ) -> Result<ListPostsResponse, DomainError> {
+        let page = paginate(request, posts, |post| {
+            PageKey::new(post.created_at, post.id)
+        });
+        let posts = page.items;
         let count = posts.len();
 
+        // One lookup for all authors on the page
+        let authors = load_profiles(
+            &self.user_repository,
+            posts.iter().map(|post| post.author_id),
+        )
+        .await?;
+
         let posts = posts
             .into_iter()
-            .map(|post| PostResponse {
-                id: post.id.to_string(),
-                title: post.title.as_str().to_string(),
-                content: post.content.as_str().to_string(),
-                author_id: post.author_id.to_string(),
-                created_at: post.created_at.to_rfc3339(),
-                updated_at: post.updated_at.to_rfc3339(),
-            })
+            .map(|post| post_response(&post, authors.get(&post.author_id).cloned()))
             .collect();
 
-        Ok(ListPostsResponse { posts, count })
+        Ok(ListPostsResponse {
+            posts,
+            count,
+            total,
+            limit: request.limit(),
+            offset: request.offset(),
+            has_more: page.has_more,
+            next_cursor: page.next_cursor,
+            prev_cursor: page.prev_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockPostRepository, MockUserRepository};
+
+    use chrono::Utc;
+    use domain::posts::{PostContent, PostTitle};
+
+    fn published_post(title: &str, tags: &[&str]) -> Post {
+        let mut post = Post::new(
+            PostTitle::new(title).unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        );
+        post.set_tags(tags.iter().map(|tag| Tag::new(*tag).unwrap()).collect())
+            .unwrap();
+        post.publish(Utc::now()).unwrap();
+        post
+    }
+
+    fn use_case() -> ListPostsUseCase<MockPostRepository, MockUserRepository> {
+        let repo = MockPostRepository::with_posts(vec![
+            published_post("Both", &["rust", "web"]),
+            published_post("Rust only", &["rust"]),
+            published_post("Web only", &["web"]),
+            published_post("Untagged", &[]),
+        ]);
+        ListPostsUseCase::new(repo, MockUserRepository::default())
+    }
+
+    async fn titles(tags: &[&str], tag_match: Option<&str>) -> Vec<String> {
+        let response = use_case()
+            .execute(
+                tags.iter().map(|tag| tag.to_string()).collect(),
+                tag_match.map(str::to_string),
+                PageRequest::first(20),
+            )
+            .await
+            .unwrap();
+        let mut titles: Vec<String> = response.posts.into_iter().map(|p| p.title).collect();
+        titles.sort();
+        titles
+    }
+
+    #[tokio::test]
+    async fn test_list_posts_with_any_tag() {
+        assert_eq!(
+            titles(&["rust", "web"], None).await,
+            vec!["Both", "Rust only", "Web only"]
+        );
+        assert_eq!(
+            titles(&["rust", "web"], Some("any")).await,
+            vec!["Both", "Rust only", "Web only"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_posts_with_all_tags() {
+        assert_eq!(titles(&["rust", "web"], Some("all")).await, vec!["Both"]);
+        // A repeated tag is one tag
+        assert_eq!(
+            titles(&["rust", "Rust"], Some("all")).await,
+            vec!["Both", "Rust only"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_posts_tags_are_normalised() {
+        assert_eq!(titles(&[" RUST "], None).await, vec!["Both", "Rust only"]);
+        assert_eq!(
+            titles(&[], None).await,
+            vec!["Both", "Rust only", "Untagged", "Web only"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_posts_rejects_unknown_tag_match() {
+        let result = use_case()
+            .execute(
+                vec!["rust".to_string()],
+                Some("some".to_string()),
+                PageRequest::first(20),
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
     }
 }
@@ -0,0 +1,96 @@
+// crates/application/src/posts/list_tags.rs
+
+use domain::common::error::DomainError;
+use domain::posts::PostRepository;
+
+use super::dtos::{ListTagsResponse, TagResponse};
+
+pub struct ListTagsUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> ListTagsUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Tags in use on published posts, most used first
+    pub async fn execute(&self) -> Result<ListTagsResponse, DomainError> {
+        let tags: Vec<TagResponse> = self
+            .post_repository
+            .find_tag_counts()
+            .await?
+            .into_iter()
+            .map(|(tag, post_count)| TagResponse {
+                name: tag.as_str().to_string(),
+                post_count,
+            })
+            .collect();
+        let count = tags.len();
+
+        Ok(ListTagsResponse { tags, count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use chrono::Utc;
+    use domain::posts::{Post, PostContent, PostTitle, Tag};
+    use uuid::Uuid;
+
+    fn post(tags: &[&str], published: bool) -> Post {
+        let mut post = Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        );
+        post.set_tags(tags.iter().map(|tag| Tag::new(*tag).unwrap()).collect())
+            .unwrap();
+        if published {
+            post.publish(Utc::now()).unwrap();
+        }
+        post
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_counts_published_posts_most_used_first() {
+        let repo = MockPostRepository::with_posts(vec![
+            post(&["web"], true),
+            post(&["rust", "web"], true),
+            post(&["rust"], true),
+            post(&["cli", "rust"], true),
+            // Drafts don't count
+            post(&["draft-only", "web"], false),
+        ]);
+
+        let response = ListTagsUseCase::new(repo).execute().await.unwrap();
+
+        let tags: Vec<(&str, i64)> = response
+            .tags
+            .iter()
+            .map(|tag| (tag.name.as_str(), tag.post_count))
+            .collect();
+        assert_eq!(tags, vec![("rust", 3), ("web", 2), ("cli", 1)]);
+        assert_eq!(response.count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_empty() {
+        let response = ListTagsUseCase::new(MockPostRepository::default())
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(response.tags.is_empty());
+        assert_eq!(response.count, 0);
+    }
+}
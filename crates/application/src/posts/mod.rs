@@ -1,11 +1,39 @@
 // crates/application/src/posts/mod.rs
 
+mod backfill_post_slugs;
+mod change_post_status;
 mod create_post;
+mod delete_post;
 mod dtos;
 mod get_post;
+mod get_post_by_slug;
+mod line_diff;
 mod list_posts;
+mod list_tags;
+mod publish_scheduled_posts;
+mod revisions;
+mod search_posts;
+mod slugs;
+mod update_post;
 
+pub use backfill_post_slugs::BackfillPostSlugsUseCase;
+pub use change_post_status::ChangePostStatusUseCase;
 pub use create_post::CreatePostUseCase;
-pub use dtos::{CreatePostRequest, ListPostsResponse, PostResponse};
+pub use delete_post::DeletePostUseCase;
+pub(crate) use dtos::post_response;
+pub use dtos::{
+    ChangePostStatusRequest, CreatePostRequest, DiffLineResponse, ListPostRevisionsResponse,
+    ListPostsResponse, ListTagsResponse, PostBySlugResponse, PostResponse,
+    PostRevisionDiffResponse, PostRevisionResponse, SearchPostsResponse, SearchResultResponse,
+    TagResponse, UpdatePostRequest,
+};
 pub use get_post::GetPostUseCase;
+pub use get_post_by_slug::GetPostBySlugUseCase;
 pub use list_posts::ListPostsUseCase;
+pub use list_tags::ListTagsUseCase;
+pub use publish_scheduled_posts::PublishScheduledPostsUseCase;
+pub use revisions::{
+    DiffPostRevisionsUseCase, ListPostRevisionsUseCase, RestorePostRevisionUseCase,
+};
+pub use search_posts::SearchPostsUseCase;
+pub use update_post::UpdatePostUseCase;
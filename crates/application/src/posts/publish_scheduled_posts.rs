@@ -0,0 +1,127 @@
+// crates/application/src/posts/publish_scheduled_posts.rs
+
+use chrono::Utc;
+use domain::common::error::DomainError;
+use domain::posts::PostRepository;
+
+// Posts published per database round trip
+const PUBLISH_BATCH_SIZE: i64 = 100;
+
+pub struct PublishScheduledPostsUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> PublishScheduledPostsUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Publish every scheduled post that has come due; returns how many were published.
+    /// A post that fails to publish is logged and left for the next run.
+    pub async fn execute(&self) -> Result<usize, DomainError> {
+        let mut published = 0;
+        loop {
+            let now = Utc::now();
+            let posts = self
+                .post_repository
+                .find_due_scheduled(now, PUBLISH_BATCH_SIZE)
+                .await?;
+            let batch_size = posts.len();
+
+            let mut published_in_batch = 0;
+            for post in posts {
+                match self.post_repository.publish_if_due(post.id, now).await {
+                    Ok(true) => published_in_batch += 1,
+                    // Edited or unscheduled since it was fetched
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!(
+                        error = %e,
+                        post_id = %post.id,
+                        "Failed to publish scheduled post"
+                    ),
+                }
+            }
+            published += published_in_batch;
+
+            // A batch that published nothing would only come back the same
+            if (batch_size as i64) < PUBLISH_BATCH_SIZE || published_in_batch == 0 {
+                return Ok(published);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use chrono::{DateTime, Duration};
+
+    use domain::posts::{Post, PostContent, PostStatus, PostTitle};
+
+    use uuid::Uuid;
+
+    fn scheduled_post(publish_at: DateTime<Utc>) -> Post {
+        let mut post = Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        );
+        post.status = PostStatus::Scheduled;
+        post.published_at = Some(publish_at);
+        post
+    }
+
+    #[tokio::test]
+    async fn test_publishes_only_due_posts() {
+        let due_at = Utc::now() - Duration::minutes(5);
+        let due = scheduled_post(due_at);
+        let later = scheduled_post(Utc::now() + Duration::hours(1));
+        let (due_id, later_id) = (due.id, later.id);
+        let repo = MockPostRepository::with_posts(vec![due, later]);
+
+        let published = PublishScheduledPostsUseCase::new(repo.clone())
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(published, 1);
+
+        let due = repo.find_by_id(due_id).await.unwrap().unwrap();
+        assert_eq!(due.status, PostStatus::Published);
+        // The post counts as published when it was scheduled to be, not when the
+        // task came around
+        assert_eq!(due.published_at, Some(due_at));
+
+        let later = repo.find_by_id(later_id).await.unwrap().unwrap();
+        assert_eq!(later.status, PostStatus::Scheduled);
+    }
+
+    #[tokio::test]
+    async fn test_failed_post_does_not_stop_the_others() {
+        let due_at = Utc::now() - Duration::minutes(5);
+        let failing = scheduled_post(due_at);
+        let due = scheduled_post(due_at);
+        let (failing_id, due_id) = (failing.id, due.id);
+        let repo = MockPostRepository::with_posts(vec![failing, due]);
+        repo.failing_publishes.lock().unwrap().insert(failing_id);
+
+        let published = PublishScheduledPostsUseCase::new(repo.clone())
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(published, 1);
+
+        let due = repo.find_by_id(due_id).await.unwrap().unwrap();
+        assert_eq!(due.status, PostStatus::Published);
+        // Still due, so the next run tries it again
+        let failing = repo.find_by_id(failing_id).await.unwrap().unwrap();
+        assert_eq!(failing.status, PostStatus::Scheduled);
+    }
+}
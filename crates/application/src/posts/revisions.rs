@@ -0,0 +1,296 @@
+// crates/application/src/posts/revisions.rs
+
+use domain::common::error::DomainError;
+use domain::posts::{Post, PostRepository, PostRevision};
+use uuid::Uuid;
+
+use super::dtos::{
+    DiffLineResponse, ListPostRevisionsResponse, PostResponse, PostRevisionDiffResponse,
+    PostRevisionResponse, post_response,
+};
+use super::line_diff::{DiffLine, diff_lines};
+use super::slugs::refresh_slug;
+use crate::authorization::{Actor, Policy};
+
+pub struct ListPostRevisionsUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> ListPostRevisionsUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// The post's revisions, oldest first
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        actor: &Actor,
+    ) -> Result<ListPostRevisionsResponse, DomainError> {
+        find_history_post(&self.post_repository, post_id, actor).await?;
+
+        let revisions: Vec<PostRevisionResponse> = self
+            .post_repository
+            .find_revisions(post_id)
+            .await?
+            .iter()
+            .map(revision_response)
+            .collect();
+        let count = revisions.len();
+
+        Ok(ListPostRevisionsResponse { revisions, count })
+    }
+}
+
+pub struct DiffPostRevisionsUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> DiffPostRevisionsUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Line-level diff of the title and content of revision `from` against revision `to`
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        from: i32,
+        to: i32,
+        actor: &Actor,
+    ) -> Result<PostRevisionDiffResponse, DomainError> {
+        find_history_post(&self.post_repository, post_id, actor).await?;
+
+        let old = find_revision(&self.post_repository, post_id, from).await?;
+        let new = find_revision(&self.post_repository, post_id, to).await?;
+
+        Ok(PostRevisionDiffResponse {
+            post_id: post_id.to_string(),
+            from,
+            to,
+            title: diff_response(diff_lines(old.title.as_str(), new.title.as_str())),
+            content: diff_response(diff_lines(old.content.as_str(), new.content.as_str())),
+        })
+    }
+}
+
+pub struct RestorePostRevisionUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> RestorePostRevisionUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    /// Bring back the title and content of an earlier revision. The history is kept as
+    /// it is and the restore is recorded as a new revision.
+    pub async fn execute(
+        &self,
+        post_id: Uuid,
+        number: i32,
+        actor: &Actor,
+    ) -> Result<PostResponse, DomainError> {
+        let mut post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+
+        // Only the author may edit
+        Policy::edit_post(actor, &post)?;
+
+        let revision = find_revision(&self.post_repository, post_id, number).await?;
+        post.edit(Some(revision.title), Some(revision.content));
+        refresh_slug(&self.post_repository, &mut post).await?;
+
+        self.post_repository
+            .update_with_revision(post.clone(), actor.user_id, Some(number))
+            .await?;
+
+        Ok(post_response(&post, None))
+    }
+}
+
+// Unpublished posts are not found by anyone but their author, like when reading them
+async fn find_history_post<R: PostRepository>(
+    post_repository: &R,
+    post_id: Uuid,
+    actor: &Actor,
+) -> Result<Post, DomainError> {
+    let post = post_repository
+        .find_by_id(post_id)
+        .await?
+        .filter(|post| post.is_visible_to(Some(actor.user_id)))
+        .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+
+    Policy::view_post_history(actor, &post)?;
+    Ok(post)
+}
+
+async fn find_revision<R: PostRepository>(
+    post_repository: &R,
+    post_id: Uuid,
+    number: i32,
+) -> Result<PostRevision, DomainError> {
+    post_repository
+        .find_revision(post_id, number)
+        .await?
+        .ok_or_else(|| DomainError::NotFound(format!("Revision {} not found", number)))
+}
+
+fn revision_response(revision: &PostRevision) -> PostRevisionResponse {
+    PostRevisionResponse {
+        number: revision.number,
+        title: revision.title.as_str().to_string(),
+        content: revision.content.as_str().to_string(),
+        editor_id: revision.editor_id.to_string(),
+        restored_from: revision.restored_from,
+        created_at: revision.created_at.to_rfc3339(),
+    }
+}
+
+fn diff_response(lines: Vec<DiffLine>) -> Vec<DiffLineResponse> {
+    lines
+        .into_iter()
+        .map(|line| DiffLineResponse {
+            op: line.op.as_str().to_string(),
+            text: line.text.to_string(),
+            old_line: line.old_line,
+            new_line: line.new_line,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use chrono::Utc;
+
+    use domain::posts::{PostContent, PostStatus, PostTitle};
+    use domain::users::Role;
+
+    // A published post with two revisions: the original and one edit of the content
+    async fn edited_post(repo: &MockPostRepository, author_id: Uuid) -> Post {
+        let mut post = Post::new(
+            PostTitle::new("Title").unwrap(),
+            PostContent::new("first line\nsecond line").unwrap(),
+            author_id,
+        );
+        post.publish(Utc::now()).unwrap();
+        repo.save(post.clone()).await.unwrap();
+
+        post.edit(
+            None,
+            Some(PostContent::new("first line\nsecond line, edited").unwrap()),
+        );
+        repo.update_with_revision(post.clone(), author_id, None)
+            .await
+            .unwrap();
+        post
+    }
+
+    #[tokio::test]
+    async fn test_diff_revisions() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let repo = MockPostRepository::default();
+        let post = edited_post(&repo, author.user_id).await;
+
+        let diff = DiffPostRevisionsUseCase::new(repo)
+            .execute(post.id, 1, 2, &author)
+            .await
+            .unwrap();
+
+        assert_eq!(diff.title.len(), 1);
+        assert_eq!(diff.title[0].op, "unchanged");
+        let content: Vec<_> = diff
+            .content
+            .iter()
+            .map(|line| (line.op.as_str(), line.text.as_str()))
+            .collect();
+        assert_eq!(
+            content,
+            vec![
+                ("unchanged", "first line"),
+                ("removed", "second line"),
+                ("added", "second line, edited"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_history_is_limited_to_author_and_moderators() {
+        let author_id = Uuid::new_v4();
+        let repo = MockPostRepository::default();
+        let post = edited_post(&repo, author_id).await;
+        let use_case = ListPostRevisionsUseCase::new(repo);
+
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+        let listed = use_case.execute(post.id, &moderator).await.unwrap();
+        assert_eq!(listed.count, 2);
+        assert_eq!(listed.revisions[1].number, 2);
+
+        let other = Actor::new(Uuid::new_v4(), Role::User, true);
+        let result = use_case.execute(post.id, &other).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_creates_new_revision() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let repo = MockPostRepository::default();
+        let post = edited_post(&repo, author.user_id).await;
+
+        let restored = RestorePostRevisionUseCase::new(repo.clone())
+            .execute(post.id, 1, &author)
+            .await
+            .unwrap();
+
+        assert_eq!(restored.content, "first line\nsecond line");
+        let revisions = repo.find_revisions(post.id).await.unwrap();
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[2].number, 3);
+        assert_eq!(revisions[2].restored_from, Some(1));
+        assert_eq!(revisions[2].content.as_str(), "first line\nsecond line");
+        // Earlier revisions are left untouched
+        assert_eq!(
+            revisions[1].content.as_str(),
+            "first line\nsecond line, edited"
+        );
+        let stored = repo.find_by_id(post.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, PostStatus::Published);
+        assert_eq!(stored.content.as_str(), "first line\nsecond line");
+    }
+
+    #[tokio::test]
+    async fn test_restore_missing_revision() {
+        let author = Actor::new(Uuid::new_v4(), Role::User, true);
+        let repo = MockPostRepository::default();
+        let post = edited_post(&repo, author.user_id).await;
+
+        let result = RestorePostRevisionUseCase::new(repo)
+            .execute(post.id, 7, &author)
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+}
@@ -0,0 +1,241 @@
+// crates/application/src/posts/search_posts.rs
+
+use domain::common::error::DomainError;
+use domain::common::pagination::{PagePosition, PageRequest};
+use domain::posts::{PostSearchRepository, SearchQuery};
+use domain::users::UserRepository;
+
+use super::dtos::{SearchPostsResponse, SearchResultResponse, post_response};
+use crate::users::load_profiles;
+
+pub struct SearchPostsUseCase<S, U>
+where
+    S: PostSearchRepository,
+    U: UserRepository,
+{
+    search_repository: S,
+    user_repository: U,
+}
+
+impl<S, U> SearchPostsUseCase<S, U>
+where
+    S: PostSearchRepository,
+    U: UserRepository,
+{
+    pub fn new(search_repository: S, user_repository: U) -> Self {
+        Self {
+            search_repository,
+            user_repository,
+        }
+    }
+
+    /// Published posts matching `query` in their title or content, best matches first.
+    /// With `include_comments`, a post also matches through its comments, which count for
+    /// less than the post's own text. Results are paged by offset only, as they are
+    /// ordered by rank rather than time.
+    pub async fn execute(
+        &self,
+        query: String,
+        include_comments: bool,
+        page: PageRequest,
+    ) -> Result<SearchPostsResponse, DomainError> {
+        let query = SearchQuery::new(query, include_comments)?;
+        let PagePosition::Offset(offset) = page.position() else {
+            return Err(DomainError::Validation(
+                "Search results are paged by offset, not cursor".into(),
+            ));
+        };
+
+        let hits = self
+            .search_repository
+            .search(&query, page.limit(), offset)
+            .await?;
+        let total = self.search_repository.count(&query).await?;
+        let count = hits.len();
+
+        // One lookup for all authors on the page
+        let authors = load_profiles(
+            &self.user_repository,
+            hits.iter().map(|hit| hit.post.author_id),
+        )
+        .await?;
+
+        let results = hits
+            .into_iter()
+            .map(|hit| {
+                let post = hit.post;
+                SearchResultResponse {
+                    post: post_response(&post, authors.get(&post.author_id).cloned()),
+                    rank: hit.rank,
+                    title_snippet: hit.title_snippet,
+                    content_snippet: hit.content_snippet,
+                }
+            })
+            .collect();
+
+        Ok(SearchPostsResponse {
+            query: query.as_str().to_string(),
+            results,
+            count,
+            total,
+            limit: page.limit(),
+            offset,
+            has_more: offset + (count as i64) < total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockPostRepository, MockUserRepository};
+
+    use chrono::{Duration, Utc};
+    use domain::posts::{Post, PostContent, PostStatus, PostTitle};
+    use uuid::Uuid;
+
+    fn post(title: &str, content: &str, status: PostStatus) -> Post {
+        let mut post = Post::new(
+            PostTitle::new(title).unwrap(),
+            PostContent::new(content).unwrap(),
+            Uuid::new_v4(),
+        );
+        match status {
+            PostStatus::Draft => {}
+            PostStatus::Scheduled => post
+                .schedule(Utc::now() + Duration::days(1), Utc::now())
+                .unwrap(),
+            PostStatus::Published => post.publish(Utc::now()).unwrap(),
+            PostStatus::Archived => {
+                post.publish(Utc::now()).unwrap();
+                post.archive(Utc::now()).unwrap();
+            }
+        }
+        post
+    }
+
+    fn use_case(posts: Vec<Post>) -> SearchPostsUseCase<MockPostRepository, MockUserRepository> {
+        SearchPostsUseCase::new(
+            MockPostRepository::with_posts(posts),
+            MockUserRepository::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_search_rejects_empty_query() {
+        let use_case = use_case(Vec::new());
+
+        for query in ["", "   \t "] {
+            let result = use_case
+                .execute(query.to_string(), false, PageRequest::first(20))
+                .await;
+            assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_best_matches_first() {
+        let use_case = use_case(vec![
+            post("Cooking", "Rust is mentioned once", PostStatus::Published),
+            post(
+                "Rust",
+                "All about rust and more rust",
+                PostStatus::Published,
+            ),
+            post("Gardening", "Nothing to see", PostStatus::Published),
+            post("Rust in the title", "Only there", PostStatus::Published),
+        ]);
+
+        let response = use_case
+            .execute(" rust ".to_string(), false, PageRequest::first(20))
+            .await
+            .unwrap();
+
+        assert_eq!(response.query, "rust");
+        let titles: Vec<&str> = response
+            .results
+            .iter()
+            .map(|result| result.post.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Rust", "Rust in the title", "Cooking"]);
+        assert!(
+            response
+                .results
+                .windows(2)
+                .all(|pair| pair[0].rank >= pair[1].rank)
+        );
+        assert_eq!(response.total, 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_excludes_unpublished_posts() {
+        let use_case = use_case(vec![
+            post("Rust draft", "rust", PostStatus::Draft),
+            post("Rust scheduled", "rust", PostStatus::Scheduled),
+            post("Rust archived", "rust", PostStatus::Archived),
+            post("Rust published", "rust", PostStatus::Published),
+        ]);
+
+        let response = use_case
+            .execute("rust".to_string(), false, PageRequest::first(20))
+            .await
+            .unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.results[0].post.title, "Rust published");
+    }
+
+    #[tokio::test]
+    async fn test_search_snippets_escape_markup() {
+        let use_case = use_case(vec![post(
+            "<script> rust & co",
+            "Use <b> rust </b>",
+            PostStatus::Published,
+        )]);
+
+        let response = use_case
+            .execute("rust".to_string(), false, PageRequest::first(20))
+            .await
+            .unwrap();
+
+        let result = &response.results[0];
+        assert_eq!(
+            result.title_snippet,
+            "&lt;script&gt; <mark>rust</mark> &amp; co"
+        );
+        assert_eq!(
+            result.content_snippet,
+            "Use &lt;b&gt; <mark>rust</mark> &lt;/b&gt;"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_pages_by_offset_only() {
+        let use_case = use_case(
+            (0..3)
+                .map(|i| post(&format!("Rust {}", i), "rust", PostStatus::Published))
+                .collect(),
+        );
+
+        let page = PageRequest::parse(2, 100, 2, None, None).unwrap();
+        let response = use_case
+            .execute("rust".to_string(), false, page)
+            .await
+            .unwrap();
+        assert_eq!(response.count, 1);
+        assert_eq!(response.total, 3);
+        assert!(!response.has_more);
+
+        let response = use_case
+            .execute("rust".to_string(), false, PageRequest::first(2))
+            .await
+            .unwrap();
+        assert!(response.has_more);
+
+        let cursor = domain::common::pagination::PageKey::new(Utc::now(), Uuid::new_v4());
+        let result = use_case
+            .execute("rust".to_string(), false, PageRequest::after(cursor, 2))
+            .await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+}
@@ -0,0 +1,136 @@
+// crates/application/src/posts/slugs.rs
+
+use domain::common::error::DomainError;
+use domain::posts::{Post, PostRepository, PostSlug};
+
+/// Give the post a slug made from its title that no other post has or had: the title's
+/// words, followed by the first free suffix ("-2", "-3", ...) if they are taken
+pub(super) async fn assign_slug<R: PostRepository>(
+    post_repository: &R,
+    post: &mut Post,
+) -> Result<(), DomainError> {
+    let base = PostSlug::from_title(&post.title);
+    let taken = post_repository
+        .find_taken_slugs(&base, Some(post.id))
+        .await?;
+
+    let mut suffix = None;
+    post.set_slug(&base, suffix);
+    while taken.contains(&post.slug) {
+        suffix = Some(suffix.map_or(2, |n| n + 1));
+        post.set_slug(&base, suffix);
+    }
+    Ok(())
+}
+
+/// Give the post a slug matching its current title. A slug made from the same words is
+/// kept, so editing the title without changing its words leaves links alone.
+pub(super) async fn refresh_slug<R: PostRepository>(
+    post_repository: &R,
+    post: &mut Post,
+) -> Result<(), DomainError> {
+    if !post.slug_made_from(&PostSlug::from_title(&post.title)) {
+        assign_slug(post_repository, post).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use domain::posts::{PostContent, PostTitle};
+    use uuid::Uuid;
+
+    fn post(title: &str) -> Post {
+        Post::new(
+            PostTitle::new(title).unwrap(),
+            PostContent::new("Content").unwrap(),
+            Uuid::new_v4(),
+        )
+    }
+
+    // A post saved with the slug it would be given on creation
+    async fn saved_post(repo: &MockPostRepository, title: &str) -> Post {
+        let mut post = post(title);
+        assign_slug(repo, &mut post).await.unwrap();
+        repo.save(post.clone()).await.unwrap();
+        post
+    }
+
+    fn retitle(post: &mut Post, title: &str) {
+        post.edit(Some(PostTitle::new(title).unwrap()), None);
+    }
+
+    #[tokio::test]
+    async fn test_assign_slug_transliterates_title() {
+        let repo = MockPostRepository::default();
+
+        let post = saved_post(&repo, "Größe & Über!").await;
+        assert_eq!(post.slug.as_str(), "grosse-uber");
+        assert_eq!(post.slug_suffix, None);
+
+        let post = saved_post(&repo, "???").await;
+        assert_eq!(post.slug.as_str(), "post");
+    }
+
+    #[tokio::test]
+    async fn test_assign_slug_suffixes_taken_slugs() {
+        let repo = MockPostRepository::default();
+        saved_post(&repo, "Hello").await;
+        let second = saved_post(&repo, "Hello").await;
+        assert_eq!(second.slug.as_str(), "hello-2");
+        assert_eq!(second.slug_suffix, Some(2));
+
+        // A slug given up by a retitled post still leads to it, so it stays taken
+        let mut renamed = saved_post(&repo, "Greetings").await;
+        retitle(&mut renamed, "Farewell");
+        refresh_slug(&repo, &mut renamed).await.unwrap();
+        repo.update(renamed).await.unwrap();
+
+        let third = saved_post(&repo, "Hello").await;
+        assert_eq!(third.slug.as_str(), "hello-3");
+        let greetings = saved_post(&repo, "Greetings").await;
+        assert_eq!(greetings.slug.as_str(), "greetings-2");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_slug_keeps_slug_for_same_words() {
+        let repo = MockPostRepository::default();
+        saved_post(&repo, "Hello World").await;
+        let mut post = saved_post(&repo, "Hello World").await;
+
+        retitle(&mut post, "Hello, world!");
+        refresh_slug(&repo, &mut post).await.unwrap();
+
+        assert_eq!(post.slug.as_str(), "hello-world-2");
+        assert_eq!(post.slug_suffix, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_slug_follows_trailing_number_in_title() {
+        let repo = MockPostRepository::default();
+        let mut post = saved_post(&repo, "Rust 2024").await;
+
+        // "2024" is part of the title, not a suffix, so "rust-2024" no longer fits
+        retitle(&mut post, "Rust");
+        refresh_slug(&repo, &mut post).await.unwrap();
+        assert_eq!(post.slug.as_str(), "rust");
+        assert_eq!(post.slug_suffix, None);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_slug_of_suffixed_post_to_numbered_title() {
+        let repo = MockPostRepository::default();
+        saved_post(&repo, "Rust").await;
+        let mut post = saved_post(&repo, "Rust").await;
+        assert_eq!(post.slug.as_str(), "rust-2");
+
+        // The same slug, but now made from the title's own words
+        retitle(&mut post, "Rust 2");
+        refresh_slug(&repo, &mut post).await.unwrap();
+        assert_eq!(post.slug.as_str(), "rust-2");
+        assert_eq!(post.slug_suffix, None);
+    }
+}
@@ -0,0 +1,254 @@
+// crates/application/src/posts/update_post.rs
+
+use domain::common::error::DomainError;
+use domain::posts::{PostContent, PostRepository, PostTitle, Tag};
+use uuid::Uuid;
+
+use super::dtos::{PostResponse, UpdatePostRequest, post_response};
+use super::slugs::refresh_slug;
+use crate::authorization::{Actor, Policy};
+
+pub struct UpdatePostUseCase<R>
+where
+    R: PostRepository,
+{
+    post_repository: R,
+}
+
+impl<R> UpdatePostUseCase<R>
+where
+    R: PostRepository,
+{
+    pub fn new(post_repository: R) -> Self {
+        Self { post_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: UpdatePostRequest,
+        post_id: Uuid,
+        actor: &Actor,
+    ) -> Result<PostResponse, DomainError> {
+        let mut post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Post not found".into()))?;
+
+        // Only the author may edit
+        Policy::edit_post(actor, &post)?;
+
+        // Validate provided fields
+        let title = request.title.map(PostTitle::new).transpose()?;
+        let content = request.content.map(PostContent::new).transpose()?;
+        let tags = request
+            .tags
+            .map(|tags| {
+                tags.into_iter()
+                    .map(Tag::new)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        // Edits that change nothing leave the history alone
+        let changed = title.as_ref().is_some_and(|title| *title != post.title)
+            || content
+                .as_ref()
+                .is_some_and(|content| *content != post.content);
+
+        // Apply edit
+        post.edit(title, content);
+        refresh_slug(&self.post_repository, &mut post).await?;
+        if let Some(tags) = tags {
+            post.set_tags(tags)?;
+        }
+
+        // Persist changes, together with the new revision when there is one
+        if changed {
+            self.post_repository
+                .update_with_revision(post.clone(), actor.user_id, None)
+                .await?;
+        } else {
+            self.post_repository.update(post.clone()).await?;
+        }
+
+        Ok(post_response(&post, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockPostRepository;
+
+    use domain::posts::Post;
+    use domain::users::Role;
+
+    fn sample_post(author_id: Uuid) -> Post {
+        Post::new(
+            PostTitle::new("Original title").unwrap(),
+            PostContent::new("Original content").unwrap(),
+            author_id,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_update_post_success() {
+        let author_id = Uuid::new_v4();
+        let post = sample_post(author_id);
+        let post_id = post.id;
+        let original_updated_at = post.updated_at;
+        let repo = MockPostRepository::with_post(post);
+        let use_case = UpdatePostUseCase::new(repo.clone());
+
+        let request = UpdatePostRequest {
+            title: Some("Edited title".to_string()),
+            content: None,
+            tags: None,
+        };
+
+        let actor = Actor::new(author_id, Role::User, true);
+        let response = use_case.execute(request, post_id, &actor).await.unwrap();
+        assert_eq!(response.title, "Edited title");
+        assert_eq!(response.content, "Original content");
+
+        let stored = repo.find_by_id(post_id).await.unwrap().unwrap();
+        assert_eq!(stored.title.as_str(), "Edited title");
+        assert!(stored.updated_at >= original_updated_at);
+    }
+
+    #[tokio::test]
+    async fn test_update_post_records_revision_only_on_change() {
+        let author_id = Uuid::new_v4();
+        let post = sample_post(author_id);
+        let post_id = post.id;
+        let repo = MockPostRepository::with_post(post);
+        let use_case = UpdatePostUseCase::new(repo.clone());
+        let actor = Actor::new(author_id, Role::User, true);
+
+        let unchanged = UpdatePostRequest {
+            title: Some("Original title".to_string()),
+            content: None,
+            tags: None,
+        };
+        use_case.execute(unchanged, post_id, &actor).await.unwrap();
+        assert!(repo.find_revisions(post_id).await.unwrap().is_empty());
+
+        let edit = UpdatePostRequest {
+            title: None,
+            content: Some("Edited content".to_string()),
+            tags: None,
+        };
+        use_case.execute(edit, post_id, &actor).await.unwrap();
+        let revisions = repo.find_revisions(post_id).await.unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].content.as_str(), "Edited content");
+        assert_eq!(revisions[0].editor_id, author_id);
+    }
+
+    #[tokio::test]
+    async fn test_update_post_title_changes_slug() {
+        let author_id = Uuid::new_v4();
+        let post = sample_post(author_id);
+        let post_id = post.id;
+        let repo = MockPostRepository::with_post(post);
+        let use_case = UpdatePostUseCase::new(repo.clone());
+        let actor = Actor::new(author_id, Role::User, true);
+
+        let other = Post::new(
+            PostTitle::new("Edited title").unwrap(),
+            PostContent::new("Taken").unwrap(),
+            Uuid::new_v4(),
+        );
+        repo.save(other).await.unwrap();
+
+        // Same words, same slug
+        let request = UpdatePostRequest {
+            title: Some("Original Title!".to_string()),
+            content: None,
+            tags: None,
+        };
+        let response = use_case.execute(request, post_id, &actor).await.unwrap();
+        assert_eq!(response.slug, "original-title");
+
+        let request = UpdatePostRequest {
+            title: Some("Edited title".to_string()),
+            content: None,
+            tags: None,
+        };
+        let response = use_case.execute(request, post_id, &actor).await.unwrap();
+        assert_eq!(response.slug, "edited-title-2");
+    }
+
+    #[tokio::test]
+    async fn test_update_post_tags() {
+        let author_id = Uuid::new_v4();
+        let post = sample_post(author_id);
+        let post_id = post.id;
+        let repo = MockPostRepository::with_post(post);
+        let use_case = UpdatePostUseCase::new(repo.clone());
+        let actor = Actor::new(author_id, Role::User, true);
+
+        let request = UpdatePostRequest {
+            title: None,
+            content: None,
+            tags: Some(vec!["Web".to_string(), "databases".to_string()]),
+        };
+        let response = use_case.execute(request, post_id, &actor).await.unwrap();
+        assert_eq!(response.tags, vec!["databases", "web"]);
+
+        // Tags are kept unless replaced, and are not part of the revision history
+        let request = UpdatePostRequest {
+            title: Some("Edited title".to_string()),
+            content: None,
+            tags: None,
+        };
+        let response = use_case.execute(request, post_id, &actor).await.unwrap();
+        assert_eq!(response.tags, vec!["databases", "web"]);
+        assert_eq!(repo.find_revisions(post_id).await.unwrap().len(), 1);
+
+        let too_many = (0..=Post::MAX_TAGS).map(|i| format!("tag-{}", i)).collect();
+        let request = UpdatePostRequest {
+            title: None,
+            content: None,
+            tags: Some(too_many),
+        };
+        let result = use_case.execute(request, post_id, &actor).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_update_post_not_author() {
+        let post = sample_post(Uuid::new_v4());
+        let post_id = post.id;
+        let use_case = UpdatePostUseCase::new(MockPostRepository::with_post(post));
+
+        let request = UpdatePostRequest {
+            title: Some("Hijacked".to_string()),
+            content: None,
+            tags: None,
+        };
+
+        let actor = Actor::new(Uuid::new_v4(), Role::User, true);
+        let result = use_case.execute(request, post_id, &actor).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_update_post_empty_title() {
+        let author_id = Uuid::new_v4();
+        let post = sample_post(author_id);
+        let post_id = post.id;
+        let use_case = UpdatePostUseCase::new(MockPostRepository::with_post(post));
+
+        let request = UpdatePostRequest {
+            title: Some("".to_string()),
+            content: None,
+            tags: None,
+        };
+
+        let actor = Actor::new(author_id, Role::User, true);
+        let result = use_case.execute(request, post_id, &actor).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+}
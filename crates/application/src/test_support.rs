@@ -0,0 +1,1316 @@
+// crates/application/src/test_support.rs
+
+// In-memory stand-ins for the repositories and services that many use case tests share.
+// Each keeps its state behind an `Arc`, so the test can still inspect it after handing a
+// clone to the use case.
+
+use crate::users::{
+    AccessToken, AuthTokenIssuer, AuthTokens, ClientInfo, EmailMessage, EmailVerificationIssuer,
+    Mailer, MfaChallengeIssuer, MfaChallengeResponse, OpaqueTokenGenerator, PasswordVerifier,
+    TokenGenerator, TotpProvider,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::comments::{Comment, CommentRepository};
+use domain::common::error::DomainError;
+use domain::common::pagination::{PageKey, PagePosition, PageRequest};
+use domain::posts::{
+    Post, PostRepository, PostRevision, PostSearchHit, PostSearchRepository, PostSlug, PostStatus,
+    SearchQuery, Tag, TagFilter,
+};
+use domain::users::{
+    Email, EmailVerificationToken, EmailVerificationTokenRepository, LoginAttemptRepository,
+    LoginAttempts, PasswordHash, PasswordHasher, PasswordResetToken, PasswordResetTokenRepository,
+    PlainPassword, Profile, RefreshToken, RefreshTokenRepository, RevokedTokenRepository, Role,
+    Session, SessionRepository, TotpCredential, TotpCredentialRepository, User, UserRepository,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Newest first, as the Postgres repositories list published posts and an author's posts
+/// and comments, then cut down to `page`
+pub(crate) fn page_of<T>(items: Vec<T>, page: &PageRequest, key: impl Fn(&T) -> PageKey) -> Vec<T> {
+    keyset_page(items, page, key, true)
+}
+
+/// Oldest first, as the comments on a post are listed
+pub(crate) fn oldest_page_of<T>(
+    items: Vec<T>,
+    page: &PageRequest,
+    key: impl Fn(&T) -> PageKey,
+) -> Vec<T> {
+    keyset_page(items, page, key, false)
+}
+
+fn keyset_page<T>(
+    mut items: Vec<T>,
+    page: &PageRequest,
+    key: impl Fn(&T) -> PageKey,
+    newest_first: bool,
+) -> Vec<T> {
+    items.sort_by_key(|item| key(item));
+    if newest_first {
+        items.reverse();
+    }
+    // Whether `a` comes after `b` in the listing
+    let follows = |a: PageKey, b: PageKey| if newest_first { a < b } else { a > b };
+    let limit = page.limit() as usize;
+    match page.position() {
+        PagePosition::Offset(offset) => items
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit)
+            .collect(),
+        PagePosition::After(cursor) => items
+            .into_iter()
+            .filter(|item| follows(key(item), cursor))
+            .take(limit)
+            .collect(),
+        PagePosition::Before(cursor) => {
+            let mut earlier: Vec<T> = items
+                .into_iter()
+                .filter(|item| follows(cursor, key(item)))
+                .collect();
+            earlier.drain(..earlier.len().saturating_sub(limit));
+            earlier
+        }
+    }
+}
+
+fn not_found() -> DomainError {
+    DomainError::NotFound("User not found".into())
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockUserRepository {
+    pub users: Arc<Mutex<Vec<User>>>,
+    pub deleted: Arc<Mutex<Vec<Uuid>>>,
+    pub anonymized: Arc<Mutex<Vec<Uuid>>>,
+    // Users that fail to be deleted or anonymized, as if the database were down for them
+    pub failing_removals: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+impl MockUserRepository {
+    pub fn with_users(users: Vec<User>) -> Self {
+        Self {
+            users: Arc::new(Mutex::new(users)),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_user(user: User) -> Self {
+        Self::with_users(vec![user])
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<User> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.id == id)
+            .cloned()
+    }
+
+    fn modify(&self, id: Uuid, change: impl FnOnce(&mut User)) -> Result<(), DomainError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|u| u.id == id)
+            .ok_or_else(not_found)?;
+        change(user);
+        Ok(())
+    }
+
+    fn fail_removal(&self, id: Uuid) -> Result<(), DomainError> {
+        if self.failing_removals.lock().unwrap().contains(&id) {
+            return Err(DomainError::InfraError("Database error".into()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserRepository for MockUserRepository {
+    async fn save(&self, user: User) -> Result<(), DomainError> {
+        self.users.lock().unwrap().push(user);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError> {
+        Ok(self.get(id))
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, DomainError> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|u| ids.contains(&u.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.email == *email)
+            .cloned())
+    }
+
+    async fn update_role(&self, id: Uuid, role: Role) -> Result<(), DomainError> {
+        self.modify(id, |user| user.role = role)
+    }
+
+    async fn count_by_role(&self, role: Role) -> Result<i64, DomainError> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|u| u.role == role)
+            .count() as i64)
+    }
+
+    async fn mark_email_verified(&self, id: Uuid) -> Result<(), DomainError> {
+        self.modify(id, |user| user.email_verified = true)
+    }
+
+    async fn update_password(
+        &self,
+        id: Uuid,
+        password_hash: PasswordHash,
+    ) -> Result<(), DomainError> {
+        self.modify(id, |user| user.password_hash = Some(password_hash))
+    }
+
+    async fn update_email(&self, id: Uuid, email: &Email) -> Result<(), DomainError> {
+        if self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|u| u.id != id && u.email == *email)
+        {
+            return Err(DomainError::AlreadyExists(
+                "User with this email already exists".into(),
+            ));
+        }
+        self.modify(id, |user| {
+            user.email = email.clone();
+            user.email_verified = false;
+        })
+    }
+
+    async fn update_profile(&self, id: Uuid, profile: &Profile) -> Result<(), DomainError> {
+        self.modify(id, |user| user.profile = profile.clone())
+    }
+
+    async fn schedule_deletion(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.modify(id, |user| user.deletion_scheduled_for = Some(at))
+    }
+
+    async fn cancel_deletion(&self, id: Uuid) -> Result<bool, DomainError> {
+        let mut users = self.users.lock().unwrap();
+        Ok(users
+            .iter_mut()
+            .find(|u| u.id == id)
+            .and_then(|u| u.deletion_scheduled_for.take())
+            .is_some())
+    }
+
+    async fn find_due_for_deletion(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<User>, DomainError> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|u| u.deletion_scheduled_for.is_some_and(|at| at <= now))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.fail_removal(id)?;
+        self.users.lock().unwrap().retain(|u| u.id != id);
+        self.deleted.lock().unwrap().push(id);
+        Ok(())
+    }
+
+    async fn anonymize(&self, id: Uuid) -> Result<(), DomainError> {
+        self.fail_removal(id)?;
+        if let Some(user) = self.users.lock().unwrap().iter_mut().find(|u| u.id == id) {
+            user.profile = Profile::default();
+            user.deletion_scheduled_for = None;
+        }
+        self.anonymized.lock().unwrap().push(id);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockPostRepository {
+    pub posts: Arc<Mutex<Vec<Post>>>,
+    pub revisions: Arc<Mutex<Vec<PostRevision>>>,
+    // Slugs posts had before their title changed, with the post they lead to
+    pub slug_history: Arc<Mutex<Vec<(PostSlug, Uuid)>>>,
+    // Posts that fail to publish, as if the database were down for them
+    pub failing_publishes: Arc<Mutex<HashSet<Uuid>>>,
+    // Posts from before slugs existed, whose stored slug is only the one of their title
+    pub unslugged: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+impl MockPostRepository {
+    pub fn with_posts(posts: Vec<Post>) -> Self {
+        Self {
+            posts: Arc::new(Mutex::new(posts)),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_post(post: Post) -> Self {
+        Self::with_posts(vec![post])
+    }
+
+    /// Store a post the way the slug migration left existing ones
+    pub fn save_without_slug(&self, post: Post) {
+        self.unslugged.lock().unwrap().insert(post.id);
+        self.posts.lock().unwrap().push(post);
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<Post> {
+        self.posts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|p| p.id == id)
+            .cloned()
+    }
+
+    // Numbered after the post's latest revision, like the database does
+    fn record_revision(&self, post: &Post, editor_id: Uuid, restored_from: Option<i32>) {
+        let mut revisions = self.revisions.lock().unwrap();
+        let latest = revisions
+            .iter()
+            .filter(|r| r.post_id == post.id)
+            .map(|r| r.number)
+            .max()
+            .unwrap_or(0);
+        revisions.push(PostRevision::new(
+            post,
+            latest + 1,
+            editor_id,
+            restored_from,
+        ));
+    }
+
+    fn published(&self, tags: Option<&TagFilter>) -> Vec<Post> {
+        self.posts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.status == PostStatus::Published)
+            .filter(|p| tags.is_none_or(|filter| filter.matches(&p.tags)))
+            .cloned()
+            .collect()
+    }
+
+    fn by_author(&self, author_id: Uuid) -> Vec<Post> {
+        self.posts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.author_id == author_id)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl PostRepository for MockPostRepository {
+    async fn save(&self, post: Post) -> Result<(), DomainError> {
+        let author_id = post.author_id;
+        self.posts.lock().unwrap().push(post.clone());
+        self.record_revision(&post, author_id, None);
+        Ok(())
+    }
+
+    async fn update(&self, post: Post) -> Result<(), DomainError> {
+        let mut posts = self.posts.lock().unwrap();
+        let mut history = self.slug_history.lock().unwrap();
+        if let Some(existing) = posts.iter_mut().find(|p| p.id == post.id) {
+            history.retain(|(slug, _)| *slug != post.slug && *slug != existing.slug);
+            if existing.slug != post.slug {
+                history.push((existing.slug.clone(), post.id));
+            }
+            *existing = post;
+        }
+        Ok(())
+    }
+
+    async fn update_with_revision(
+        &self,
+        post: Post,
+        editor_id: Uuid,
+        restored_from: Option<i32>,
+    ) -> Result<(), DomainError> {
+        self.record_revision(&post, editor_id, restored_from);
+        self.update(post).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.posts.lock().unwrap().retain(|p| p.id != id);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError> {
+        Ok(self.get(id))
+    }
+
+    async fn find_by_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError> {
+        Ok(self
+            .posts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|p| p.slug == *slug && !self.unslugged.lock().unwrap().contains(&p.id))
+            .cloned())
+    }
+
+    async fn find_by_previous_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError> {
+        let post_id = self
+            .slug_history
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(old, _)| old == slug)
+            .map(|(_, post_id)| *post_id);
+        Ok(post_id.and_then(|id| self.get(id)))
+    }
+
+    async fn find_taken_slugs(
+        &self,
+        base: &PostSlug,
+        except_post: Option<Uuid>,
+    ) -> Result<Vec<PostSlug>, DomainError> {
+        // `base` itself or `base` followed by "-<digits>", as the database matches them
+        let matches = |slug: &PostSlug| match slug.as_str().strip_prefix(base.as_str()) {
+            Some(rest) => {
+                rest.is_empty()
+                    || rest
+                        .strip_prefix('-')
+                        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            }
+            None => false,
+        };
+        let current = self
+            .posts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| {
+                Some(p.id) != except_post && !self.unslugged.lock().unwrap().contains(&p.id)
+            })
+            .map(|p| p.slug.clone())
+            .collect::<Vec<_>>();
+        let previous = self
+            .slug_history
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, post_id)| Some(*post_id) != except_post)
+            .map(|(slug, _)| slug.clone())
+            .collect::<Vec<_>>();
+        Ok(current
+            .into_iter()
+            .chain(previous)
+            .filter(matches)
+            .collect())
+    }
+
+    async fn find_without_slug(&self, limit: i64) -> Result<Vec<Post>, DomainError> {
+        let unslugged = self.unslugged.lock().unwrap().clone();
+        let mut posts: Vec<Post> = self
+            .posts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| unslugged.contains(&p.id))
+            .cloned()
+            .collect();
+        posts.sort_by_key(|p| PageKey::new(p.created_at, p.id));
+        posts.truncate(limit as usize);
+        Ok(posts)
+    }
+
+    async fn backfill_slug(&self, post: &Post) -> Result<bool, DomainError> {
+        if !self.unslugged.lock().unwrap().remove(&post.id) {
+            return Ok(false);
+        }
+        for stored in self.posts.lock().unwrap().iter_mut() {
+            if stored.id == post.id {
+                stored.slug = post.slug.clone();
+                stored.slug_suffix = post.slug_suffix;
+            }
+        }
+        Ok(true)
+    }
+
+    async fn find_published(
+        &self,
+        tags: Option<&TagFilter>,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError> {
+        Ok(page_of(self.published(tags), page, |p| {
+            PageKey::new(p.created_at, p.id)
+        }))
+    }
+
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError> {
+        Ok(page_of(self.by_author(author_id), page, |p| {
+            PageKey::new(p.created_at, p.id)
+        }))
+    }
+
+    async fn count_published(&self, tags: Option<&TagFilter>) -> Result<i64, DomainError> {
+        Ok(self.published(tags).len() as i64)
+    }
+
+    async fn count_by_author(&self, author_id: Uuid) -> Result<i64, DomainError> {
+        Ok(self.by_author(author_id).len() as i64)
+    }
+
+    async fn find_due_scheduled(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Post>, DomainError> {
+        Ok(self
+            .posts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.status == PostStatus::Scheduled)
+            .filter(|p| p.published_at.is_some_and(|at| at <= now))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn publish_if_due(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, DomainError> {
+        if self.failing_publishes.lock().unwrap().contains(&id) {
+            return Err(DomainError::InfraError("Database error".into()));
+        }
+        let mut posts = self.posts.lock().unwrap();
+        let Some(post) = posts.iter_mut().find(|p| {
+            p.id == id
+                && p.status == PostStatus::Scheduled
+                && p.published_at.is_some_and(|at| at <= now)
+        }) else {
+            return Ok(false);
+        };
+        post.publish(now)?;
+        Ok(true)
+    }
+
+    async fn find_tag_counts(&self) -> Result<Vec<(Tag, i64)>, DomainError> {
+        let mut counts: Vec<(Tag, i64)> = Vec::new();
+        for post in self.published(None) {
+            for tag in post.tags {
+                match counts.iter_mut().find(|(t, _)| *t == tag) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((tag, 1)),
+                }
+            }
+        }
+        counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        Ok(counts)
+    }
+
+    async fn find_revisions(&self, post_id: Uuid) -> Result<Vec<PostRevision>, DomainError> {
+        Ok(self
+            .revisions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.post_id == post_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_revision(
+        &self,
+        post_id: Uuid,
+        number: i32,
+    ) -> Result<Option<PostRevision>, DomainError> {
+        Ok(self
+            .revisions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.post_id == post_id && r.number == number)
+            .cloned())
+    }
+}
+
+// Like the Postgres search: published posts only, best first, ranked by how often the
+// query's words occur with title words counting double. Snippets are the whole text,
+// escaped before the matching words are marked.
+#[async_trait]
+impl PostSearchRepository for MockPostRepository {
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PostSearchHit>, DomainError> {
+        let words: Vec<String> = query
+            .as_str()
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+        let occurrences = |text: &str| {
+            text.split(|c: char| !c.is_alphanumeric())
+                .filter(|word| words.contains(&word.to_lowercase()))
+                .count()
+        };
+        let highlight = |text: &str| {
+            let escaped = text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            words.iter().fold(escaped, |text, word| {
+                text.split(' ')
+                    .map(|part| {
+                        if part.to_lowercase() == *word {
+                            format!("<mark>{}</mark>", part)
+                        } else {
+                            part.to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+        };
+
+        let mut hits: Vec<PostSearchHit> = self
+            .published(None)
+            .into_iter()
+            .filter_map(|post| {
+                let rank =
+                    2 * occurrences(post.title.as_str()) + occurrences(post.content.as_str());
+                (rank > 0).then(|| PostSearchHit {
+                    rank: rank as f32,
+                    title_snippet: highlight(post.title.as_str()),
+                    content_snippet: highlight(post.content.as_str()),
+                    post,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.rank.total_cmp(&a.rank));
+        Ok(hits
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn count(&self, query: &SearchQuery) -> Result<i64, DomainError> {
+        Ok(self.search(query, i64::MAX, 0).await?.len() as i64)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockCommentRepository {
+    pub comments: Arc<Mutex<Vec<Comment>>>,
+}
+
+impl MockCommentRepository {
+    fn on_post(&self, post_id: Uuid) -> Vec<Comment> {
+        let mut comments: Vec<Comment> = self
+            .comments
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.post_id == post_id)
+            .cloned()
+            .collect();
+        comments.sort_by_key(|c| PageKey::new(c.created_at, c.id));
+        comments
+    }
+}
+
+#[async_trait]
+impl CommentRepository for MockCommentRepository {
+    async fn save(&self, comment: Comment) -> Result<(), DomainError> {
+        self.comments.lock().unwrap().push(comment);
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.comments
+            .lock()
+            .unwrap()
+            .retain(|c| c.id != id && c.parent_id != Some(id));
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, DomainError> {
+        Ok(self
+            .comments
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.id == id)
+            .cloned())
+    }
+
+    async fn find_by_post(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        Ok(oldest_page_of(self.on_post(post_id), page, |c| {
+            PageKey::new(c.created_at, c.id)
+        }))
+    }
+
+    async fn count_by_post(&self, post_id: Uuid) -> Result<i64, DomainError> {
+        Ok(self.on_post(post_id).len() as i64)
+    }
+
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let mine = self
+            .comments
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.author_id == author_id)
+            .cloned()
+            .collect();
+        Ok(page_of(mine, page, |c| PageKey::new(c.created_at, c.id)))
+    }
+
+    async fn find_thread(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let comments = self.on_post(post_id);
+        let roots: Vec<Comment> = comments
+            .iter()
+            .filter(|c| c.parent_id.is_none())
+            .cloned()
+            .collect();
+        let roots: Vec<Uuid> = oldest_page_of(roots, page, |c| PageKey::new(c.created_at, c.id))
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        // Replies come along with their root, however deep they sit
+        let mut thread: Vec<Uuid> = roots;
+        let mut grew = true;
+        while grew {
+            grew = false;
+            for comment in &comments {
+                if !thread.contains(&comment.id)
+                    && comment
+                        .parent_id
+                        .is_some_and(|parent| thread.contains(&parent))
+                {
+                    thread.push(comment.id);
+                    grew = true;
+                }
+            }
+        }
+        Ok(comments
+            .into_iter()
+            .filter(|c| thread.contains(&c.id))
+            .collect())
+    }
+
+    async fn count_threads(&self, post_id: Uuid) -> Result<i64, DomainError> {
+        Ok(self
+            .on_post(post_id)
+            .iter()
+            .filter(|c| c.parent_id.is_none())
+            .count() as i64)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockLoginAttemptRepository {
+    pub attempts: Arc<Mutex<HashMap<String, LoginAttempts>>>,
+}
+
+#[async_trait]
+impl LoginAttemptRepository for MockLoginAttemptRepository {
+    async fn find(&self, key: &str) -> Result<LoginAttempts, DomainError> {
+        Ok(self
+            .attempts
+            .lock()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn record_failure(&self, key: &str, _window: Duration) -> Result<u32, DomainError> {
+        let mut attempts = self.attempts.lock().unwrap();
+        let entry = attempts.entry(key.to_string()).or_default();
+        entry.failures += 1;
+        Ok(entry.failures)
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        let mut attempts = self.attempts.lock().unwrap();
+        attempts.entry(key.to_string()).or_default().locked_until = Some(until);
+        Ok(())
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), DomainError> {
+        self.attempts.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn purge_stale(&self, _before: DateTime<Utc>) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+}
+
+/// Remembers every kind of revocation and answers `is_revoked` from them
+#[derive(Clone, Default)]
+pub(crate) struct MockRevokedTokenRepository {
+    pub tokens: Arc<Mutex<HashSet<Uuid>>>,
+    pub cutoffs: Arc<Mutex<HashMap<Uuid, DateTime<Utc>>>>,
+    pub sessions: Arc<Mutex<HashMap<Uuid, DateTime<Utc>>>>,
+}
+
+impl MockRevokedTokenRepository {
+    /// Users who had all of their tokens revoked
+    pub fn revoked_users(&self) -> Vec<Uuid> {
+        self.cutoffs.lock().unwrap().keys().copied().collect()
+    }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for MockRevokedTokenRepository {
+    async fn revoke(&self, token_id: Uuid, _expires_at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().insert(token_id);
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        issued_before: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        self.cutoffs.lock().unwrap().insert(user_id, issued_before);
+        Ok(())
+    }
+
+    async fn revoke_session(
+        &self,
+        session_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        self.sessions.lock().unwrap().insert(session_id, expires_at);
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        token_id: Uuid,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+        issued_at: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        let token_revoked = self.tokens.lock().unwrap().contains(&token_id);
+        let session_revoked =
+            session_id.is_some_and(|id| self.sessions.lock().unwrap().contains_key(&id));
+        let user_revoked = self
+            .cutoffs
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .is_some_and(|cutoff| *cutoff > issued_at);
+        Ok(token_revoked || session_revoked || user_revoked)
+    }
+
+    async fn purge_expired(&self) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+}
+
+/// Issues "token_<user id>" access tokens and refuses every refresh token
+#[derive(Clone, Default)]
+pub(crate) struct MockTokenIssuer {
+    pub revoked_users: Arc<Mutex<Vec<Uuid>>>,
+    pub revoked_refresh_tokens: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl AuthTokenIssuer for MockTokenIssuer {
+    async fn issue(&self, user: &User, _client: &ClientInfo) -> Result<AuthTokens, DomainError> {
+        Ok(AuthTokens {
+            access_token: format!("token_{}", user.id),
+            access_token_expires_at: Utc::now(),
+            refresh_token: format!("refresh_{}", user.id),
+            refresh_token_expires_at: Utc::now(),
+        })
+    }
+
+    async fn rotate(&self, user: &User, _session_id: Uuid) -> Result<AuthTokens, DomainError> {
+        self.issue(user, &ClientInfo::default()).await
+    }
+
+    async fn redeem(&self, _refresh_token: &str) -> Result<RefreshToken, DomainError> {
+        Err(DomainError::Unauthorized("Invalid refresh token".into()))
+    }
+
+    async fn revoke(&self, _user_id: Uuid, refresh_token: &str) -> Result<(), DomainError> {
+        self.revoked_refresh_tokens
+            .lock()
+            .unwrap()
+            .push(refresh_token.to_string());
+        Ok(())
+    }
+
+    async fn revoke_all(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.revoked_users.lock().unwrap().push(user_id);
+        Ok(())
+    }
+}
+
+/// Refresh tokens kept in a list, with revocation marked on the record
+#[derive(Clone, Default)]
+pub(crate) struct MockRefreshTokenRepository {
+    pub tokens: Arc<Mutex<Vec<RefreshToken>>>,
+}
+
+#[async_trait]
+impl RefreshTokenRepository for MockRefreshTokenRepository {
+    async fn save(&self, token: RefreshToken) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().push(token);
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, DomainError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool, DomainError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        match tokens
+            .iter_mut()
+            .find(|t| t.id == id && t.revoked_at.is_none())
+        {
+            Some(token) => {
+                token.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), DomainError> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.family_id == family_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<(), DomainError> {
+        for token in self.tokens.lock().unwrap().iter_mut() {
+            if token.user_id == user_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockSessionRepository {
+    pub sessions: Arc<Mutex<Vec<Session>>>,
+}
+
+#[async_trait]
+impl SessionRepository for MockSessionRepository {
+    async fn save(&self, session: Session) -> Result<(), DomainError> {
+        self.sessions.lock().unwrap().push(session);
+        Ok(())
+    }
+
+    async fn find_active_by_user(&self, user_id: Uuid) -> Result<Vec<Session>, DomainError> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|session| session.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), DomainError> {
+        for session in self.sessions.lock().unwrap().iter_mut() {
+            if session.id == id {
+                session.last_seen_at = seen_at;
+            }
+        }
+        Ok(())
+    }
+
+    async fn purge_inactive(&self) -> Result<u64, DomainError> {
+        Ok(0)
+    }
+}
+
+/// Access tokens named "token_<user id>", valid for 15 minutes
+#[derive(Clone, Copy)]
+pub(crate) struct MockTokenGenerator;
+
+impl TokenGenerator for MockTokenGenerator {
+    fn generate(&self, user: &User, _session_id: Uuid) -> Result<AccessToken, DomainError> {
+        Ok(AccessToken {
+            token: format!("token_{}", user.id),
+            expires_at: Utc::now() + Duration::minutes(15),
+        })
+    }
+}
+
+/// Hashes "password" to "hashed_password"
+#[derive(Clone, Copy)]
+pub(crate) struct MockPasswordHasher;
+
+#[async_trait]
+impl PasswordHasher for MockPasswordHasher {
+    async fn hash(&self, password: PlainPassword) -> Result<PasswordHash, DomainError> {
+        Ok(PasswordHash::new(format!("hashed_{}", password.as_str())))
+    }
+}
+
+/// Accepts a password against the hash `MockPasswordHasher` makes of it
+#[derive(Clone, Copy)]
+pub(crate) struct MockPasswordVerifier;
+
+#[async_trait]
+impl PasswordVerifier for MockPasswordVerifier {
+    async fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError> {
+        Ok(password_hash == format!("hashed_{}", plain_password))
+    }
+
+    async fn verify_dummy(&self, _plain_password: &str) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+/// Random tokens, hashed to "hash_<token>"
+#[derive(Clone, Copy)]
+pub(crate) struct MockOpaqueTokenGenerator;
+
+impl OpaqueTokenGenerator for MockOpaqueTokenGenerator {
+    fn generate(&self) -> String {
+        Uuid::new_v4().simple().to_string()
+    }
+
+    fn hash(&self, token: &str) -> String {
+        format!("hash_{}", token)
+    }
+}
+
+/// Two-factor credentials keyed by user, with each recovery code hash stored against its
+/// user until it is used
+#[derive(Clone, Default)]
+pub(crate) struct MockTotpCredentialRepository {
+    pub credentials: Arc<Mutex<HashMap<Uuid, TotpCredential>>>,
+    pub recovery_codes: Arc<Mutex<Vec<(Uuid, String)>>>,
+}
+
+#[async_trait]
+impl TotpCredentialRepository for MockTotpCredentialRepository {
+    async fn save(&self, credential: TotpCredential) -> Result<(), DomainError> {
+        self.credentials
+            .lock()
+            .unwrap()
+            .insert(credential.user_id, credential);
+        Ok(())
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<TotpCredential>, DomainError> {
+        Ok(self.credentials.lock().unwrap().get(&user_id).cloned())
+    }
+
+    async fn confirm(
+        &self,
+        user_id: Uuid,
+        recovery_code_hashes: &[String],
+    ) -> Result<(), DomainError> {
+        let mut credentials = self.credentials.lock().unwrap();
+        let credential = credentials
+            .get_mut(&user_id)
+            .ok_or_else(|| DomainError::NotFound("No two-factor enrolment is pending".into()))?;
+        credential.confirmed_at = Some(Utc::now());
+
+        let mut codes = self.recovery_codes.lock().unwrap();
+        codes.retain(|(id, _)| *id != user_id);
+        codes.extend(
+            recovery_code_hashes
+                .iter()
+                .map(|hash| (user_id, hash.clone())),
+        );
+        Ok(())
+    }
+
+    async fn use_step(&self, user_id: Uuid, step: i64) -> Result<bool, DomainError> {
+        let mut credentials = self.credentials.lock().unwrap();
+        let Some(credential) = credentials.get_mut(&user_id) else {
+            return Ok(false);
+        };
+        if credential.last_used_step.is_some_and(|used| used >= step) {
+            return Ok(false);
+        }
+        credential.last_used_step = Some(step);
+        Ok(true)
+    }
+
+    async fn use_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool, DomainError> {
+        let mut codes = self.recovery_codes.lock().unwrap();
+        let before = codes.len();
+        codes.retain(|(id, hash)| !(*id == user_id && hash == code_hash));
+        Ok(codes.len() < before)
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.credentials.lock().unwrap().remove(&user_id);
+        self.recovery_codes
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| *id != user_id);
+        Ok(())
+    }
+}
+
+/// Secret "SECRET"; accepts "123456" in step 1 and "654321" in step 2, and numbers the
+/// recovery codes it makes
+#[derive(Clone, Default)]
+pub(crate) struct MockTotpProvider {
+    pub recovery_codes: Arc<Mutex<u32>>,
+}
+
+impl TotpProvider for MockTotpProvider {
+    fn generate_secret(&self) -> String {
+        "SECRET".to_string()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> String {
+        format!("otpauth://totp/Test:{}?secret={}", account_name, secret)
+    }
+
+    fn verify(&self, _secret: &str, code: &str, _now: DateTime<Utc>) -> Option<i64> {
+        match code {
+            "123456" => Some(1),
+            "654321" => Some(2),
+            _ => None,
+        }
+    }
+
+    fn generate_recovery_code(&self) -> String {
+        let mut counter = self.recovery_codes.lock().unwrap();
+        *counter += 1;
+        format!("RECOVERY-{}", counter)
+    }
+}
+
+/// Login challenges: with `enabled` every login is challenged with "mfa_token", and with a
+/// `user_id` that token is outstanding for the user and answered by "123456"
+#[derive(Clone, Default)]
+pub(crate) struct MockMfa {
+    pub enabled: bool,
+    pub user_id: Option<Uuid>,
+}
+
+#[async_trait]
+impl MfaChallengeIssuer for MockMfa {
+    async fn challenge(&self, _user: &User) -> Result<Option<MfaChallengeResponse>, DomainError> {
+        Ok(self.enabled.then(|| MfaChallengeResponse {
+            mfa_token: "mfa_token".to_string(),
+            mfa_token_expires_at: Utc::now().to_rfc3339(),
+        }))
+    }
+
+    async fn challenged_user(&self, mfa_token: &str) -> Result<Option<Uuid>, DomainError> {
+        Ok(self.user_id.filter(|_| mfa_token == "mfa_token"))
+    }
+
+    async fn redeem(&self, mfa_token: &str, code: &str) -> Result<Uuid, DomainError> {
+        let Some(user_id) = self.user_id.filter(|_| mfa_token == "mfa_token") else {
+            return Err(DomainError::Unauthorized(
+                "Invalid or expired MFA token".into(),
+            ));
+        };
+        if code != "123456" {
+            return Err(DomainError::Unauthorized("Invalid code".into()));
+        }
+        Ok(user_id)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockEmailVerificationTokenRepository {
+    pub tokens: Arc<Mutex<Vec<EmailVerificationToken>>>,
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepository for MockEmailVerificationTokenRepository {
+    async fn save(&self, token: EmailVerificationToken) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().push(token);
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailVerificationToken>, DomainError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn consume(&self, id: Uuid) -> Result<bool, DomainError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        match tokens
+            .iter_mut()
+            .find(|t| t.id == id && t.used_at.is_none())
+        {
+            Some(token) => {
+                token.used_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_for_user(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().retain(|t| t.user_id != user_id);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MockPasswordResetTokenRepository {
+    pub tokens: Arc<Mutex<Vec<PasswordResetToken>>>,
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for MockPasswordResetTokenRepository {
+    async fn save(&self, token: PasswordResetToken) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().push(token);
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, DomainError> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn consume(&self, id: Uuid) -> Result<bool, DomainError> {
+        let mut tokens = self.tokens.lock().unwrap();
+        match tokens
+            .iter_mut()
+            .find(|t| t.id == id && t.used_at.is_none())
+        {
+            Some(token) => {
+                token.used_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete_for_user(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.tokens.lock().unwrap().retain(|t| t.user_id != user_id);
+        Ok(())
+    }
+}
+
+/// Keeps every message instead of sending it
+#[derive(Clone, Default)]
+pub(crate) struct MockMailer {
+    pub sent: Arc<Mutex<Vec<EmailMessage>>>,
+}
+
+impl MockMailer {
+    /// Pull the token back out of the last mailed link
+    pub fn last_token(&self) -> String {
+        let sent = self.sent.lock().unwrap();
+        let body = &sent.last().unwrap().body;
+        let start = body.find("token=").unwrap() + "token=".len();
+        body[start..].split_whitespace().next().unwrap().to_string()
+    }
+}
+
+#[async_trait]
+impl Mailer for MockMailer {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError> {
+        self.sent.lock().unwrap().push(message);
+        Ok(())
+    }
+}
+
+/// Records who was sent a verification email; no token can be redeemed
+#[derive(Clone, Default)]
+pub(crate) struct MockEmailVerification {
+    pub sent_to: Arc<Mutex<Vec<User>>>,
+}
+
+#[async_trait]
+impl EmailVerificationIssuer for MockEmailVerification {
+    async fn send(&self, user: &User) -> Result<(), DomainError> {
+        self.sent_to.lock().unwrap().push(user.clone());
+        Ok(())
+    }
+
+    async fn redeem(&self, _token: &str) -> Result<EmailVerificationToken, DomainError> {
+        Err(DomainError::Validation(
+            "Invalid or expired verification token".into(),
+        ))
+    }
+}
@@ -0,0 +1,494 @@
+// crates/application/src/users/api_keys.rs
+
+use chrono::{Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{ApiKey, ApiKeyRepository, ApiKeyScope, User, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{ApiKeyResponse, CreateApiKeyRequest, CreateApiKeyResponse};
+use super::token_issuer::OpaqueTokenGenerator;
+
+// Keys look like `ferro_<prefix>_<secret>`; the prefix finds the record
+const KEY_MARKER: &str = "ferro_";
+const PREFIX_LENGTH: usize = 12;
+// Active keys a user may hold at once
+const MAX_ACTIVE_KEYS: usize = 20;
+// Longest lifetime that can be asked for, in days
+const MAX_EXPIRES_IN_DAYS: u32 = 365;
+// How stale `last_used_at` may get before a request records the new time, so that a
+// busy script does not write to the database on every call
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+pub struct CreateApiKeyUseCase<R, K, O>
+where
+    R: UserRepository,
+    K: ApiKeyRepository,
+    O: OpaqueTokenGenerator,
+{
+    user_repository: R,
+    api_key_repository: K,
+    opaque_token_generator: O,
+}
+
+impl<R, K, O> CreateApiKeyUseCase<R, K, O>
+where
+    R: UserRepository,
+    K: ApiKeyRepository,
+    O: OpaqueTokenGenerator,
+{
+    pub fn new(user_repository: R, api_key_repository: K, opaque_token_generator: O) -> Self {
+        Self {
+            user_repository,
+            api_key_repository,
+            opaque_token_generator,
+        }
+    }
+
+    /// Create a key for the user. The key is only ever returned here.
+    pub async fn execute(
+        &self,
+        request: CreateApiKeyRequest,
+        user_id: Uuid,
+    ) -> Result<CreateApiKeyResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        let scopes = request
+            .scopes
+            .iter()
+            .map(|scope| ApiKeyScope::parse(scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expires_at = match request.expires_in_days {
+            None => None,
+            Some(days) if (1..=MAX_EXPIRES_IN_DAYS).contains(&days) => {
+                Some(Utc::now() + Duration::days(i64::from(days)))
+            }
+            Some(_) => {
+                return Err(DomainError::Validation(format!(
+                    "expires_in_days must be between 1 and {}",
+                    MAX_EXPIRES_IN_DAYS
+                )));
+            }
+        };
+
+        let active = self
+            .api_key_repository
+            .find_by_user(user.id)
+            .await?
+            .iter()
+            .filter(|key| !key.is_revoked() && !key.is_expired())
+            .count();
+        if active >= MAX_ACTIVE_KEYS {
+            return Err(DomainError::Validation(format!(
+                "You can have at most {} active API keys; revoke one first",
+                MAX_ACTIVE_KEYS
+            )));
+        }
+
+        let prefix: String = self
+            .opaque_token_generator
+            .generate()
+            .chars()
+            .take(PREFIX_LENGTH)
+            .collect();
+        let key = format!(
+            "{}{}_{}",
+            KEY_MARKER,
+            prefix,
+            self.opaque_token_generator.generate()
+        );
+        let api_key = ApiKey::new(
+            user.id,
+            request.name,
+            prefix,
+            self.opaque_token_generator.hash(&key),
+            scopes,
+            expires_at,
+        )?;
+        self.api_key_repository.save(api_key.clone()).await?;
+
+        Ok(CreateApiKeyResponse {
+            key,
+            api_key: api_key_response(&api_key),
+        })
+    }
+}
+
+pub struct ListApiKeysUseCase<K>
+where
+    K: ApiKeyRepository,
+{
+    api_key_repository: K,
+}
+
+impl<K> ListApiKeysUseCase<K>
+where
+    K: ApiKeyRepository,
+{
+    pub fn new(api_key_repository: K) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// The user's keys, newest first, without the keys themselves
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ApiKeyResponse>, DomainError> {
+        let keys = self.api_key_repository.find_by_user(user_id).await?;
+        Ok(keys.iter().map(api_key_response).collect())
+    }
+}
+
+pub struct RevokeApiKeyUseCase<K>
+where
+    K: ApiKeyRepository,
+{
+    api_key_repository: K,
+}
+
+impl<K> RevokeApiKeyUseCase<K>
+where
+    K: ApiKeyRepository,
+{
+    pub fn new(api_key_repository: K) -> Self {
+        Self { api_key_repository }
+    }
+
+    /// Revoke one of the user's keys. Other users' keys are reported as not found.
+    pub async fn execute(&self, key_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        if !self.api_key_repository.revoke(key_id, user_id).await? {
+            return Err(DomainError::NotFound("API key not found".into()));
+        }
+        Ok(())
+    }
+}
+
+pub struct AuthenticateApiKeyUseCase<R, K, O>
+where
+    R: UserRepository,
+    K: ApiKeyRepository,
+    O: OpaqueTokenGenerator,
+{
+    user_repository: R,
+    api_key_repository: K,
+    opaque_token_generator: O,
+}
+
+impl<R, K, O> AuthenticateApiKeyUseCase<R, K, O>
+where
+    R: UserRepository,
+    K: ApiKeyRepository,
+    O: OpaqueTokenGenerator,
+{
+    pub fn new(user_repository: R, api_key_repository: K, opaque_token_generator: O) -> Self {
+        Self {
+            user_repository,
+            api_key_repository,
+            opaque_token_generator,
+        }
+    }
+
+    /// Resolve a presented key to its owner, provided the key grants `scope`.
+    /// Unknown, revoked and expired keys are all reported the same way.
+    pub async fn execute(&self, key: &str, scope: ApiKeyScope) -> Result<User, DomainError> {
+        let invalid = || DomainError::Unauthorized("Invalid API key".into());
+
+        let prefix = key
+            .strip_prefix(KEY_MARKER)
+            .and_then(|rest| rest.split_once('_'))
+            .map(|(prefix, _)| prefix)
+            .filter(|prefix| prefix.len() == PREFIX_LENGTH)
+            .ok_or_else(invalid)?;
+
+        let api_key = self
+            .api_key_repository
+            .find_by_prefix(prefix)
+            .await?
+            .filter(|api_key| api_key.key_hash == self.opaque_token_generator.hash(key))
+            .filter(|api_key| !api_key.is_revoked() && !api_key.is_expired())
+            .ok_or_else(invalid)?;
+
+        if !api_key.has_scope(scope) {
+            return Err(DomainError::Forbidden(format!(
+                "API key does not have the {} scope",
+                scope.as_str()
+            )));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(api_key.user_id)
+            .await?
+            .ok_or_else(invalid)?;
+
+        let now = Utc::now();
+        if api_key
+            .last_used_at
+            .is_none_or(|at| now - at >= Duration::seconds(LAST_USED_RESOLUTION_SECONDS))
+        {
+            self.api_key_repository.touch(api_key.id, now).await?;
+        }
+
+        Ok(user)
+    }
+}
+
+fn api_key_response(api_key: &ApiKey) -> ApiKeyResponse {
+    ApiKeyResponse {
+        id: api_key.id.to_string(),
+        name: api_key.name.clone(),
+        prefix: api_key.prefix.clone(),
+        scopes: api_key
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str().to_string())
+            .collect(),
+        expires_at: api_key.expires_at.map(|at| at.to_rfc3339()),
+        last_used_at: api_key.last_used_at.map(|at| at.to_rfc3339()),
+        created_at: api_key.created_at.to_rfc3339(),
+        revoked_at: api_key.revoked_at.map(|at| at.to_rfc3339()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockOpaqueTokenGenerator, MockUserRepository};
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use domain::users::{Email, PasswordHash};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct MockApiKeyRepository {
+        keys: Arc<Mutex<Vec<ApiKey>>>,
+        touched: Arc<Mutex<Vec<Uuid>>>,
+    }
+
+    #[async_trait]
+    impl ApiKeyRepository for MockApiKeyRepository {
+        async fn save(&self, key: ApiKey) -> Result<(), DomainError> {
+            self.keys.lock().unwrap().push(key);
+            Ok(())
+        }
+
+        async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError> {
+            Ok(self
+                .keys
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|key| key.prefix == prefix)
+                .cloned())
+        }
+
+        async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, DomainError> {
+            Ok(self
+                .keys
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|key| key.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+            let mut keys = self.keys.lock().unwrap();
+            match keys
+                .iter_mut()
+                .find(|key| key.id == id && key.user_id == user_id && !key.is_revoked())
+            {
+                Some(key) => {
+                    key.revoked_at = Some(Utc::now());
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), DomainError> {
+            if let Some(key) = self
+                .keys
+                .lock()
+                .unwrap()
+                .iter_mut()
+                .find(|key| key.id == id)
+            {
+                key.last_used_at = Some(used_at);
+            }
+            self.touched.lock().unwrap().push(id);
+            Ok(())
+        }
+    }
+
+    fn test_user() -> User {
+        User::new(
+            Email::new("script@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        )
+    }
+
+    fn create_request(scopes: &[&str]) -> CreateApiKeyRequest {
+        CreateApiKeyRequest {
+            name: "  Deploy script ".into(),
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+            expires_in_days: Some(30),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_authenticate() {
+        let user = test_user();
+        let keys = MockApiKeyRepository::default();
+        let create = CreateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+        let authenticate = AuthenticateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+
+        let created = create
+            .execute(create_request(&["posts:write"]), user.id)
+            .await
+            .unwrap();
+        assert!(
+            created
+                .key
+                .starts_with(&format!("ferro_{}_", created.api_key.prefix))
+        );
+        assert_eq!(created.api_key.name, "Deploy script");
+        assert_eq!(created.api_key.scopes, vec!["posts:write"]);
+        assert!(created.api_key.expires_at.is_some());
+        // Only the hash is stored
+        let stored = keys.keys.lock().unwrap()[0].clone();
+        assert_eq!(stored.key_hash, format!("hash_{}", created.key));
+
+        let owner = authenticate
+            .execute(&created.key, ApiKeyScope::PostsWrite)
+            .await
+            .unwrap();
+        assert_eq!(owner.id, user.id);
+        assert!(keys.keys.lock().unwrap()[0].last_used_at.is_some());
+
+        // A second use right away does not write again
+        authenticate
+            .execute(&created.key, ApiKeyScope::PostsWrite)
+            .await
+            .unwrap();
+        assert_eq!(keys.touched.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_unknown_scope_and_bad_lifetime() {
+        let user = test_user();
+        let create = CreateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            MockApiKeyRepository::default(),
+            MockOpaqueTokenGenerator,
+        );
+
+        let unknown = create.execute(create_request(&["admin"]), user.id).await;
+        assert!(matches!(unknown, Err(DomainError::Validation(_))));
+
+        let none = create.execute(create_request(&[]), user.id).await;
+        assert!(matches!(none, Err(DomainError::Validation(_))));
+
+        let mut too_long = create_request(&["posts:write"]);
+        too_long.expires_in_days = Some(MAX_EXPIRES_IN_DAYS + 1);
+        let result = create.execute(too_long, user.id).await;
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_rejects_wrong_secret_and_missing_scope() {
+        let user = test_user();
+        let keys = MockApiKeyRepository::default();
+        let create = CreateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+        let authenticate = AuthenticateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+        let created = create
+            .execute(create_request(&["comments:write"]), user.id)
+            .await
+            .unwrap();
+
+        let forged = format!("ferro_{}_{}", created.api_key.prefix, "0".repeat(32));
+        let result = authenticate
+            .execute(&forged, ApiKeyScope::CommentsWrite)
+            .await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+
+        let result = authenticate
+            .execute("not-a-key", ApiKeyScope::CommentsWrite)
+            .await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+
+        let result = authenticate
+            .execute(&created.key, ApiKeyScope::PostsWrite)
+            .await;
+        assert!(matches!(result, Err(DomainError::Forbidden(_))));
+        assert!(keys.touched.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_and_expired_keys_are_rejected() {
+        let user = test_user();
+        let keys = MockApiKeyRepository::default();
+        let create = CreateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+        let authenticate = AuthenticateApiKeyUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            keys.clone(),
+            MockOpaqueTokenGenerator,
+        );
+        let revoke = RevokeApiKeyUseCase::new(keys.clone());
+
+        let first = create
+            .execute(create_request(&["posts:write"]), user.id)
+            .await
+            .unwrap();
+        let second = create
+            .execute(create_request(&["posts:write"]), user.id)
+            .await
+            .unwrap();
+
+        // Someone else cannot revoke the key
+        let id = Uuid::parse_str(&first.api_key.id).unwrap();
+        let result = revoke.execute(id, Uuid::new_v4()).await;
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+
+        revoke.execute(id, user.id).await.unwrap();
+        let result = authenticate
+            .execute(&first.key, ApiKeyScope::PostsWrite)
+            .await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+
+        keys.keys.lock().unwrap()[1].expires_at = Some(Utc::now() - Duration::minutes(1));
+        let result = authenticate
+            .execute(&second.key, ApiKeyScope::PostsWrite)
+            .await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+
+        let listed = ListApiKeysUseCase::new(keys.clone())
+            .execute(user.id)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 2);
+        assert!(listed[0].revoked_at.is_some());
+    }
+}
@@ -0,0 +1,191 @@
+// crates/application/src/users/change_email.rs
+
+use domain::common::error::DomainError;
+use domain::users::{Email, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{ChangeEmailRequest, ChangeEmailResponse};
+use super::email_verification::EmailVerificationIssuer;
+use super::login_user::{PasswordVerifier, confirm_password};
+
+pub struct ChangeEmailUseCase<R, V, E>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    E: EmailVerificationIssuer,
+{
+    user_repository: R,
+    password_verifier: V,
+    email_verification: E,
+}
+
+impl<R, V, E> ChangeEmailUseCase<R, V, E>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    E: EmailVerificationIssuer,
+{
+    pub fn new(user_repository: R, password_verifier: V, email_verification: E) -> Self {
+        Self {
+            user_repository,
+            password_verifier,
+            email_verification,
+        }
+    }
+
+    /// Move the account to a new email address after checking the password. The new
+    /// address starts out unverified and is sent a verification link.
+    pub async fn execute(
+        &self,
+        request: ChangeEmailRequest,
+        user_id: Uuid,
+    ) -> Result<ChangeEmailResponse, DomainError> {
+        let mut user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        confirm_password(&self.password_verifier, &request.password, &user).await?;
+
+        let email = Email::new(request.new_email)?;
+        if email == user.email {
+            return Err(DomainError::Validation(
+                "New email is the same as the current one".into(),
+            ));
+        }
+
+        // Uniqueness is enforced by the repository (unique index on users.email)
+        self.user_repository.update_email(user.id, &email).await?;
+        user.email = email;
+        user.email_verified = false;
+
+        // The change stands even if the mail cannot be sent; the user can ask for
+        // another link
+        if let Err(e) = self.email_verification.send(&user).await {
+            tracing::warn!(error = %e, user_id = %user.id, "Failed to send verification email after email change");
+        }
+
+        Ok(ChangeEmailResponse {
+            user_id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            email_verified: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockEmailVerification, MockPasswordVerifier, MockUserRepository};
+    use domain::users::{PasswordHash, User};
+
+    fn user(email: &str) -> User {
+        let mut user = User::new(
+            Email::new(email).unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        user.email_verified = true;
+        user
+    }
+
+    #[tokio::test]
+    async fn test_change_email_success() {
+        let user = user("old@example.com");
+        let users = MockUserRepository::with_user(user.clone());
+        let email_verification = MockEmailVerification::default();
+        let use_case = ChangeEmailUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            email_verification.clone(),
+        );
+
+        let request = ChangeEmailRequest {
+            new_email: "new@example.com".to_string(),
+            password: "password123".to_string(),
+        };
+        let response = use_case.execute(request, user.id).await.unwrap();
+
+        assert_eq!(response.email, "new@example.com");
+        assert!(!response.email_verified);
+        let stored = users.users.lock().unwrap()[0].clone();
+        assert_eq!(stored.email.as_str(), "new@example.com");
+        assert!(!stored.email_verified);
+        let sent_to = email_verification.sent_to.lock().unwrap();
+        assert_eq!(sent_to.len(), 1);
+        assert_eq!(sent_to[0].email.as_str(), "new@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_change_email_already_taken() {
+        let other = user("taken@example.com");
+        let user = user("old@example.com");
+        let users = MockUserRepository::with_users(vec![user.clone(), other]);
+        let email_verification = MockEmailVerification::default();
+        let use_case = ChangeEmailUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            email_verification.clone(),
+        );
+
+        let request = ChangeEmailRequest {
+            new_email: "taken@example.com".to_string(),
+            password: "password123".to_string(),
+        };
+        let result = use_case.execute(request, user.id).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::AlreadyExists(_)));
+        assert!(email_verification.sent_to.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_change_email_wrong_password() {
+        let user = user("old@example.com");
+        let users = MockUserRepository::with_user(user.clone());
+        let use_case = ChangeEmailUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            MockEmailVerification::default(),
+        );
+
+        let request = ChangeEmailRequest {
+            new_email: "new@example.com".to_string(),
+            password: "wrongpassword".to_string(),
+        };
+        let result = use_case.execute(request, user.id).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert_eq!(
+            users.users.lock().unwrap()[0].email.as_str(),
+            "old@example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_email_without_password_asks_for_one() {
+        let user = User::new_passwordless(Email::new("old@example.com").unwrap(), true);
+        let users = MockUserRepository::with_user(user.clone());
+        let email_verification = MockEmailVerification::default();
+        let use_case = ChangeEmailUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            email_verification.clone(),
+        );
+
+        let request = ChangeEmailRequest {
+            new_email: "new@example.com".to_string(),
+            password: "anything".to_string(),
+        };
+        let result = use_case.execute(request, user.id).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            DomainError::Validation(message) if message.contains("password reset")
+        ));
+        assert_eq!(
+            users.users.lock().unwrap()[0].email.as_str(),
+            "old@example.com"
+        );
+        assert!(email_verification.sent_to.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+// crates/application/src/users/change_password.rs
+
+use chrono::{SubsecRound, Utc};
+use domain::common::error::DomainError;
+use domain::users::{PasswordHasher, PlainPassword, RevokedTokenRepository, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{ChangePasswordRequest, ChangePasswordResponse};
+use super::login_user::PasswordVerifier;
+use super::token_issuer::{AuthTokenIssuer, ClientInfo};
+
+pub struct ChangePasswordUseCase<R, V, H, T, I>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    H: PasswordHasher,
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    user_repository: R,
+    password_verifier: V,
+    password_hasher: H,
+    revoked_token_repository: T,
+    token_issuer: I,
+}
+
+impl<R, V, H, T, I> ChangePasswordUseCase<R, V, H, T, I>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    H: PasswordHasher,
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    pub fn new(
+        user_repository: R,
+        password_verifier: V,
+        password_hasher: H,
+        revoked_token_repository: T,
+        token_issuer: I,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_verifier,
+            password_hasher,
+            revoked_token_repository,
+            token_issuer,
+        }
+    }
+
+    /// Replace the user's password after checking the current one. Every existing
+    /// session is signed out and the caller gets a fresh token pair, in a new session
+    /// for `client`.
+    pub async fn execute(
+        &self,
+        request: ChangePasswordRequest,
+        user_id: Uuid,
+        client: &ClientInfo,
+    ) -> Result<ChangePasswordResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        // A stolen access token must not be enough to give a passwordless account a
+        // password, so those go through the emailed reset link instead
+        if user.password_hash.is_none() {
+            return Err(DomainError::Validation(
+                "Account has no password; use a password reset to set one".into(),
+            ));
+        }
+
+        if !self
+            .password_verifier
+            .verify_user(&request.current_password, &user)
+            .await?
+        {
+            return Err(DomainError::Validation(
+                "Current password is incorrect".into(),
+            ));
+        }
+
+        let password = PlainPassword::new(request.new_password)?;
+        let password_hash = self.password_hasher.hash(password).await?;
+        self.user_repository
+            .update_password(user.id, password_hash)
+            .await?;
+
+        // Access tokens carry `iat` in whole seconds; truncating the cutoff keeps the
+        // pair issued below valid.
+        let cutoff = Utc::now().trunc_subsecs(0);
+        self.revoked_token_repository
+            .revoke_all_for_user(user.id, cutoff)
+            .await?;
+        self.token_issuer.revoke_all(user.id).await?;
+
+        let tokens = self.token_issuer.issue(&user, client).await?;
+
+        Ok(ChangePasswordResponse {
+            user_id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            role: user.role.as_str().to_string(),
+            email_verified: user.email_verified,
+            token: tokens.access_token,
+            token_expires_at: tokens.access_token_expires_at.to_rfc3339(),
+            refresh_token: tokens.refresh_token,
+            refresh_token_expires_at: tokens.refresh_token_expires_at.to_rfc3339(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockPasswordHasher, MockPasswordVerifier, MockRevokedTokenRepository, MockTokenIssuer,
+        MockUserRepository,
+    };
+
+    use domain::users::{Email, PasswordHash, User};
+
+    // Accepts a password when its hash is "hashed_<password>"
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_oldpassword".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_change_password_success() {
+        let user = user();
+        let users = MockUserRepository::with_user(user.clone());
+        let revoked_tokens = MockRevokedTokenRepository::default();
+        let token_issuer = MockTokenIssuer::default();
+        let use_case = ChangePasswordUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            MockPasswordHasher,
+            revoked_tokens.clone(),
+            token_issuer.clone(),
+        );
+
+        let request = ChangePasswordRequest {
+            current_password: "oldpassword".to_string(),
+            new_password: "newpassword123".to_string(),
+        };
+        let response = use_case
+            .execute(request, user.id, &ClientInfo::default())
+            .await
+            .unwrap();
+
+        assert_eq!(response.token, format!("token_{}", user.id));
+        assert_eq!(
+            users.users.lock().unwrap()[0]
+                .password_hash
+                .as_ref()
+                .unwrap()
+                .as_str(),
+            "hashed_newpassword123"
+        );
+        assert_eq!(revoked_tokens.revoked_users(), vec![user.id]);
+        assert_eq!(*token_issuer.revoked_users.lock().unwrap(), vec![user.id]);
+    }
+
+    #[tokio::test]
+    async fn test_change_password_wrong_current_password() {
+        let user = user();
+        let users = MockUserRepository::with_user(user.clone());
+        let token_issuer = MockTokenIssuer::default();
+        let use_case = ChangePasswordUseCase::new(
+            users.clone(),
+            MockPasswordVerifier,
+            MockPasswordHasher,
+            MockRevokedTokenRepository::default(),
+            token_issuer.clone(),
+        );
+
+        let request = ChangePasswordRequest {
+            current_password: "wrongpassword".to_string(),
+            new_password: "newpassword123".to_string(),
+        };
+        let result = use_case
+            .execute(request, user.id, &ClientInfo::default())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert_eq!(
+            users.users.lock().unwrap()[0]
+                .password_hash
+                .as_ref()
+                .unwrap()
+                .as_str(),
+            "hashed_oldpassword"
+        );
+        assert!(token_issuer.revoked_users.lock().unwrap().is_empty());
+    }
+}
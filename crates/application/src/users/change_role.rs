@@ -0,0 +1,188 @@
+// crates/application/src/users/change_role.rs
+
+use domain::common::error::DomainError;
+use domain::users::{Role, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{ChangeRoleRequest, UserRoleResponse};
+use crate::authorization::{Actor, Policy};
+
+pub struct ChangeRoleUseCase<R>
+where
+    R: UserRepository,
+{
+    user_repository: R,
+}
+
+impl<R> ChangeRoleUseCase<R>
+where
+    R: UserRepository,
+{
+    pub fn new(user_repository: R) -> Self {
+        Self { user_repository }
+    }
+
+    /// Change a user's role. Takes effect for new access tokens; tokens already
+    /// issued keep their role until they expire.
+    pub async fn execute(
+        &self,
+        request: ChangeRoleRequest,
+        user_id: Uuid,
+        actor: &Actor,
+    ) -> Result<UserRoleResponse, DomainError> {
+        // Only admins may change roles
+        Policy::change_role(actor, user_id)?;
+
+        let role = Role::parse(&request.role)?;
+
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        // Someone has to be left to manage roles
+        if user.role == Role::Admin
+            && role != Role::Admin
+            && self.user_repository.count_by_role(Role::Admin).await? <= 1
+        {
+            return Err(DomainError::Forbidden(
+                "Cannot demote the last admin".into(),
+            ));
+        }
+
+        self.user_repository.update_role(user.id, role).await?;
+
+        Ok(UserRoleResponse {
+            user_id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            role: role.as_str().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockUserRepository;
+    use domain::users::{Email, PasswordHash, User};
+
+    fn user(email: &str, role: Role) -> User {
+        let mut user = User::new(
+            Email::new(email).unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        user.role = role;
+        user
+    }
+
+    fn request(role: &str) -> ChangeRoleRequest {
+        ChangeRoleRequest {
+            role: role.to_string(),
+        }
+    }
+
+    fn admin_actor() -> Actor {
+        Actor::new(Uuid::new_v4(), Role::Admin, true)
+    }
+
+    #[tokio::test]
+    async fn test_change_role_success() {
+        let target = user("user@example.com", Role::User);
+        let users = MockUserRepository::with_user(target.clone());
+        let use_case = ChangeRoleUseCase::new(users.clone());
+
+        let response = use_case
+            .execute(request("moderator"), target.id, &admin_actor())
+            .await
+            .unwrap();
+
+        assert_eq!(response.role, "moderator");
+        assert_eq!(users.get(target.id).unwrap().role, Role::Moderator);
+    }
+
+    #[tokio::test]
+    async fn test_change_role_requires_admin() {
+        let target = user("user@example.com", Role::User);
+        let users = MockUserRepository::with_user(target.clone());
+        let use_case = ChangeRoleUseCase::new(users.clone());
+        let moderator = Actor::new(Uuid::new_v4(), Role::Moderator, true);
+
+        let result = use_case
+            .execute(request("admin"), target.id, &moderator)
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+        assert_eq!(users.get(target.id).unwrap().role, Role::User);
+    }
+
+    #[tokio::test]
+    async fn test_change_role_rejects_own_role() {
+        let admin = user("admin@example.com", Role::Admin);
+        let users = MockUserRepository::with_user(admin.clone());
+        let use_case = ChangeRoleUseCase::new(users);
+        let actor = Actor::new(admin.id, Role::Admin, true);
+
+        let result = use_case.execute(request("user"), admin.id, &actor).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_change_role_rejects_unknown_role() {
+        let target = user("user@example.com", Role::User);
+        let use_case = ChangeRoleUseCase::new(MockUserRepository::with_user(target.clone()));
+
+        let result = use_case
+            .execute(request("superuser"), target.id, &admin_actor())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_change_role_user_not_found() {
+        let use_case = ChangeRoleUseCase::new(MockUserRepository::default());
+
+        let result = use_case
+            .execute(request("moderator"), Uuid::new_v4(), &admin_actor())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_change_role_keeps_the_last_admin() {
+        // The actor's token still says admin, but they were demoted since, which
+        // leaves the target as the only admin
+        let demoted = user("demoted@example.com", Role::User);
+        let admin = user("admin@example.com", Role::Admin);
+        let users = MockUserRepository::with_users(vec![demoted.clone(), admin.clone()]);
+        let use_case = ChangeRoleUseCase::new(users.clone());
+        let stale_actor = Actor::new(demoted.id, Role::Admin, true);
+
+        let result = use_case
+            .execute(request("user"), admin.id, &stale_actor)
+            .await;
+
+        assert!(
+            matches!(result.unwrap_err(), DomainError::Forbidden(msg) if msg.contains("last admin"))
+        );
+        assert_eq!(users.get(admin.id).unwrap().role, Role::Admin);
+    }
+
+    #[tokio::test]
+    async fn test_change_role_demotes_an_admin_when_another_remains() {
+        let admin = user("admin@example.com", Role::Admin);
+        let other = user("other@example.com", Role::Admin);
+        let users = MockUserRepository::with_users(vec![admin.clone(), other]);
+        let use_case = ChangeRoleUseCase::new(users.clone());
+
+        use_case
+            .execute(request("moderator"), admin.id, &admin_actor())
+            .await
+            .unwrap();
+
+        assert_eq!(users.get(admin.id).unwrap().role, Role::Moderator);
+    }
+}
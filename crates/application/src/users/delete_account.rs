@@ -0,0 +1,358 @@
+// crates/application/src/users/delete_account.rs
+
+use chrono::{Duration, SubsecRound, Utc};
+use domain::common::error::DomainError;
+use domain::users::{RevokedTokenRepository, User, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{AccountDeletionResponse, DeleteAccountRequest};
+use super::login_user::{PasswordVerifier, confirm_password};
+use super::token_issuer::AuthTokenIssuer;
+
+/// What happens to an account once its grace period is over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDeletionMode {
+    /// Remove the user together with their posts and comments
+    Delete,
+    /// Scrub the user's personal data but keep their posts and comments
+    Anonymize,
+}
+
+impl AccountDeletionMode {
+    pub fn parse(mode: &str) -> Result<Self, DomainError> {
+        match mode {
+            "delete" => Ok(AccountDeletionMode::Delete),
+            "anonymize" => Ok(AccountDeletionMode::Anonymize),
+            _ => Err(DomainError::Validation(
+                "Account deletion mode must be one of: delete, anonymize".into(),
+            )),
+        }
+    }
+}
+
+// How many due accounts a single purge run handles
+const PURGE_BATCH_SIZE: i64 = 100;
+
+pub struct DeleteAccountUseCase<R, V, T, I>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    user_repository: R,
+    password_verifier: V,
+    revoked_token_repository: T,
+    token_issuer: I,
+    mode: AccountDeletionMode,
+    grace_period: Duration,
+}
+
+impl<R, V, T, I> DeleteAccountUseCase<R, V, T, I>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    pub fn new(
+        user_repository: R,
+        password_verifier: V,
+        revoked_token_repository: T,
+        token_issuer: I,
+        mode: AccountDeletionMode,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_verifier,
+            revoked_token_repository,
+            token_issuer,
+            mode,
+            grace_period,
+        }
+    }
+
+    /// Schedule the account for deletion once the grace period is over. Asking again
+    /// while a deletion is pending keeps the original date. With no grace period the
+    /// account is removed straight away.
+    pub async fn execute(
+        &self,
+        request: DeleteAccountRequest,
+        user_id: Uuid,
+    ) -> Result<AccountDeletionResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        confirm_password(&self.password_verifier, &request.password, &user).await?;
+
+        let scheduled_for = match user.deletion_scheduled_for {
+            Some(at) => at,
+            None => {
+                let at = Utc::now() + self.grace_period;
+                self.user_repository.schedule_deletion(user.id, at).await?;
+                at
+            }
+        };
+
+        if self.grace_period <= Duration::zero() {
+            self.purge(&user).await?;
+        }
+
+        Ok(AccountDeletionResponse {
+            user_id: user.id.to_string(),
+            deletion_scheduled_for: scheduled_for.to_rfc3339(),
+        })
+    }
+
+    /// Undo a pending deletion
+    pub async fn cancel(&self, user_id: Uuid) -> Result<(), DomainError> {
+        if !self.user_repository.cancel_deletion(user_id).await? {
+            return Err(DomainError::NotFound(
+                "No account deletion is scheduled".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Carry out every deletion whose grace period has ended; returns how many
+    /// accounts were removed. Meant to be run periodically; an account that fails to
+    /// be removed is logged and left for the next run.
+    pub async fn purge_due(&self) -> Result<usize, DomainError> {
+        let mut purged = 0;
+        loop {
+            let users = self
+                .user_repository
+                .find_due_for_deletion(Utc::now(), PURGE_BATCH_SIZE)
+                .await?;
+            let batch_size = users.len();
+
+            let mut purged_in_batch = 0;
+            for user in &users {
+                match self.purge(user).await {
+                    Ok(()) => purged_in_batch += 1,
+                    Err(e) => tracing::warn!(
+                        error = %e,
+                        user_id = %user.id,
+                        "Failed to purge account due for deletion"
+                    ),
+                }
+            }
+            purged += purged_in_batch;
+
+            // A batch that removed nothing would only come back the same
+            if (batch_size as i64) < PURGE_BATCH_SIZE || purged_in_batch == 0 {
+                return Ok(purged);
+            }
+        }
+    }
+
+    async fn purge(&self, user: &User) -> Result<(), DomainError> {
+        // Sign the user out everywhere first, so nothing can act on the account while
+        // it is being removed
+        self.revoked_token_repository
+            .revoke_all_for_user(user.id, Utc::now().trunc_subsecs(0))
+            .await?;
+        self.token_issuer.revoke_all(user.id).await?;
+
+        match self.mode {
+            AccountDeletionMode::Delete => self.user_repository.delete(user.id).await,
+            AccountDeletionMode::Anonymize => self.user_repository.anonymize(user.id).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockPasswordVerifier, MockRevokedTokenRepository, MockTokenIssuer, MockUserRepository,
+    };
+    use domain::users::{Email, PasswordHash};
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        )
+    }
+
+    fn use_case(
+        users: MockUserRepository,
+        revoked_tokens: MockRevokedTokenRepository,
+        mode: AccountDeletionMode,
+        grace_period: Duration,
+    ) -> DeleteAccountUseCase<
+        MockUserRepository,
+        MockPasswordVerifier,
+        MockRevokedTokenRepository,
+        MockTokenIssuer,
+    > {
+        DeleteAccountUseCase::new(
+            users,
+            MockPasswordVerifier,
+            revoked_tokens,
+            MockTokenIssuer::default(),
+            mode,
+            grace_period,
+        )
+    }
+
+    fn request(password: &str) -> DeleteAccountRequest {
+        DeleteAccountRequest {
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_schedules_and_cancels() {
+        let user = user();
+        let users = MockUserRepository::default();
+        users.users.lock().unwrap().push(user.clone());
+        let use_case = use_case(
+            users.clone(),
+            MockRevokedTokenRepository::default(),
+            AccountDeletionMode::Anonymize,
+            Duration::days(30),
+        );
+
+        use_case
+            .execute(request("password123"), user.id)
+            .await
+            .unwrap();
+
+        let scheduled = users.users.lock().unwrap()[0]
+            .deletion_scheduled_for
+            .unwrap();
+        assert!(scheduled > Utc::now() + Duration::days(29));
+        assert_eq!(use_case.purge_due().await.unwrap(), 0);
+
+        use_case.cancel(user.id).await.unwrap();
+        assert!(
+            users.users.lock().unwrap()[0]
+                .deletion_scheduled_for
+                .is_none()
+        );
+        assert!(matches!(
+            use_case.cancel(user.id).await.unwrap_err(),
+            DomainError::NotFound(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_wrong_password() {
+        let user = user();
+        let users = MockUserRepository::default();
+        users.users.lock().unwrap().push(user.clone());
+        let use_case = use_case(
+            users.clone(),
+            MockRevokedTokenRepository::default(),
+            AccountDeletionMode::Delete,
+            Duration::zero(),
+        );
+
+        let result = use_case.execute(request("wrongpassword"), user.id).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert!(users.deleted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_without_password_asks_for_one() {
+        let user = User::new_passwordless(Email::new("oidc@example.com").unwrap(), true);
+        let users = MockUserRepository::with_user(user.clone());
+        let use_case = use_case(
+            users.clone(),
+            MockRevokedTokenRepository::default(),
+            AccountDeletionMode::Delete,
+            Duration::zero(),
+        );
+
+        let result = use_case.execute(request("anything"), user.id).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            DomainError::Validation(message) if message.contains("password reset")
+        ));
+        assert!(users.deleted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_purge_due_anonymizes_and_signs_out() {
+        let mut due = user();
+        due.deletion_scheduled_for = Some(Utc::now() - Duration::minutes(1));
+        let mut pending = user();
+        pending.deletion_scheduled_for = Some(Utc::now() + Duration::days(1));
+        let users = MockUserRepository::default();
+        users
+            .users
+            .lock()
+            .unwrap()
+            .extend([due.clone(), pending.clone()]);
+        let revoked_tokens = MockRevokedTokenRepository::default();
+        let use_case = use_case(
+            users.clone(),
+            revoked_tokens.clone(),
+            AccountDeletionMode::Anonymize,
+            Duration::days(30),
+        );
+
+        assert_eq!(use_case.purge_due().await.unwrap(), 1);
+
+        assert_eq!(*users.anonymized.lock().unwrap(), vec![due.id]);
+        assert!(users.deleted.lock().unwrap().is_empty());
+        assert_eq!(revoked_tokens.revoked_users(), vec![due.id]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_due_continues_past_a_failure() {
+        let mut failing = user();
+        failing.deletion_scheduled_for = Some(Utc::now() - Duration::minutes(2));
+        let mut due = user();
+        due.deletion_scheduled_for = Some(Utc::now() - Duration::minutes(1));
+        let users = MockUserRepository::with_users(vec![failing.clone(), due.clone()]);
+        users.failing_removals.lock().unwrap().insert(failing.id);
+        let use_case = use_case(
+            users.clone(),
+            MockRevokedTokenRepository::default(),
+            AccountDeletionMode::Delete,
+            Duration::days(30),
+        );
+
+        assert_eq!(use_case.purge_due().await.unwrap(), 1);
+
+        assert_eq!(*users.deleted.lock().unwrap(), vec![due.id]);
+        // Still scheduled, so the next run tries it again
+        assert!(
+            users
+                .get(failing.id)
+                .unwrap()
+                .deletion_scheduled_for
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_without_grace_period_deletes_immediately() {
+        let user = user();
+        let users = MockUserRepository::default();
+        users.users.lock().unwrap().push(user.clone());
+        let use_case = use_case(
+            users.clone(),
+            MockRevokedTokenRepository::default(),
+            AccountDeletionMode::Delete,
+            Duration::zero(),
+        );
+
+        use_case
+            .execute(request("password123"), user.id)
+            .await
+            .unwrap();
+
+        assert_eq!(*users.deleted.lock().unwrap(), vec![user.id]);
+        assert!(users.users.lock().unwrap().is_empty());
+    }
+}
@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::comments::CommentResponse;
+use crate::posts::PostResponse;
+
 // Register User DTOs
 #[derive(Debug, Clone, Deserialize)]
 pub struct RegisterUserRequest {
@@ -13,7 +16,12 @@ pub struct RegisterUserRequest {
 pub struct RegisterUserResponse {
     pub user_id: String,
     pub email: String,
+    pub role: String,
+    pub email_verified: bool,
     pub token: String,
+    pub token_expires_at: String,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: String,
 }
 
 // Login User DTOs
@@ -27,5 +35,260 @@ pub struct LoginUserRequest {
 pub struct LoginUserResponse {
     pub user_id: String,
     pub email: String,
+    pub role: String,
+    pub email_verified: bool,
+    pub token: String,
+    pub token_expires_at: String,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: String,
+}
+
+// Result of a correct password: either the user is logged in, or the account has
+// two-factor authentication and the challenge has to be answered first
+#[derive(Debug)]
+pub enum LoginOutcome {
+    Authenticated(LoginUserResponse),
+    MfaRequired(MfaChallengeResponse),
+}
+
+// Two-Factor Authentication DTOs
+#[derive(Debug, Serialize)]
+pub struct MfaChallengeResponse {
+    pub mfa_token: String,
+    pub mfa_token_expires_at: String,
+}
+
+// `code` is either a code from the authenticator app or an unused recovery code
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyMfaRequest {
+    pub mfa_token: String,
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfirmTotpRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DisableTotpRequest {
+    pub password: String,
+}
+
+// OpenID Connect DTOs
+// `state` is returned so the client can check it when the provider redirects back
+#[derive(Debug, Serialize)]
+pub struct OidcAuthorizationResponse {
+    pub authorization_url: String,
+    pub state: String,
+    pub expires_at: String,
+}
+
+// The `code` and `state` query parameters the provider redirected back with
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcCallbackRequest {
+    pub code: String,
+    pub state: String,
+}
+
+// API Key DTOs
+// Without `expires_in_days` the key does not expire
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+// The only response that contains the key itself
+#[derive(Debug, Serialize)]
+pub struct CreateApiKeyResponse {
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+}
+
+// Session DTOs
+// `current` marks the session the request was made from
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionResponse {
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: String,
+    pub last_seen_at: String,
+    pub current: bool,
+}
+
+// Refresh Token DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshTokenResponse {
+    pub user_id: String,
+    pub email: String,
+    pub role: String,
+    pub email_verified: bool,
+    pub token: String,
+    pub token_expires_at: String,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: String,
+}
+
+// Logout User DTOs
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LogoutUserRequest {
+    #[serde(default)]
+    pub refresh_token: Option<String>, // Also revoke this refresh token chain
+}
+
+// Change Role DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeRoleRequest {
+    pub role: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserRoleResponse {
+    pub user_id: String,
+    pub email: String,
+    pub role: String,
+}
+
+// Verify Email DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyEmailResponse {
+    pub user_id: String,
+    pub email: String,
+    pub email_verified: bool,
+}
+
+// Password Reset DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequestPasswordResetRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+// Change Password DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangePasswordResponse {
+    pub user_id: String,
+    pub email: String,
+    pub role: String,
+    pub email_verified: bool,
     pub token: String,
+    pub token_expires_at: String,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: String,
+}
+
+// Change Email DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeEmailRequest {
+    pub new_email: String,
+    pub password: String, // Current password, to confirm the change
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangeEmailResponse {
+    pub user_id: String,
+    pub email: String,
+    pub email_verified: bool,
+}
+
+// Profile DTOs
+// Public view of a user, safe to show to anyone
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileResponse {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+// The authenticated user's own account, including private fields
+#[derive(Debug, Serialize)]
+pub struct CurrentUserResponse {
+    pub user_id: String,
+    pub email: String,
+    pub role: String,
+    pub email_verified: bool,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub deletion_scheduled_for: Option<String>, // Set while a requested deletion is pending
+    pub created_at: String,
+}
+
+// Fields left as None keep their current value; an empty string clears the field
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateProfileRequest {
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+// Delete Account DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountDeletionResponse {
+    pub user_id: String,
+    pub deletion_scheduled_for: String,
+}
+
+// Data Export DTOs
+// Everything stored about a user, as handed to them on request
+#[derive(Debug, Serialize)]
+pub struct UserDataExport {
+    pub exported_at: String,
+    pub account: CurrentUserResponse,
+    pub posts: Vec<PostResponse>,
+    pub comments: Vec<CommentResponse>,
 }
@@ -0,0 +1,189 @@
+// crates/application/src/users/email_verification.rs
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{EmailVerificationToken, EmailVerificationTokenRepository, User};
+
+use super::mailer::{EmailMessage, Mailer};
+use super::token_issuer::OpaqueTokenGenerator;
+
+/// Trait for sending and redeeming email verification tokens
+#[async_trait]
+pub trait EmailVerificationIssuer: Send + Sync {
+    /// Mail a new verification token to the user. Tokens sent earlier stop working.
+    async fn send(&self, user: &User) -> Result<(), DomainError>;
+
+    /// Consume a verification token so it cannot be used again
+    async fn redeem(&self, token: &str) -> Result<EmailVerificationToken, DomainError>;
+}
+
+/// Issues single-use verification tokens and mails them as links
+#[derive(Clone)]
+pub struct EmailVerificationService<T, O, M>
+where
+    T: EmailVerificationTokenRepository,
+    O: OpaqueTokenGenerator,
+    M: Mailer,
+{
+    token_repository: T,
+    opaque_token_generator: O,
+    mailer: M,
+    verification_url: String,
+    token_ttl: Duration,
+}
+
+impl<T, O, M> EmailVerificationService<T, O, M>
+where
+    T: EmailVerificationTokenRepository,
+    O: OpaqueTokenGenerator,
+    M: Mailer,
+{
+    /// `verification_url` is the page the mailed link points to; the token is
+    /// appended as the `token` query parameter.
+    pub fn new(
+        token_repository: T,
+        opaque_token_generator: O,
+        mailer: M,
+        verification_url: String,
+        token_ttl: Duration,
+    ) -> Self {
+        Self {
+            token_repository,
+            opaque_token_generator,
+            mailer,
+            verification_url,
+            token_ttl,
+        }
+    }
+
+    fn link(&self, token: &str) -> String {
+        let separator = if self.verification_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.verification_url, separator, token)
+    }
+}
+
+#[async_trait]
+impl<T, O, M> EmailVerificationIssuer for EmailVerificationService<T, O, M>
+where
+    T: EmailVerificationTokenRepository,
+    O: OpaqueTokenGenerator,
+    M: Mailer,
+{
+    async fn send(&self, user: &User) -> Result<(), DomainError> {
+        // Only the most recently sent link works
+        self.token_repository.delete_for_user(user.id).await?;
+
+        let token = self.opaque_token_generator.generate();
+        let expires_at = Utc::now() + self.token_ttl;
+        let record = EmailVerificationToken::new(
+            user.id,
+            self.opaque_token_generator.hash(&token),
+            expires_at,
+        );
+        self.token_repository.save(record).await?;
+
+        self.mailer
+            .send(EmailMessage {
+                to: user.email.as_str().to_string(),
+                subject: "Verify your email address".to_string(),
+                body: format!(
+                    "Confirm your email address by opening the link below:\n\n{}\n\n\
+                     The link expires at {}. If you did not create an account, ignore this email.\n",
+                    self.link(&token),
+                    expires_at.to_rfc3339(),
+                ),
+            })
+            .await
+    }
+
+    async fn redeem(&self, token: &str) -> Result<EmailVerificationToken, DomainError> {
+        let invalid = || DomainError::Validation("Invalid or expired verification token".into());
+
+        let token_hash = self.opaque_token_generator.hash(token);
+        let record = self
+            .token_repository
+            .find_by_hash(&token_hash)
+            .await?
+            .ok_or_else(invalid)?;
+
+        if record.is_expired() || record.is_used() {
+            return Err(invalid());
+        }
+
+        // Conditional consume so two concurrent redemptions cannot both succeed
+        if !self.token_repository.consume(record.id).await? {
+            return Err(invalid());
+        }
+
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockEmailVerificationTokenRepository, MockMailer, MockOpaqueTokenGenerator,
+    };
+    use domain::users::{Email, PasswordHash};
+
+    fn service(
+        mailer: MockMailer,
+    ) -> EmailVerificationService<
+        MockEmailVerificationTokenRepository,
+        MockOpaqueTokenGenerator,
+        MockMailer,
+    > {
+        EmailVerificationService::new(
+            MockEmailVerificationTokenRepository::default(),
+            MockOpaqueTokenGenerator,
+            mailer,
+            "http://localhost:3000/verify-email".to_string(),
+            Duration::hours(24),
+        )
+    }
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_send_and_redeem() {
+        let mailer = MockMailer::default();
+        let service = service(mailer.clone());
+        let user = user();
+
+        service.send(&user).await.unwrap();
+        let token = mailer.last_token();
+
+        let redeemed = service.redeem(&token).await.unwrap();
+        assert_eq!(redeemed.user_id, user.id);
+
+        // Single use
+        let result = service.redeem(&token).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_resend_invalidates_previous_token() {
+        let mailer = MockMailer::default();
+        let service = service(mailer.clone());
+        let user = user();
+
+        service.send(&user).await.unwrap();
+        let first = mailer.last_token();
+        service.send(&user).await.unwrap();
+        let second = mailer.last_token();
+
+        assert!(service.redeem(&first).await.is_err());
+        assert!(service.redeem(&second).await.is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+// crates/application/src/users/export_user_data.rs
+
+use chrono::Utc;
+use domain::comments::CommentRepository;
+use domain::common::error::DomainError;
+use domain::common::pagination::{PageKey, PageRequest};
+use domain::posts::PostRepository;
+use domain::users::UserRepository;
+use uuid::Uuid;
+
+use super::dtos::UserDataExport;
+use super::get_profile::{current_user_response, profile_response};
+use crate::comments::CommentResponse;
+use crate::posts::post_response;
+
+// Posts and comments are read in pages of this size
+const EXPORT_PAGE_SIZE: i64 = 100;
+
+pub struct ExportUserDataUseCase<R, P, C>
+where
+    R: UserRepository,
+    P: PostRepository,
+    C: CommentRepository,
+{
+    user_repository: R,
+    post_repository: P,
+    comment_repository: C,
+}
+
+impl<R, P, C> ExportUserDataUseCase<R, P, C>
+where
+    R: UserRepository,
+    P: PostRepository,
+    C: CommentRepository,
+{
+    pub fn new(user_repository: R, post_repository: P, comment_repository: C) -> Self {
+        Self {
+            user_repository,
+            post_repository,
+            comment_repository,
+        }
+    }
+
+    /// Collect the user's account, posts and comments into a single archive
+    pub async fn execute(&self, user_id: Uuid) -> Result<UserDataExport, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+        let author = profile_response(&user);
+
+        let mut posts = Vec::new();
+        let mut page = PageRequest::first(EXPORT_PAGE_SIZE);
+        loop {
+            let batch = self.post_repository.find_by_author(user.id, &page).await?;
+            let last = batch
+                .last()
+                .map(|post| PageKey::new(post.created_at, post.id));
+            let batch_size = batch.len();
+
+            posts.extend(
+                batch
+                    .into_iter()
+                    .map(|post| post_response(&post, Some(author.clone()))),
+            );
+
+            match last {
+                Some(last) if (batch_size as i64) == EXPORT_PAGE_SIZE => {
+                    page = PageRequest::after(last, EXPORT_PAGE_SIZE);
+                }
+                _ => break,
+            }
+        }
+
+        let mut comments = Vec::new();
+        let mut page = PageRequest::first(EXPORT_PAGE_SIZE);
+        loop {
+            let batch = self
+                .comment_repository
+                .find_by_author(user.id, &page)
+                .await?;
+            let last = batch
+                .last()
+                .map(|comment| PageKey::new(comment.created_at, comment.id));
+            let batch_size = batch.len();
+
+            comments.extend(batch.into_iter().map(|comment| CommentResponse {
+                id: comment.id.to_string(),
+                content: comment.content.as_str().to_string(),
+                post_id: comment.post_id.to_string(),
+                author_id: comment.author_id.to_string(),
+                author: Some(author.clone()),
+                parent_id: comment.parent_id.map(|id| id.to_string()),
+                created_at: comment.created_at.to_rfc3339(),
+            }));
+
+            match last {
+                Some(last) if (batch_size as i64) == EXPORT_PAGE_SIZE => {
+                    page = PageRequest::after(last, EXPORT_PAGE_SIZE);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(UserDataExport {
+            exported_at: Utc::now().to_rfc3339(),
+            account: current_user_response(&user),
+            posts,
+            comments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockCommentRepository, MockPostRepository, MockUserRepository};
+
+    use domain::comments::{Comment, CommentContent};
+
+    use domain::posts::{Post, PostContent, PostTitle};
+    use domain::users::{Email, PasswordHash, User};
+
+    #[tokio::test]
+    async fn test_export_collects_every_page() {
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        let someone_else = Uuid::new_v4();
+        let posts = MockPostRepository::default();
+        let comments = MockCommentRepository::default();
+        for i in 0..(EXPORT_PAGE_SIZE + 5) {
+            let post = Post::new(
+                PostTitle::new(format!("Post {}", i)).unwrap(),
+                PostContent::new("content").unwrap(),
+                user.id,
+            );
+            comments
+                .save(Comment::new(
+                    CommentContent::new("comment").unwrap(),
+                    post.id,
+                    user.id,
+                    None,
+                ))
+                .await
+                .unwrap();
+            posts.save(post).await.unwrap();
+        }
+        posts
+            .save(Post::new(
+                PostTitle::new("Not mine").unwrap(),
+                PostContent::new("content").unwrap(),
+                someone_else,
+            ))
+            .await
+            .unwrap();
+        let use_case = ExportUserDataUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            posts,
+            comments,
+        );
+
+        let export = use_case.execute(user.id).await.unwrap();
+
+        assert_eq!(export.account.email, "test@example.com");
+        assert_eq!(export.posts.len(), (EXPORT_PAGE_SIZE + 5) as usize);
+        assert_eq!(export.comments.len(), (EXPORT_PAGE_SIZE + 5) as usize);
+        assert!(
+            export
+                .posts
+                .iter()
+                .all(|p| p.author_id == user.id.to_string())
+        );
+    }
+}
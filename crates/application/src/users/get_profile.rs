@@ -0,0 +1,104 @@
+// crates/application/src/users/get_profile.rs
+
+use std::collections::HashMap;
+
+use domain::common::error::DomainError;
+use domain::users::{User, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{CurrentUserResponse, ProfileResponse};
+
+pub struct GetProfileUseCase<R>
+where
+    R: UserRepository,
+{
+    user_repository: R,
+}
+
+impl<R> GetProfileUseCase<R>
+where
+    R: UserRepository,
+{
+    pub fn new(user_repository: R) -> Self {
+        Self { user_repository }
+    }
+
+    /// The public profile of any user
+    pub async fn execute(&self, user_id: Uuid) -> Result<ProfileResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        Ok(profile_response(&user))
+    }
+
+    /// The authenticated user's own account
+    pub async fn execute_current(&self, user_id: Uuid) -> Result<CurrentUserResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        Ok(current_user_response(&user))
+    }
+}
+
+pub(crate) fn profile_response(user: &User) -> ProfileResponse {
+    ProfileResponse {
+        user_id: user.id.to_string(),
+        display_name: user
+            .profile
+            .display_name
+            .as_ref()
+            .map(|name| name.as_str().to_string()),
+        bio: user
+            .profile
+            .bio
+            .as_ref()
+            .map(|bio| bio.as_str().to_string()),
+        avatar_url: user
+            .profile
+            .avatar_url
+            .as_ref()
+            .map(|url| url.as_str().to_string()),
+    }
+}
+
+pub(crate) fn current_user_response(user: &User) -> CurrentUserResponse {
+    let profile = profile_response(user);
+
+    CurrentUserResponse {
+        user_id: profile.user_id,
+        email: user.email.as_str().to_string(),
+        role: user.role.as_str().to_string(),
+        email_verified: user.email_verified,
+        display_name: profile.display_name,
+        bio: profile.bio,
+        avatar_url: profile.avatar_url,
+        deletion_scheduled_for: user.deletion_scheduled_for.map(|at| at.to_rfc3339()),
+        created_at: user.created_at.to_rfc3339(),
+    }
+}
+
+/// Look up the public profiles of several users at once, e.g. the authors of a page
+/// of posts. Users that no longer exist are missing from the map.
+pub(crate) async fn load_profiles<R: UserRepository>(
+    user_repository: &R,
+    user_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<HashMap<Uuid, ProfileResponse>, DomainError> {
+    let mut ids: Vec<Uuid> = user_ids.into_iter().collect();
+    ids.sort();
+    ids.dedup();
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let users = user_repository.find_by_ids(&ids).await?;
+    Ok(users
+        .iter()
+        .map(|user| (user.id, profile_response(user)))
+        .collect())
+}
@@ -0,0 +1,128 @@
+// crates/application/src/users/login_throttle.rs
+
+use chrono::{DateTime, Duration, Utc};
+use std::net::IpAddr;
+
+use domain::common::error::DomainError;
+use domain::users::{Email, LoginAttemptRepository};
+
+use super::token_issuer::ClientInfo;
+
+/// Limits on failed logins. Once an account or client IP reaches its limit it is locked
+/// out for `lockout`, and every further failure doubles that, up to `max_lockout`.
+#[derive(Debug, Clone, Copy)]
+pub struct LoginThrottlePolicy {
+    pub max_account_failures: u32,
+    pub max_ip_failures: u32,
+    pub lockout: Duration,
+    pub max_lockout: Duration,
+    /// Failures older than this no longer count
+    pub failure_window: Duration,
+}
+
+impl Default for LoginThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            max_account_failures: 5,
+            max_ip_failures: 20,
+            lockout: Duration::minutes(1),
+            max_lockout: Duration::hours(1),
+            failure_window: Duration::minutes(15),
+        }
+    }
+}
+
+impl LoginThrottlePolicy {
+    /// How long to lock a key out after its `failures`-th failure, given its limit
+    pub fn lockout_for(&self, failures: u32, limit: u32) -> Option<Duration> {
+        if failures < limit {
+            return None;
+        }
+
+        // 2^16 times any sensible base lockout is already past any sensible maximum
+        let doublings = (failures - limit).min(16);
+        let lockout = self
+            .lockout
+            .checked_mul(1 << doublings)
+            .unwrap_or(self.max_lockout);
+        Some(lockout.min(self.max_lockout))
+    }
+}
+
+pub(crate) fn account_key(email: &Email) -> String {
+    format!("account:{}", email.as_str().to_lowercase())
+}
+
+pub(crate) fn ip_key(ip: IpAddr) -> String {
+    format!("ip:{}", ip)
+}
+
+/// The keys an attempt from `client` is counted under, each with its limit: the
+/// account's, when it is known, and the client IP's
+pub(crate) fn throttle_keys(
+    policy: &LoginThrottlePolicy,
+    email: Option<&Email>,
+    client: &ClientInfo,
+) -> Vec<(String, u32)> {
+    let mut keys = Vec::new();
+    if let Some(email) = email {
+        keys.push((account_key(email), policy.max_account_failures));
+    }
+    if let Some(ip) = client.ip_address {
+        keys.push((ip_key(ip), policy.max_ip_failures));
+    }
+    keys
+}
+
+/// Refuse outright while any of `keys` is locked out
+pub(crate) async fn ensure_not_locked<A: LoginAttemptRepository>(
+    login_attempts: &A,
+    keys: &[(String, u32)],
+    now: DateTime<Utc>,
+) -> Result<(), DomainError> {
+    for (key, _) in keys {
+        let attempts = login_attempts.find(key).await?;
+        if let Some(until) = attempts.locked_until.filter(|until| *until > now) {
+            let retry_after_secs = (until - now).num_seconds().max(1) as u64;
+            return Err(DomainError::TooManyRequests {
+                message: "Too many failed login attempts, try again later".into(),
+                retry_after_secs,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Count a failure against each of `keys`, locking out those that reached their limit
+pub(crate) async fn record_failures<A: LoginAttemptRepository>(
+    login_attempts: &A,
+    policy: &LoginThrottlePolicy,
+    keys: &[(String, u32)],
+    now: DateTime<Utc>,
+) -> Result<(), DomainError> {
+    for (key, limit) in keys {
+        let failures = login_attempts
+            .record_failure(key, policy.failure_window)
+            .await?;
+        if let Some(lockout) = policy.lockout_for(failures, *limit) {
+            login_attempts.lock(key, now + lockout).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockout_doubles_up_to_max() {
+        let policy = LoginThrottlePolicy::default();
+
+        assert_eq!(policy.lockout_for(4, 5), None);
+        assert_eq!(policy.lockout_for(5, 5), Some(Duration::minutes(1)));
+        assert_eq!(policy.lockout_for(6, 5), Some(Duration::minutes(2)));
+        assert_eq!(policy.lockout_for(8, 5), Some(Duration::minutes(8)));
+        assert_eq!(policy.lockout_for(100, 5), Some(Duration::hours(1)));
+    }
+}
@@ -1,126 +1,251 @@
 // crates/application/src/users/login_user.rs
 
+use async_trait::async_trait;
+use chrono::Utc;
 use domain::common::error::DomainError;
-use domain::users::{Email, UserRepository};
-
-use super::dtos::{LoginUserRequest, LoginUserResponse};
-use super::register_user::TokenGenerator;
-
-pub struct LoginUserUseCase<R, V, J>
+use domain::users::{
+    Email, LoginAttemptRepository, PasswordHasher, PlainPassword, User, UserRepository,
+};
+use uuid::Uuid;
+
+use super::dtos::{LoginOutcome, LoginUserRequest, LoginUserResponse};
+use super::login_throttle::{
+    LoginThrottlePolicy, account_key, ensure_not_locked, record_failures, throttle_keys,
+};
+use super::mfa::MfaChallengeIssuer;
+use super::token_issuer::{AuthTokenIssuer, AuthTokens, ClientInfo};
+
+pub struct LoginUserUseCase<R, V, I, A, M>
 where
     R: UserRepository,
-    V: PasswordVerifier,
-    J: TokenGenerator,
+    V: PasswordVerifier + PasswordHasher,
+    I: AuthTokenIssuer,
+    A: LoginAttemptRepository,
+    M: MfaChallengeIssuer,
 {
     user_repository: R,
     password_verifier: V,
-    token_generator: J,
+    token_issuer: I,
+    login_attempts: A,
+    mfa: M,
+    throttle: LoginThrottlePolicy,
 }
 
 /// Trait for verifying passwords
+#[async_trait]
 pub trait PasswordVerifier: Send + Sync {
-    fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError>;
+    async fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError>;
+    /// Spend as long as `verify` would without a hash to check against, so that
+    /// unknown accounts cannot be told apart by response time
+    async fn verify_dummy(&self, plain_password: &str) -> Result<(), DomainError>;
+    /// Whether the hash was made with weaker settings than are now configured and should
+    /// be replaced once the password has been verified
+    fn needs_rehash(&self, _password_hash: &str) -> bool {
+        false
+    }
+    /// Check a password against the user's own. Accounts without a password, created
+    /// through an identity provider, match nothing.
+    async fn verify_user(&self, plain_password: &str, user: &User) -> Result<bool, DomainError> {
+        match &user.password_hash {
+            Some(password_hash) => self.verify(plain_password, password_hash.as_str()).await,
+            None => Ok(false),
+        }
+    }
 }
 
-impl<R, V, J> LoginUserUseCase<R, V, J>
+/// Check the password a user re-entered to confirm a sensitive change. Accounts without
+/// a password are told how to get one instead of that theirs is wrong.
+pub(crate) async fn confirm_password<V: PasswordVerifier>(
+    password_verifier: &V,
+    plain_password: &str,
+    user: &User,
+) -> Result<(), DomainError> {
+    if user.password_hash.is_none() {
+        return Err(DomainError::Validation(
+            "Account has no password; use a password reset to set one".into(),
+        ));
+    }
+    if !password_verifier.verify_user(plain_password, user).await? {
+        return Err(DomainError::Validation("Password is incorrect".into()));
+    }
+    Ok(())
+}
+
+impl<R, V, I, A, M> LoginUserUseCase<R, V, I, A, M>
 where
     R: UserRepository,
-    V: PasswordVerifier,
-    J: TokenGenerator,
+    V: PasswordVerifier + PasswordHasher,
+    I: AuthTokenIssuer,
+    A: LoginAttemptRepository,
+    M: MfaChallengeIssuer,
 {
-    pub fn new(user_repository: R, password_verifier: V, token_generator: J) -> Self {
+    pub fn new(
+        user_repository: R,
+        password_verifier: V,
+        token_issuer: I,
+        login_attempts: A,
+        mfa: M,
+        throttle: LoginThrottlePolicy,
+    ) -> Self {
         Self {
             user_repository,
             password_verifier,
-            token_generator,
+            token_issuer,
+            login_attempts,
+            mfa,
+            throttle,
         }
     }
 
+    /// Log in with email and password. Failures are counted against both the account
+    /// and the client IP, and either one is locked out once it has failed too often.
+    /// Accounts with two-factor authentication get a challenge instead of tokens.
     pub async fn execute(
         &self,
         request: LoginUserRequest,
-    ) -> Result<LoginUserResponse, DomainError> {
+        client: &ClientInfo,
+    ) -> Result<LoginOutcome, DomainError> {
         // Validate email format
         let email = Email::new(request.email)?;
 
-        // Find user by email
-        let user = self
-            .user_repository
-            .find_by_email(&email)
-            .await?
-            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
-
-        // Verify password
-        if !self
-            .password_verifier
-            .verify(&request.password, user.password_hash.as_str())?
+        let keys = throttle_keys(&self.throttle, Some(&email), client);
+        let now = Utc::now();
+        ensure_not_locked(&self.login_attempts, &keys, now).await?;
+
+        // Verify password. Unknown accounts and accounts without a password still pay for
+        // a verification so they take as long as a wrong password does.
+        let user = self.user_repository.find_by_email(&email).await?;
+        let password_hash = user.as_ref().and_then(|user| user.password_hash.as_ref());
+        let verified = match password_hash {
+            Some(password_hash) => {
+                self.password_verifier
+                    .verify(&request.password, password_hash.as_str())
+                    .await?
+            }
+            None => {
+                self.password_verifier
+                    .verify_dummy(&request.password)
+                    .await?;
+                false
+            }
+        };
+
+        let Some(user) = user.filter(|_| verified) else {
+            record_failures(&self.login_attempts, &self.throttle, &keys, now).await?;
+            return Err(DomainError::Unauthorized("Invalid credentials".into()));
+        };
+
+        // Upgrade hashes made with older settings while the plain password is at hand. The
+        // old hash still works, so a failed upgrade is retried on the next login instead of
+        // failing this one.
+        if user
+            .password_hash
+            .as_ref()
+            .is_some_and(|hash| self.password_verifier.needs_rehash(hash.as_str()))
+            && let Ok(password) = PlainPassword::new(request.password)
+            && let Err(e) = self.rehash(user.id, password).await
         {
-            return Err(DomainError::Validation("Invalid credentials".into()));
+            tracing::warn!(error = %e, user_id = %user.id, "Failed to rehash password");
         }
 
-        // Generate token
-        let token = self.token_generator.generate(user.id, user.email.as_str())?;
+        // The account's failures stand until the second factor has been answered as well
+        if let Some(challenge) = self.mfa.challenge(&user).await? {
+            return Ok(LoginOutcome::MfaRequired(challenge));
+        }
+
+        // The IP keeps its count, so one good login does not reset a spraying client
+        self.login_attempts.clear(&account_key(&email)).await?;
+
+        // Issue access and refresh tokens
+        let tokens = self.token_issuer.issue(&user, client).await?;
+
+        Ok(LoginOutcome::Authenticated(login_response(&user, tokens)))
+    }
+
+    async fn rehash(&self, user_id: Uuid, password: PlainPassword) -> Result<(), DomainError> {
+        let password_hash = self.password_verifier.hash(password).await?;
+        self.user_repository
+            .update_password(user_id, password_hash)
+            .await
+    }
+}
 
-        Ok(LoginUserResponse {
-            user_id: user.id.to_string(),
-            email: user.email.as_str().to_string(),
-            token,
-        })
+pub(crate) fn login_response(user: &User, tokens: AuthTokens) -> LoginUserResponse {
+    LoginUserResponse {
+        user_id: user.id.to_string(),
+        email: user.email.as_str().to_string(),
+        role: user.role.as_str().to_string(),
+        email_verified: user.email_verified,
+        token: tokens.access_token,
+        token_expires_at: tokens.access_token_expires_at.to_rfc3339(),
+        refresh_token: tokens.refresh_token,
+        refresh_token_expires_at: tokens.refresh_token_expires_at.to_rfc3339(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{
+        MockLoginAttemptRepository, MockMfa, MockTokenIssuer, MockUserRepository,
+    };
     use async_trait::async_trait;
+    use chrono::Duration;
     use domain::users::{PasswordHash, User};
+
     use std::sync::{Arc, Mutex};
 
-    struct MockUserRepository {
-        users: Arc<Mutex<Vec<User>>>,
+    #[derive(Clone, Default)]
+    struct MockPasswordVerifier {
+        should_verify: bool,
+        outdated_hash: bool,
+        failing_hash: bool,
+        dummy_verifications: Arc<Mutex<u32>>,
     }
 
-    impl MockUserRepository {
-        fn new_with_user(user: User) -> Self {
+    impl MockPasswordVerifier {
+        fn new(should_verify: bool) -> Self {
             Self {
-                users: Arc::new(Mutex::new(vec![user])),
+                should_verify,
+                ..Default::default()
             }
         }
     }
 
     #[async_trait]
-    impl UserRepository for MockUserRepository {
-        async fn save(&self, user: User) -> Result<(), DomainError> {
-            self.users.lock().unwrap().push(user);
-            Ok(())
+    impl PasswordVerifier for MockPasswordVerifier {
+        async fn verify(
+            &self,
+            _plain_password: &str,
+            _password_hash: &str,
+        ) -> Result<bool, DomainError> {
+            Ok(self.should_verify)
         }
 
-        async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == *email)
-                .cloned())
+        async fn verify_dummy(&self, _plain_password: &str) -> Result<(), DomainError> {
+            *self.dummy_verifications.lock().unwrap() += 1;
+            Ok(())
         }
-    }
 
-    struct MockPasswordVerifier {
-        should_verify: bool,
+        fn needs_rehash(&self, password_hash: &str) -> bool {
+            self.outdated_hash && !password_hash.starts_with("rehashed_")
+        }
     }
 
-    impl PasswordVerifier for MockPasswordVerifier {
-        fn verify(&self, _plain_password: &str, _password_hash: &str) -> Result<bool, DomainError> {
-            Ok(self.should_verify)
+    #[async_trait]
+    impl PasswordHasher for MockPasswordVerifier {
+        async fn hash(&self, password: PlainPassword) -> Result<PasswordHash, DomainError> {
+            if self.failing_hash {
+                return Err(DomainError::InfraError("Hashing failed".into()));
+            }
+            Ok(PasswordHash::new(format!("rehashed_{}", password.as_str())))
         }
     }
 
-    struct MockTokenGenerator;
-
-    impl TokenGenerator for MockTokenGenerator {
-        fn generate(&self, user_id: uuid::Uuid, _email: &str) -> Result<String, DomainError> {
-            Ok(format!("token_{}", user_id))
+    fn login_request(email: &str, password: &str) -> LoginUserRequest {
+        LoginUserRequest {
+            email: email.to_string(),
+            password: password.to_string(),
         }
     }
 
@@ -131,9 +256,12 @@ mod tests {
         let user = User::new(email.clone(), password_hash);
 
         let use_case = LoginUserUseCase::new(
-            MockUserRepository::new_with_user(user.clone()),
-            MockPasswordVerifier { should_verify: true },
-            MockTokenGenerator,
+            MockUserRepository::with_user(user.clone()),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
         );
 
         let request = LoginUserRequest {
@@ -141,10 +269,12 @@ mod tests {
             password: "password123".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_ok());
 
-        let response = result.unwrap();
+        let LoginOutcome::Authenticated(response) = result.unwrap() else {
+            panic!("expected tokens");
+        };
         assert_eq!(response.email, "test@example.com");
         assert!(response.token.starts_with("token_"));
     }
@@ -156,9 +286,12 @@ mod tests {
         let user = User::new(email, password_hash);
 
         let use_case = LoginUserUseCase::new(
-            MockUserRepository::new_with_user(user),
-            MockPasswordVerifier { should_verify: true },
-            MockTokenGenerator,
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
         );
 
         let request = LoginUserRequest {
@@ -166,9 +299,9 @@ mod tests {
             password: "password123".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), DomainError::NotFound(_)));
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
     }
 
     #[tokio::test]
@@ -178,9 +311,12 @@ mod tests {
         let user = User::new(email, password_hash);
 
         let use_case = LoginUserUseCase::new(
-            MockUserRepository::new_with_user(user),
-            MockPasswordVerifier { should_verify: false },
-            MockTokenGenerator,
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(false),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
         );
 
         let request = LoginUserRequest {
@@ -188,8 +324,325 @@ mod tests {
             password: "wrongpassword".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_login_unknown_user_verifies_dummy_hash() {
+        let verifier = MockPasswordVerifier::new(true);
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::default(),
+            verifier.clone(),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
+        );
+
+        let result = use_case
+            .execute(
+                login_request("nobody@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        assert_eq!(*verifier.dummy_verifications.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_login_passwordless_user_is_refused() {
+        let verifier = MockPasswordVerifier::new(true);
+        let user = User::new_passwordless(Email::new("oidc@example.com").unwrap(), true);
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::with_user(user),
+            verifier.clone(),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
+        );
+
+        let result = use_case
+            .execute(
+                login_request("oidc@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        assert_eq!(*verifier.dummy_verifications.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_login_locks_account_after_too_many_failures() {
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let attempts = MockLoginAttemptRepository::default();
+        let policy = LoginThrottlePolicy {
+            max_account_failures: 3,
+            ..Default::default()
+        };
+        let failing = LoginUserUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            MockPasswordVerifier::new(false),
+            MockTokenIssuer::default(),
+            attempts.clone(),
+            MockMfa::default(),
+            policy,
+        );
+
+        for _ in 0..3 {
+            let result = failing
+                .execute(
+                    login_request("test@example.com", "wrongpassword"),
+                    &ClientInfo::default(),
+                )
+                .await;
+            assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        }
+
+        // Even the right password is refused while the lockout lasts
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            attempts,
+            MockMfa::default(),
+            policy,
+        );
+        let result = use_case
+            .execute(
+                login_request("test@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await;
+
+        match result {
+            Err(DomainError::TooManyRequests {
+                retry_after_secs, ..
+            }) => {
+                assert!(retry_after_secs > 0 && retry_after_secs <= 60);
+            }
+            other => panic!("expected lockout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_locks_ip_across_accounts() {
+        let attempts = MockLoginAttemptRepository::default();
+        let policy = LoginThrottlePolicy {
+            max_ip_failures: 2,
+            ..Default::default()
+        };
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::default(),
+            MockPasswordVerifier::new(false),
+            MockTokenIssuer::default(),
+            attempts,
+            MockMfa::default(),
+            policy,
+        );
+        let client = ClientInfo {
+            ip_address: Some("203.0.113.7".parse().unwrap()),
+            user_agent: None,
+        };
+
+        for email in ["a@example.com", "b@example.com"] {
+            let result = use_case
+                .execute(login_request(email, "password123"), &client)
+                .await;
+            assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        }
+
+        let result = use_case
+            .execute(login_request("c@example.com", "password123"), &client)
+            .await;
+        assert!(matches!(result, Err(DomainError::TooManyRequests { .. })));
+
+        // Other clients are unaffected
+        let result = use_case
+            .execute(
+                login_request("c@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_successful_login_clears_account_failures() {
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let attempts = MockLoginAttemptRepository::default();
+        attempts
+            .record_failure("account:test@example.com", Duration::minutes(15))
+            .await
+            .unwrap();
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            attempts.clone(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
+        );
+
+        use_case
+            .execute(
+                login_request("test@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        let remaining = attempts.find("account:test@example.com").await.unwrap();
+        assert_eq!(remaining.failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_login_rehashes_outdated_hash() {
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let user_repository = MockUserRepository::with_user(user.clone());
+        let use_case = LoginUserUseCase::new(
+            user_repository.clone(),
+            MockPasswordVerifier {
+                should_verify: true,
+                outdated_hash: true,
+                ..Default::default()
+            },
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
+        );
+
+        use_case
+            .execute(
+                login_request("test@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        let stored = user_repository.find_by_id(user.id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.password_hash.as_ref().unwrap().as_str(),
+            "rehashed_password123"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_succeeds_when_rehash_fails() {
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let user_repository = MockUserRepository::with_user(user.clone());
+        let use_case = LoginUserUseCase::new(
+            user_repository.clone(),
+            MockPasswordVerifier {
+                should_verify: true,
+                outdated_hash: true,
+                failing_hash: true,
+                ..Default::default()
+            },
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa::default(),
+            LoginThrottlePolicy::default(),
+        );
+
+        let result = use_case
+            .execute(
+                login_request("test@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Ok(LoginOutcome::Authenticated(_))));
+        // The old hash stays, to be upgraded on a later login
+        let stored = user_repository.find_by_id(user.id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.password_hash.as_ref().unwrap().as_str(),
+            "hashed_password"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_with_mfa_returns_challenge() {
+        let user = User::new(
+            Email::new("admin@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            MockLoginAttemptRepository::default(),
+            MockMfa {
+                enabled: true,
+                ..Default::default()
+            },
+            LoginThrottlePolicy::default(),
+        );
+
+        let outcome = use_case
+            .execute(
+                login_request("admin@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            outcome,
+            LoginOutcome::MfaRequired(challenge) if challenge.mfa_token == "mfa_token"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_mfa_keeps_account_failures() {
+        let user = User::new(
+            Email::new("admin@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        );
+        let attempts = MockLoginAttemptRepository::default();
+        attempts
+            .record_failure("account:admin@example.com", Duration::minutes(15))
+            .await
+            .unwrap();
+        let use_case = LoginUserUseCase::new(
+            MockUserRepository::with_user(user),
+            MockPasswordVerifier::new(true),
+            MockTokenIssuer::default(),
+            attempts.clone(),
+            MockMfa {
+                enabled: true,
+                ..Default::default()
+            },
+            LoginThrottlePolicy::default(),
+        );
+
+        use_case
+            .execute(
+                login_request("admin@example.com", "password123"),
+                &ClientInfo::default(),
+            )
+            .await
+            .unwrap();
+
+        // Cleared only once the second factor has been answered
+        let remaining = attempts.find("account:admin@example.com").await.unwrap();
+        assert_eq!(remaining.failures, 1);
     }
 }
@@ -0,0 +1,169 @@
+// crates/application/src/users/logout_user.rs
+
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::users::RevokedTokenRepository;
+use uuid::Uuid;
+
+use super::dtos::LogoutUserRequest;
+use super::token_issuer::AuthTokenIssuer;
+
+pub struct LogoutUserUseCase<T, I>
+where
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    revoked_token_repository: T,
+    token_issuer: I,
+}
+
+impl<T, I> LogoutUserUseCase<T, I>
+where
+    T: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    pub fn new(revoked_token_repository: T, token_issuer: I) -> Self {
+        Self {
+            revoked_token_repository,
+            token_issuer,
+        }
+    }
+
+    /// Revoke the access token identified by `token_id` until it expires, and the
+    /// user's refresh token chain if one is provided
+    pub async fn execute(
+        &self,
+        request: LogoutUserRequest,
+        user_id: Uuid,
+        token_id: Uuid,
+        token_expires_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        self.revoked_token_repository
+            .revoke(token_id, token_expires_at)
+            .await?;
+
+        if let Some(refresh_token) = request.refresh_token {
+            self.token_issuer.revoke(user_id, &refresh_token).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockOpaqueTokenGenerator, MockRefreshTokenRepository, MockRevokedTokenRepository,
+        MockSessionRepository, MockTokenGenerator,
+    };
+    use crate::users::{ClientInfo, TokenIssuer};
+    use chrono::Duration;
+    use domain::users::{Email, PasswordHash, User};
+
+    type Issuer = TokenIssuer<
+        MockTokenGenerator,
+        MockRefreshTokenRepository,
+        MockSessionRepository,
+        MockOpaqueTokenGenerator,
+    >;
+
+    fn issuer() -> Issuer {
+        TokenIssuer::new(
+            MockTokenGenerator,
+            MockRefreshTokenRepository::default(),
+            MockSessionRepository::default(),
+            MockOpaqueTokenGenerator,
+            Duration::days(30),
+        )
+    }
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_access_token() {
+        let revoked_tokens = MockRevokedTokenRepository::default();
+        let use_case = LogoutUserUseCase::new(revoked_tokens.clone(), issuer());
+        let user_id = Uuid::new_v4();
+        let token_id = Uuid::new_v4();
+        let issued_at = Utc::now();
+
+        use_case
+            .execute(
+                LogoutUserRequest {
+                    refresh_token: None,
+                },
+                user_id,
+                token_id,
+                issued_at + Duration::minutes(15),
+            )
+            .await
+            .unwrap();
+
+        assert!(
+            revoked_tokens
+                .is_revoked(token_id, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+        // Other tokens of the same user stay valid
+        assert!(
+            !revoked_tokens
+                .is_revoked(Uuid::new_v4(), user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_logout_revokes_refresh_token_chain() {
+        let issuer = issuer();
+        let use_case =
+            LogoutUserUseCase::new(MockRevokedTokenRepository::default(), issuer.clone());
+        let user = user();
+        let tokens = issuer.issue(&user, &ClientInfo::default()).await.unwrap();
+
+        use_case
+            .execute(
+                LogoutUserRequest {
+                    refresh_token: Some(tokens.refresh_token.clone()),
+                },
+                user.id,
+                Uuid::new_v4(),
+                tokens.access_token_expires_at,
+            )
+            .await
+            .unwrap();
+
+        let result = issuer.redeem(&tokens.refresh_token).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_logout_ignores_another_users_refresh_token() {
+        let issuer = issuer();
+        let use_case =
+            LogoutUserUseCase::new(MockRevokedTokenRepository::default(), issuer.clone());
+        let victim = user();
+        let tokens = issuer.issue(&victim, &ClientInfo::default()).await.unwrap();
+
+        use_case
+            .execute(
+                LogoutUserRequest {
+                    refresh_token: Some(tokens.refresh_token.clone()),
+                },
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                tokens.access_token_expires_at,
+            )
+            .await
+            .unwrap();
+
+        assert!(issuer.redeem(&tokens.refresh_token).await.is_ok());
+    }
+}
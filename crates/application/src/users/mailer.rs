@@ -0,0 +1,18 @@
+// crates/application/src/users/mailer.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+
+/// A plain text email
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Trait for delivering outbound email
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError>;
+}
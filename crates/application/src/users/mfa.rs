@@ -0,0 +1,375 @@
+// crates/application/src/users/mfa.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{
+    MfaChallenge, MfaChallengeRepository, TotpCredential, TotpCredentialRepository, User,
+};
+use uuid::Uuid;
+
+use super::dtos::MfaChallengeResponse;
+use super::token_issuer::OpaqueTokenGenerator;
+
+// Wrong codes a single challenge tolerates before it is thrown away
+const MAX_CHALLENGE_FAILURES: u32 = 5;
+
+/// Trait for time-based one-time passwords and the recovery codes that replace them
+pub trait TotpProvider: Send + Sync {
+    fn generate_secret(&self) -> String;
+    /// `otpauth://` URI for enrolling `secret` in an authenticator app
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> String;
+    /// The time step `code` is valid for at `now`, if any
+    fn verify(&self, secret: &str, code: &str, now: DateTime<Utc>) -> Option<i64>;
+    fn generate_recovery_code(&self) -> String;
+}
+
+/// Trait for the second step of a login
+#[async_trait]
+pub trait MfaChallengeIssuer: Send + Sync {
+    /// Start a challenge if the user has two-factor authentication enabled
+    async fn challenge(&self, user: &User) -> Result<Option<MfaChallengeResponse>, DomainError>;
+
+    /// The user a live challenge was issued to, so wrong answers can be counted against
+    /// their account
+    async fn challenged_user(&self, mfa_token: &str) -> Result<Option<Uuid>, DomainError>;
+
+    /// Answer a challenge with an authenticator or recovery code; returns the user it
+    /// was issued to. Each challenge can be answered successfully once.
+    async fn redeem(&self, mfa_token: &str, code: &str) -> Result<Uuid, DomainError>;
+}
+
+/// Issues short-lived challenges to accounts with a confirmed TOTP credential
+#[derive(Clone)]
+pub struct MfaService<T, C, O, P>
+where
+    T: TotpCredentialRepository,
+    C: MfaChallengeRepository,
+    O: OpaqueTokenGenerator,
+    P: TotpProvider,
+{
+    credential_repository: T,
+    challenge_repository: C,
+    opaque_token_generator: O,
+    totp_provider: P,
+    challenge_ttl: Duration,
+}
+
+impl<T, C, O, P> MfaService<T, C, O, P>
+where
+    T: TotpCredentialRepository,
+    C: MfaChallengeRepository,
+    O: OpaqueTokenGenerator,
+    P: TotpProvider,
+{
+    pub fn new(
+        credential_repository: T,
+        challenge_repository: C,
+        opaque_token_generator: O,
+        totp_provider: P,
+        challenge_ttl: Duration,
+    ) -> Self {
+        Self {
+            credential_repository,
+            challenge_repository,
+            opaque_token_generator,
+            totp_provider,
+            challenge_ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, C, O, P> MfaChallengeIssuer for MfaService<T, C, O, P>
+where
+    T: TotpCredentialRepository,
+    C: MfaChallengeRepository,
+    O: OpaqueTokenGenerator,
+    P: TotpProvider,
+{
+    async fn challenge(&self, user: &User) -> Result<Option<MfaChallengeResponse>, DomainError> {
+        let enabled = self
+            .credential_repository
+            .find_by_user(user.id)
+            .await?
+            .is_some_and(|credential| credential.is_confirmed());
+        if !enabled {
+            return Ok(None);
+        }
+
+        let token = self.opaque_token_generator.generate();
+        let expires_at = Utc::now() + self.challenge_ttl;
+        self.challenge_repository
+            .save(MfaChallenge::new(
+                user.id,
+                self.opaque_token_generator.hash(&token),
+                expires_at,
+            ))
+            .await?;
+
+        Ok(Some(MfaChallengeResponse {
+            mfa_token: token,
+            mfa_token_expires_at: expires_at.to_rfc3339(),
+        }))
+    }
+
+    async fn challenged_user(&self, mfa_token: &str) -> Result<Option<Uuid>, DomainError> {
+        Ok(self
+            .challenge_repository
+            .find_by_hash(&self.opaque_token_generator.hash(mfa_token))
+            .await?
+            .filter(|challenge| !challenge.is_expired())
+            .map(|challenge| challenge.user_id))
+    }
+
+    async fn redeem(&self, mfa_token: &str, code: &str) -> Result<Uuid, DomainError> {
+        let invalid = || DomainError::Unauthorized("Invalid or expired MFA token".into());
+
+        let challenge = self
+            .challenge_repository
+            .find_by_hash(&self.opaque_token_generator.hash(mfa_token))
+            .await?
+            .filter(|challenge| !challenge.is_expired())
+            .ok_or_else(invalid)?;
+        let credential = self
+            .credential_repository
+            .find_by_user(challenge.user_id)
+            .await?
+            .filter(TotpCredential::is_confirmed)
+            .ok_or_else(invalid)?;
+
+        let mut accepted = accept_totp_code(
+            &self.credential_repository,
+            &self.totp_provider,
+            &credential,
+            code,
+        )
+        .await?;
+        if !accepted {
+            let code_hash = hash_recovery_code(&self.opaque_token_generator, code);
+            accepted = self
+                .credential_repository
+                .use_recovery_code(credential.user_id, &code_hash)
+                .await?;
+        }
+
+        if !accepted {
+            let failures = self
+                .challenge_repository
+                .record_failure(challenge.id)
+                .await?;
+            if failures >= MAX_CHALLENGE_FAILURES {
+                self.challenge_repository.delete(challenge.id).await?;
+            }
+            return Err(DomainError::Unauthorized("Invalid code".into()));
+        }
+
+        // Conditional delete so the challenge cannot be answered twice concurrently
+        if !self.challenge_repository.delete(challenge.id).await? {
+            return Err(invalid());
+        }
+
+        Ok(challenge.user_id)
+    }
+}
+
+/// Check an authenticator code against the credential, refusing codes from a time step
+/// that was already used
+pub(crate) async fn accept_totp_code<T, P>(
+    credential_repository: &T,
+    totp_provider: &P,
+    credential: &TotpCredential,
+    code: &str,
+) -> Result<bool, DomainError>
+where
+    T: TotpCredentialRepository,
+    P: TotpProvider,
+{
+    match totp_provider.verify(&credential.secret, code, Utc::now()) {
+        Some(step) => {
+            credential_repository
+                .use_step(credential.user_id, step)
+                .await
+        }
+        None => Ok(false),
+    }
+}
+
+/// Hash a recovery code for storage, ignoring case and separators so it can be typed
+/// back however is convenient
+pub(crate) fn hash_recovery_code<O: OpaqueTokenGenerator>(generator: &O, code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    generator.hash(&normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockOpaqueTokenGenerator, MockTotpCredentialRepository, MockTotpProvider,
+    };
+    use domain::users::{Email, PasswordHash};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct MockMfaChallengeRepository {
+        challenges: Arc<Mutex<Vec<MfaChallenge>>>,
+    }
+
+    #[async_trait]
+    impl MfaChallengeRepository for MockMfaChallengeRepository {
+        async fn save(&self, challenge: MfaChallenge) -> Result<(), DomainError> {
+            self.challenges.lock().unwrap().push(challenge);
+            Ok(())
+        }
+
+        async fn find_by_hash(
+            &self,
+            token_hash: &str,
+        ) -> Result<Option<MfaChallenge>, DomainError> {
+            Ok(self
+                .challenges
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.token_hash == token_hash)
+                .cloned())
+        }
+
+        async fn record_failure(&self, id: Uuid) -> Result<u32, DomainError> {
+            let mut challenges = self.challenges.lock().unwrap();
+            let challenge = challenges
+                .iter_mut()
+                .find(|c| c.id == id)
+                .ok_or_else(|| DomainError::NotFound("MFA challenge not found".into()))?;
+            challenge.failed_attempts += 1;
+            Ok(challenge.failed_attempts)
+        }
+
+        async fn delete(&self, id: Uuid) -> Result<bool, DomainError> {
+            let mut challenges = self.challenges.lock().unwrap();
+            let before = challenges.len();
+            challenges.retain(|c| c.id != id);
+            Ok(challenges.len() < before)
+        }
+
+        async fn purge_expired(&self) -> Result<u64, DomainError> {
+            Ok(0)
+        }
+    }
+
+    fn enabled_user(credentials: &MockTotpCredentialRepository) -> User {
+        let user = User::new(
+            Email::new("admin@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        let mut credential = TotpCredential::new(user.id, "SECRET".to_string());
+        credential.confirmed_at = Some(Utc::now());
+        credentials
+            .credentials
+            .lock()
+            .unwrap()
+            .insert(user.id, credential);
+        credentials
+            .recovery_codes
+            .lock()
+            .unwrap()
+            .push((user.id, "hash_recovery1".to_string()));
+        user
+    }
+
+    fn service(
+        credentials: MockTotpCredentialRepository,
+    ) -> MfaService<
+        MockTotpCredentialRepository,
+        MockMfaChallengeRepository,
+        MockOpaqueTokenGenerator,
+        MockTotpProvider,
+    > {
+        MfaService::new(
+            credentials,
+            MockMfaChallengeRepository::default(),
+            MockOpaqueTokenGenerator,
+            MockTotpProvider::default(),
+            Duration::minutes(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_no_challenge_without_confirmed_credential() {
+        let credentials = MockTotpCredentialRepository::default();
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        credentials
+            .save(TotpCredential::new(user.id, "SECRET".to_string()))
+            .await
+            .unwrap();
+
+        let challenge = service(credentials).challenge(&user).await.unwrap();
+
+        assert!(challenge.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_with_totp_code_once() {
+        let credentials = MockTotpCredentialRepository::default();
+        let user = enabled_user(&credentials);
+        let service = service(credentials);
+
+        let challenge = service.challenge(&user).await.unwrap().unwrap();
+        let user_id = service
+            .redeem(&challenge.mfa_token, "123456")
+            .await
+            .unwrap();
+        assert_eq!(user_id, user.id);
+
+        // The challenge is used up, and the code cannot be replayed on a new one
+        let result = service.redeem(&challenge.mfa_token, "123456").await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        let challenge = service.challenge(&user).await.unwrap().unwrap();
+        let result = service.redeem(&challenge.mfa_token, "123456").await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        assert!(service.redeem(&challenge.mfa_token, "654321").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_with_recovery_code() {
+        let credentials = MockTotpCredentialRepository::default();
+        let user = enabled_user(&credentials);
+        let service = service(credentials);
+
+        let challenge = service.challenge(&user).await.unwrap().unwrap();
+        assert!(
+            service
+                .redeem(&challenge.mfa_token, "RECOVERY-1")
+                .await
+                .is_ok()
+        );
+
+        let challenge = service.challenge(&user).await.unwrap().unwrap();
+        let result = service.redeem(&challenge.mfa_token, "recovery1").await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_challenge_dropped_after_too_many_wrong_codes() {
+        let credentials = MockTotpCredentialRepository::default();
+        let user = enabled_user(&credentials);
+        let service = service(credentials);
+
+        let challenge = service.challenge(&user).await.unwrap().unwrap();
+        for _ in 0..MAX_CHALLENGE_FAILURES {
+            let result = service.redeem(&challenge.mfa_token, "000000").await;
+            assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        }
+
+        let result = service.redeem(&challenge.mfa_token, "123456").await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(msg)) if msg.contains("MFA token")));
+    }
+}
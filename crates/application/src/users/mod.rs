@@ -1,9 +1,71 @@
 // crates/application/src/users/mod.rs
 
-mod register_user;
-mod login_user;
+mod api_keys;
+mod change_email;
+mod change_password;
+mod change_role;
+mod delete_account;
 mod dtos;
+mod email_verification;
+mod export_user_data;
+mod get_profile;
+mod login_throttle;
+mod login_user;
+mod logout_user;
+mod mailer;
+mod mfa;
+mod oidc_login;
+mod refresh_token;
+mod register_user;
+mod request_password_reset;
+mod resend_verification_email;
+mod reset_password;
+mod sessions;
+mod token_issuer;
+mod totp_enrollment;
+mod update_profile;
+mod verify_email;
+mod verify_mfa;
 
-pub use register_user::{RegisterUserUseCase, TokenGenerator};
+pub use api_keys::{
+    AuthenticateApiKeyUseCase, CreateApiKeyUseCase, ListApiKeysUseCase, RevokeApiKeyUseCase,
+};
+pub use change_email::ChangeEmailUseCase;
+pub use change_password::ChangePasswordUseCase;
+pub use change_role::ChangeRoleUseCase;
+pub use delete_account::{AccountDeletionMode, DeleteAccountUseCase};
+pub use dtos::{
+    AccountDeletionResponse, ApiKeyResponse, ChangeEmailRequest, ChangeEmailResponse,
+    ChangePasswordRequest, ChangePasswordResponse, ChangeRoleRequest, ConfirmTotpRequest,
+    CreateApiKeyRequest, CreateApiKeyResponse, CurrentUserResponse, DeleteAccountRequest,
+    DisableTotpRequest, LoginOutcome, LoginUserRequest, LoginUserResponse, LogoutUserRequest,
+    MfaChallengeResponse, OidcAuthorizationResponse, OidcCallbackRequest, ProfileResponse,
+    RecoveryCodesResponse, RefreshTokenRequest, RefreshTokenResponse, RegisterUserRequest,
+    RegisterUserResponse, RequestPasswordResetRequest, ResetPasswordRequest, SessionResponse,
+    TotpEnrollmentResponse, UpdateProfileRequest, UserDataExport, UserRoleResponse,
+    VerifyEmailRequest, VerifyEmailResponse, VerifyMfaRequest,
+};
+pub use email_verification::{EmailVerificationIssuer, EmailVerificationService};
+pub use export_user_data::ExportUserDataUseCase;
+pub use get_profile::GetProfileUseCase;
+pub(crate) use get_profile::load_profiles;
+pub use login_throttle::LoginThrottlePolicy;
 pub use login_user::{LoginUserUseCase, PasswordVerifier};
-pub use dtos::{RegisterUserRequest, RegisterUserResponse, LoginUserRequest, LoginUserResponse};
+pub use logout_user::LogoutUserUseCase;
+pub use mailer::{EmailMessage, Mailer};
+pub use mfa::{MfaChallengeIssuer, MfaService, TotpProvider};
+pub use oidc_login::{OidcClaims, OidcLoginUseCase, OidcProvider, StartOidcLoginUseCase};
+pub use refresh_token::RefreshTokenUseCase;
+pub use register_user::RegisterUserUseCase;
+pub use request_password_reset::RequestPasswordResetUseCase;
+pub use resend_verification_email::ResendVerificationEmailUseCase;
+pub use reset_password::ResetPasswordUseCase;
+pub use sessions::{ListSessionsUseCase, LogoutEverywhereUseCase, RevokeSessionUseCase};
+pub use token_issuer::{
+    AccessToken, AuthTokenIssuer, AuthTokens, ClientInfo, OpaqueTokenGenerator, TokenGenerator,
+    TokenIssuer,
+};
+pub use totp_enrollment::{ConfirmTotpUseCase, DisableTotpUseCase, EnrollTotpUseCase};
+pub use update_profile::UpdateProfileUseCase;
+pub use verify_email::VerifyEmailUseCase;
+pub use verify_mfa::VerifyMfaUseCase;
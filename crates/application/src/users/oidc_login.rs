@@ -0,0 +1,536 @@
+// crates/application/src/users/oidc_login.rs
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{
+    Email, OidcAuthorization, OidcAuthorizationRepository, User, UserIdentity,
+    UserIdentityRepository, UserRepository,
+};
+
+use super::dtos::{LoginOutcome, OidcAuthorizationResponse, OidcCallbackRequest};
+use super::login_user::login_response;
+use super::mfa::MfaChallengeIssuer;
+use super::token_issuer::{AuthTokenIssuer, ClientInfo, OpaqueTokenGenerator};
+
+/// Claims of a validated ID token that sign-in relies on
+#[derive(Debug, Clone)]
+pub struct OidcClaims {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub nonce: Option<String>,
+}
+
+/// Trait for the authorization code flow against external OpenID Connect providers
+#[async_trait]
+pub trait OidcProvider: Send + Sync {
+    /// URL that starts sign-in at `provider`, with the S256 PKCE challenge of `code_verifier`
+    fn authorization_url(
+        &self,
+        provider: &str,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError>;
+
+    /// Redeem an authorization code; returns the claims of the validated ID token
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<OidcClaims, DomainError>;
+}
+
+pub struct StartOidcLoginUseCase<A, P, O>
+where
+    A: OidcAuthorizationRepository,
+    P: OidcProvider,
+    O: OpaqueTokenGenerator,
+{
+    authorization_repository: A,
+    oidc_provider: P,
+    opaque_token_generator: O,
+    authorization_ttl: Duration,
+}
+
+impl<A, P, O> StartOidcLoginUseCase<A, P, O>
+where
+    A: OidcAuthorizationRepository,
+    P: OidcProvider,
+    O: OpaqueTokenGenerator,
+{
+    pub fn new(
+        authorization_repository: A,
+        oidc_provider: P,
+        opaque_token_generator: O,
+        authorization_ttl: Duration,
+    ) -> Self {
+        Self {
+            authorization_repository,
+            oidc_provider,
+            opaque_token_generator,
+            authorization_ttl,
+        }
+    }
+
+    /// Start signing in with `provider`. The state, nonce and PKCE verifier are fresh
+    /// random tokens; only a hash of the state is stored.
+    pub async fn execute(&self, provider: &str) -> Result<OidcAuthorizationResponse, DomainError> {
+        let state = self.opaque_token_generator.generate();
+        let nonce = self.opaque_token_generator.generate();
+        let code_verifier = self.opaque_token_generator.generate();
+
+        let authorization_url =
+            self.oidc_provider
+                .authorization_url(provider, &state, &nonce, &code_verifier)?;
+
+        let expires_at = Utc::now() + self.authorization_ttl;
+        self.authorization_repository
+            .save(OidcAuthorization::new(
+                self.opaque_token_generator.hash(&state),
+                provider.to_string(),
+                nonce,
+                code_verifier,
+                expires_at,
+            ))
+            .await?;
+
+        Ok(OidcAuthorizationResponse {
+            authorization_url,
+            state,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+}
+
+pub struct OidcLoginUseCase<R, U, A, P, O, I, M>
+where
+    R: UserRepository,
+    U: UserIdentityRepository,
+    A: OidcAuthorizationRepository,
+    P: OidcProvider,
+    O: OpaqueTokenGenerator,
+    I: AuthTokenIssuer,
+    M: MfaChallengeIssuer,
+{
+    user_repository: R,
+    identity_repository: U,
+    authorization_repository: A,
+    oidc_provider: P,
+    opaque_token_generator: O,
+    token_issuer: I,
+    mfa: M,
+}
+
+impl<R, U, A, P, O, I, M> OidcLoginUseCase<R, U, A, P, O, I, M>
+where
+    R: UserRepository,
+    U: UserIdentityRepository,
+    A: OidcAuthorizationRepository,
+    P: OidcProvider,
+    O: OpaqueTokenGenerator,
+    I: AuthTokenIssuer,
+    M: MfaChallengeIssuer,
+{
+    pub fn new(
+        user_repository: R,
+        identity_repository: U,
+        authorization_repository: A,
+        oidc_provider: P,
+        opaque_token_generator: O,
+        token_issuer: I,
+        mfa: M,
+    ) -> Self {
+        Self {
+            user_repository,
+            identity_repository,
+            authorization_repository,
+            oidc_provider,
+            opaque_token_generator,
+            token_issuer,
+            mfa,
+        }
+    }
+
+    /// Finish signing in with `provider` once it has redirected back. A first sign-in
+    /// links the identity to the account with the same verified email, or creates a
+    /// passwordless account. Accounts with two-factor authentication get a challenge.
+    pub async fn execute(
+        &self,
+        provider: &str,
+        request: OidcCallbackRequest,
+        client: &ClientInfo,
+    ) -> Result<LoginOutcome, DomainError> {
+        let authorization = self
+            .authorization_repository
+            .take(&self.opaque_token_generator.hash(&request.state))
+            .await?
+            .filter(|authorization| {
+                !authorization.is_expired() && authorization.provider == provider
+            })
+            .ok_or_else(|| DomainError::Unauthorized("Invalid or expired sign-in state".into()))?;
+
+        let claims = self
+            .oidc_provider
+            .exchange_code(provider, &request.code, &authorization.code_verifier)
+            .await?;
+        // Guards against an ID token from another sign-in being replayed into this one
+        if claims.nonce.as_deref() != Some(authorization.nonce.as_str()) {
+            return Err(DomainError::Unauthorized(
+                "ID token nonce does not match".into(),
+            ));
+        }
+
+        let user = match self
+            .identity_repository
+            .find_by_subject(provider, &claims.subject)
+            .await?
+        {
+            Some(identity) => self
+                .user_repository
+                .find_by_id(identity.user_id)
+                .await?
+                .ok_or_else(|| DomainError::Unauthorized("Account no longer exists".into()))?,
+            None => self.link_or_register(provider, claims).await?,
+        };
+
+        if let Some(challenge) = self.mfa.challenge(&user).await? {
+            return Ok(LoginOutcome::MfaRequired(challenge));
+        }
+
+        let tokens = self.token_issuer.issue(&user, client).await?;
+
+        Ok(LoginOutcome::Authenticated(login_response(&user, tokens)))
+    }
+
+    /// Attach a provider identity seen for the first time to a user. Only emails the
+    /// provider has verified are trusted, and an existing account is only taken over
+    /// if its own address has been verified too; otherwise whoever registered it first
+    /// without owning the mailbox would gain the provider user's sign-in.
+    async fn link_or_register(
+        &self,
+        provider: &str,
+        claims: OidcClaims,
+    ) -> Result<User, DomainError> {
+        let email = claims.email.clone().ok_or_else(|| {
+            DomainError::Validation("The identity provider did not share an email address".into())
+        })?;
+        if !claims.email_verified {
+            return Err(DomainError::Validation(
+                "The identity provider has not verified this email address".into(),
+            ));
+        }
+        let email = Email::new(email)?;
+
+        let user = match self.user_repository.find_by_email(&email).await? {
+            Some(user) if user.email_verified => user,
+            Some(_) => {
+                return Err(DomainError::AlreadyExists(
+                    "An account with this email already exists; log in with its password \
+                     and verify the email address first"
+                        .into(),
+                ));
+            }
+            None => {
+                let user = User::new_passwordless(email, true);
+                self.user_repository.save(user.clone()).await?;
+                user
+            }
+        };
+
+        self.identity_repository
+            .save(UserIdentity::new(
+                user.id,
+                provider.to_string(),
+                claims.subject,
+                claims.email,
+            ))
+            .await?;
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockMfa, MockOpaqueTokenGenerator, MockTokenIssuer, MockUserRepository,
+    };
+    use domain::users::PasswordHash;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    #[derive(Clone, Default)]
+    struct MockIdentityRepository {
+        identities: Arc<Mutex<Vec<UserIdentity>>>,
+    }
+
+    #[async_trait]
+    impl UserIdentityRepository for MockIdentityRepository {
+        async fn save(&self, identity: UserIdentity) -> Result<(), DomainError> {
+            self.identities.lock().unwrap().push(identity);
+            Ok(())
+        }
+
+        async fn find_by_subject(
+            &self,
+            provider: &str,
+            subject: &str,
+        ) -> Result<Option<UserIdentity>, DomainError> {
+            Ok(self
+                .identities
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|i| i.provider == provider && i.subject == subject)
+                .cloned())
+        }
+
+        async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<UserIdentity>, DomainError> {
+            Ok(self
+                .identities
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|i| i.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockAuthorizationRepository {
+        authorizations: Arc<Mutex<Vec<OidcAuthorization>>>,
+    }
+
+    #[async_trait]
+    impl OidcAuthorizationRepository for MockAuthorizationRepository {
+        async fn save(&self, authorization: OidcAuthorization) -> Result<(), DomainError> {
+            self.authorizations.lock().unwrap().push(authorization);
+            Ok(())
+        }
+
+        async fn take(&self, state_hash: &str) -> Result<Option<OidcAuthorization>, DomainError> {
+            let mut authorizations = self.authorizations.lock().unwrap();
+            let index = authorizations
+                .iter()
+                .position(|a| a.state_hash == state_hash);
+            Ok(index.map(|index| authorizations.remove(index)))
+        }
+
+        async fn purge_expired(&self) -> Result<u64, DomainError> {
+            Ok(0)
+        }
+    }
+
+    // Hands back whatever claims the test put in, with the nonce of the last authorization
+    #[derive(Clone)]
+    struct MockOidcProvider {
+        claims: OidcClaims,
+        last_nonce: Arc<Mutex<Option<String>>>,
+    }
+
+    impl MockOidcProvider {
+        fn new(subject: &str, email: &str, email_verified: bool) -> Self {
+            Self {
+                claims: OidcClaims {
+                    subject: subject.to_string(),
+                    email: Some(email.to_string()),
+                    email_verified,
+                    nonce: None,
+                },
+                last_nonce: Arc::new(Mutex::new(None)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OidcProvider for MockOidcProvider {
+        fn authorization_url(
+            &self,
+            provider: &str,
+            state: &str,
+            nonce: &str,
+            _code_verifier: &str,
+        ) -> Result<String, DomainError> {
+            if provider != "mock" {
+                return Err(DomainError::NotFound("Unknown identity provider".into()));
+            }
+            *self.last_nonce.lock().unwrap() = Some(nonce.to_string());
+            Ok(format!("https://idp.example.com/authorize?state={}", state))
+        }
+
+        async fn exchange_code(
+            &self,
+            _provider: &str,
+            code: &str,
+            _code_verifier: &str,
+        ) -> Result<OidcClaims, DomainError> {
+            if code != "good-code" {
+                return Err(DomainError::Unauthorized(
+                    "Authorization code was rejected".into(),
+                ));
+            }
+            Ok(OidcClaims {
+                nonce: self.last_nonce.lock().unwrap().clone(),
+                ..self.claims.clone()
+            })
+        }
+    }
+
+    struct Fixture {
+        users: MockUserRepository,
+        identities: MockIdentityRepository,
+        authorizations: MockAuthorizationRepository,
+        provider: MockOidcProvider,
+        opaque: MockOpaqueTokenGenerator,
+    }
+
+    impl Fixture {
+        fn new(provider: MockOidcProvider) -> Self {
+            Self {
+                users: MockUserRepository::default(),
+                identities: MockIdentityRepository::default(),
+                authorizations: MockAuthorizationRepository::default(),
+                provider,
+                opaque: MockOpaqueTokenGenerator,
+            }
+        }
+
+        async fn start(&self) -> OidcAuthorizationResponse {
+            StartOidcLoginUseCase::new(
+                self.authorizations.clone(),
+                self.provider.clone(),
+                self.opaque,
+                Duration::minutes(10),
+            )
+            .execute("mock")
+            .await
+            .unwrap()
+        }
+
+        async fn finish(&self, code: &str, state: &str) -> Result<LoginOutcome, DomainError> {
+            OidcLoginUseCase::new(
+                self.users.clone(),
+                self.identities.clone(),
+                self.authorizations.clone(),
+                self.provider.clone(),
+                self.opaque,
+                MockTokenIssuer::default(),
+                MockMfa::default(),
+            )
+            .execute(
+                "mock",
+                OidcCallbackRequest {
+                    code: code.to_string(),
+                    state: state.to_string(),
+                },
+                &ClientInfo::default(),
+            )
+            .await
+        }
+    }
+
+    fn authenticated_user_id(outcome: LoginOutcome) -> String {
+        match outcome {
+            LoginOutcome::Authenticated(response) => response.user_id,
+            LoginOutcome::MfaRequired(_) => panic!("expected tokens"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_stores_hashed_state() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "oidc@example.com", true));
+
+        let response = fixture.start().await;
+
+        assert!(response.authorization_url.contains(&response.state));
+        let stored = fixture.authorizations.authorizations.lock().unwrap()[0].clone();
+        assert_eq!(stored.state_hash, format!("hash_{}", response.state));
+        assert_eq!(stored.provider, "mock");
+        assert_ne!(stored.nonce, response.state);
+    }
+
+    #[tokio::test]
+    async fn test_first_sign_in_creates_passwordless_user() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "oidc@example.com", true));
+
+        let state = fixture.start().await.state;
+        let user_id = authenticated_user_id(fixture.finish("good-code", &state).await.unwrap());
+
+        let users = fixture.users.users.lock().unwrap().clone();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].id.to_string(), user_id);
+        assert!(users[0].password_hash.is_none());
+        assert!(users[0].email_verified);
+        assert_eq!(
+            fixture.identities.identities.lock().unwrap()[0].subject,
+            "sub-1"
+        );
+
+        // Signing in again finds the same account through the linked identity
+        let state = fixture.start().await.state;
+        let again = authenticated_user_id(fixture.finish("good-code", &state).await.unwrap());
+        assert_eq!(again, user_id);
+        assert_eq!(fixture.users.users.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_state_is_single_use() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "oidc@example.com", true));
+
+        let state = fixture.start().await.state;
+        assert!(fixture.finish("good-code", &state).await.is_ok());
+
+        let replay = fixture.finish("good-code", &state).await;
+        assert!(matches!(replay, Err(DomainError::Unauthorized(_))));
+        let unknown = fixture.finish("good-code", "made-up-state").await;
+        assert!(matches!(unknown, Err(DomainError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_nonce_must_match() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "oidc@example.com", true));
+
+        let first = fixture.start().await.state;
+        // The provider now answers with the nonce of the second authorization
+        fixture.start().await;
+
+        let result = fixture.finish("good-code", &first).await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(msg)) if msg.contains("nonce")));
+        assert!(fixture.users.users.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_links_to_existing_verified_account_only() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "taken@example.com", true));
+        let email = Email::new("taken@example.com").unwrap();
+        let mut existing = User::new(email, PasswordHash::new("hashed".into()));
+        fixture.users.save(existing.clone()).await.unwrap();
+
+        let state = fixture.start().await.state;
+        let result = fixture.finish("good-code", &state).await;
+        assert!(matches!(result, Err(DomainError::AlreadyExists(_))));
+        assert!(fixture.identities.identities.lock().unwrap().is_empty());
+
+        existing.email_verified = true;
+        *fixture.users.users.lock().unwrap() = vec![existing.clone()];
+        let state = fixture.start().await.state;
+        let user_id = authenticated_user_id(fixture.finish("good-code", &state).await.unwrap());
+        assert_eq!(user_id, existing.id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_unverified_provider_email_is_refused() {
+        let fixture = Fixture::new(MockOidcProvider::new("sub-1", "oidc@example.com", false));
+
+        let state = fixture.start().await.state;
+        let result = fixture.finish("good-code", &state).await;
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(fixture.users.users.lock().unwrap().is_empty());
+    }
+}
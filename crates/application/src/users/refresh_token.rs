@@ -0,0 +1,168 @@
+// crates/application/src/users/refresh_token.rs
+
+use domain::common::error::DomainError;
+use domain::users::UserRepository;
+
+use super::dtos::{RefreshTokenRequest, RefreshTokenResponse};
+use super::token_issuer::AuthTokenIssuer;
+
+pub struct RefreshTokenUseCase<R, I>
+where
+    R: UserRepository,
+    I: AuthTokenIssuer,
+{
+    user_repository: R,
+    token_issuer: I,
+}
+
+impl<R, I> RefreshTokenUseCase<R, I>
+where
+    R: UserRepository,
+    I: AuthTokenIssuer,
+{
+    pub fn new(user_repository: R, token_issuer: I) -> Self {
+        Self {
+            user_repository,
+            token_issuer,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: RefreshTokenRequest,
+    ) -> Result<RefreshTokenResponse, DomainError> {
+        // Consume the presented refresh token (detects reuse)
+        let redeemed = self.token_issuer.redeem(&request.refresh_token).await?;
+
+        // Load the owner to stamp current claims into the new access token
+        let user = self
+            .user_repository
+            .find_by_id(redeemed.user_id)
+            .await?
+            .ok_or_else(|| DomainError::Unauthorized("Invalid refresh token".into()))?;
+
+        // Rotate: issue a new pair within the same family, i.e. the same session
+        let tokens = self.token_issuer.rotate(&user, redeemed.family_id).await?;
+
+        Ok(RefreshTokenResponse {
+            user_id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            role: user.role.as_str().to_string(),
+            email_verified: user.email_verified,
+            token: tokens.access_token,
+            token_expires_at: tokens.access_token_expires_at.to_rfc3339(),
+            refresh_token: tokens.refresh_token,
+            refresh_token_expires_at: tokens.refresh_token_expires_at.to_rfc3339(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockOpaqueTokenGenerator, MockRefreshTokenRepository, MockSessionRepository,
+        MockTokenGenerator, MockUserRepository,
+    };
+    use crate::users::{AuthTokens, ClientInfo, TokenIssuer};
+    use chrono::Duration;
+    use domain::users::{Email, PasswordHash, Role, User};
+
+    type Issuer = TokenIssuer<
+        MockTokenGenerator,
+        MockRefreshTokenRepository,
+        MockSessionRepository,
+        MockOpaqueTokenGenerator,
+    >;
+
+    fn issuer() -> Issuer {
+        TokenIssuer::new(
+            MockTokenGenerator,
+            MockRefreshTokenRepository::default(),
+            MockSessionRepository::default(),
+            MockOpaqueTokenGenerator,
+            Duration::days(30),
+        )
+    }
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        )
+    }
+
+    fn request(tokens: &AuthTokens) -> RefreshTokenRequest {
+        RefreshTokenRequest {
+            refresh_token: tokens.refresh_token.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_tokens() {
+        let user = user();
+        let users = MockUserRepository::with_user(user.clone());
+        let issuer = issuer();
+        let use_case = RefreshTokenUseCase::new(users.clone(), issuer.clone());
+        let first = issuer.issue(&user, &ClientInfo::default()).await.unwrap();
+
+        // The new access token carries the user's current claims
+        users.users.lock().unwrap()[0].role = Role::Moderator;
+        let response = use_case.execute(request(&first)).await.unwrap();
+
+        assert_eq!(response.user_id, user.id.to_string());
+        assert_eq!(response.role, "moderator");
+        assert_ne!(response.refresh_token, first.refresh_token);
+        let second = RefreshTokenRequest {
+            refresh_token: response.refresh_token,
+        };
+        assert!(use_case.execute(second).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_reuse_revokes_the_chain() {
+        let user = user();
+        let issuer = issuer();
+        let use_case =
+            RefreshTokenUseCase::new(MockUserRepository::with_user(user.clone()), issuer.clone());
+        let first = issuer.issue(&user, &ClientInfo::default()).await.unwrap();
+        let rotated = use_case.execute(request(&first)).await.unwrap();
+
+        let replayed = use_case.execute(request(&first)).await;
+        assert!(matches!(
+            replayed.unwrap_err(),
+            DomainError::Unauthorized(_)
+        ));
+
+        // The replay gives the chain away, so the rotated token dies with it
+        let second = RefreshTokenRequest {
+            refresh_token: rotated.refresh_token,
+        };
+        let result = use_case.execute(second).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_unknown_token() {
+        let use_case = RefreshTokenUseCase::new(MockUserRepository::default(), issuer());
+
+        let result = use_case
+            .execute(RefreshTokenRequest {
+                refresh_token: "does-not-exist".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_for_deleted_user() {
+        let issuer = issuer();
+        let use_case = RefreshTokenUseCase::new(MockUserRepository::default(), issuer.clone());
+        let tokens = issuer.issue(&user(), &ClientInfo::default()).await.unwrap();
+
+        let result = use_case.execute(request(&tokens)).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+}
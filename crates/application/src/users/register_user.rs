@@ -4,51 +4,54 @@ use domain::common::error::DomainError;
 use domain::users::{Email, PasswordHasher, PlainPassword, User, UserRepository};
 
 use super::dtos::{RegisterUserRequest, RegisterUserResponse};
+use super::email_verification::EmailVerificationIssuer;
+use super::token_issuer::{AuthTokenIssuer, ClientInfo};
 
-pub struct RegisterUserUseCase<R, H, J>
+pub struct RegisterUserUseCase<R, H, I, V>
 where
     R: UserRepository,
     H: PasswordHasher,
-    J: TokenGenerator,
+    I: AuthTokenIssuer,
+    V: EmailVerificationIssuer,
 {
     user_repository: R,
     password_hasher: H,
-    token_generator: J,
+    token_issuer: I,
+    email_verification: V,
 }
 
-/// Trait for generating authentication tokens
-pub trait TokenGenerator: Send + Sync {
-    fn generate(&self, user_id: uuid::Uuid, email: &str) -> Result<String, DomainError>;
-}
-
-impl<R, H, J> RegisterUserUseCase<R, H, J>
+impl<R, H, I, V> RegisterUserUseCase<R, H, I, V>
 where
     R: UserRepository,
     H: PasswordHasher,
-    J: TokenGenerator,
+    I: AuthTokenIssuer,
+    V: EmailVerificationIssuer,
 {
-    pub fn new(user_repository: R, password_hasher: H, token_generator: J) -> Self {
+    pub fn new(
+        user_repository: R,
+        password_hasher: H,
+        token_issuer: I,
+        email_verification: V,
+    ) -> Self {
         Self {
             user_repository,
             password_hasher,
-            token_generator,
+            token_issuer,
+            email_verification,
         }
     }
 
+    /// Create an account and sign it in on `client`
     pub async fn execute(
         &self,
         request: RegisterUserRequest,
+        client: &ClientInfo,
     ) -> Result<RegisterUserResponse, DomainError> {
         // Validate email format
         let email = Email::new(request.email)?;
 
         // Check if user already exists
-        if self
-            .user_repository
-            .find_by_email(&email)
-            .await?
-            .is_some()
-        {
+        if self.user_repository.find_by_email(&email).await?.is_some() {
             return Err(DomainError::AlreadyExists(
                 "User with this email already exists".into(),
             ));
@@ -64,13 +67,24 @@ where
         // Save user
         self.user_repository.save(user.clone()).await?;
 
-        // Generate token
-        let token = self.token_generator.generate(user.id, user.email.as_str())?;
+        // Mail a verification link. The account already exists at this point, so a
+        // delivery failure is not fatal: the user can ask for a resend.
+        if let Err(e) = self.email_verification.send(&user).await {
+            tracing::warn!(error = %e, user_id = %user.id, "Failed to send verification email after registration");
+        }
+
+        // Issue access and refresh tokens
+        let tokens = self.token_issuer.issue(&user, client).await?;
 
         Ok(RegisterUserResponse {
             user_id: user.id.to_string(),
             email: user.email.as_str().to_string(),
-            token,
+            role: user.role.as_str().to_string(),
+            email_verified: user.email_verified,
+            token: tokens.access_token,
+            token_expires_at: tokens.access_token_expires_at.to_rfc3339(),
+            refresh_token: tokens.refresh_token,
+            refresh_token_expires_at: tokens.refresh_token_expires_at.to_rfc3339(),
         })
     }
 }
@@ -78,68 +92,17 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
-    use std::sync::{Arc, Mutex};
-
-    struct MockUserRepository {
-        users: Arc<Mutex<Vec<User>>>,
-    }
-
-    impl MockUserRepository {
-        fn new() -> Self {
-            Self {
-                users: Arc::new(Mutex::new(Vec::new())),
-            }
-        }
-    }
-
-    #[async_trait]
-    impl UserRepository for MockUserRepository {
-        async fn save(&self, user: User) -> Result<(), DomainError> {
-            self.users.lock().unwrap().push(user);
-            Ok(())
-        }
-
-        async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError> {
-            Ok(self
-                .users
-                .lock()
-                .unwrap()
-                .iter()
-                .find(|u| u.email == *email)
-                .cloned())
-        }
-    }
-
-    struct MockPasswordHasher;
-
-    #[async_trait]
-    impl PasswordHasher for MockPasswordHasher {
-        async fn hash(
-            &self,
-            password: PlainPassword,
-        ) -> Result<domain::users::PasswordHash, DomainError> {
-            Ok(domain::users::PasswordHash::new(format!(
-                "hashed_{}",
-                password.as_str()
-            )))
-        }
-    }
-
-    struct MockTokenGenerator;
-
-    impl TokenGenerator for MockTokenGenerator {
-        fn generate(&self, user_id: uuid::Uuid, _email: &str) -> Result<String, DomainError> {
-            Ok(format!("token_{}", user_id))
-        }
-    }
+    use crate::test_support::{
+        MockEmailVerification, MockPasswordHasher, MockTokenIssuer, MockUserRepository,
+    };
 
     #[tokio::test]
     async fn test_register_user_success() {
         let use_case = RegisterUserUseCase::new(
-            MockUserRepository::new(),
+            MockUserRepository::default(),
             MockPasswordHasher,
-            MockTokenGenerator,
+            MockTokenIssuer::default(),
+            MockEmailVerification::default(),
         );
 
         let request = RegisterUserRequest {
@@ -147,18 +110,50 @@ mod tests {
             password: "password123".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
         assert_eq!(response.email, "test@example.com");
+        assert!(!response.email_verified);
         assert!(response.token.starts_with("token_"));
+        assert!(response.refresh_token.starts_with("refresh_"));
+    }
+
+    #[tokio::test]
+    async fn test_register_user_sends_verification_email() {
+        let email_verification = MockEmailVerification::default();
+        let use_case = RegisterUserUseCase::new(
+            MockUserRepository::default(),
+            MockPasswordHasher,
+            MockTokenIssuer::default(),
+            email_verification.clone(),
+        );
+
+        let request = RegisterUserRequest {
+            email: "test@example.com".to_string(),
+            password: "password123".to_string(),
+        };
+
+        let response = use_case
+            .execute(request, &ClientInfo::default())
+            .await
+            .unwrap();
+
+        let sent_to = email_verification.sent_to.lock().unwrap();
+        assert_eq!(sent_to.len(), 1);
+        assert_eq!(sent_to[0].id.to_string(), response.user_id);
     }
 
     #[tokio::test]
     async fn test_register_user_duplicate_email() {
-        let repo = MockUserRepository::new();
-        let use_case = RegisterUserUseCase::new(repo, MockPasswordHasher, MockTokenGenerator);
+        let repo = MockUserRepository::default();
+        let use_case = RegisterUserUseCase::new(
+            repo,
+            MockPasswordHasher,
+            MockTokenIssuer::default(),
+            MockEmailVerification::default(),
+        );
 
         let request = RegisterUserRequest {
             email: "test@example.com".to_string(),
@@ -166,10 +161,13 @@ mod tests {
         };
 
         // First registration should succeed
-        use_case.execute(request.clone()).await.unwrap();
+        use_case
+            .execute(request.clone(), &ClientInfo::default())
+            .await
+            .unwrap();
 
         // Second registration should fail
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), DomainError::AlreadyExists(_)));
     }
@@ -177,9 +175,10 @@ mod tests {
     #[tokio::test]
     async fn test_register_user_invalid_email() {
         let use_case = RegisterUserUseCase::new(
-            MockUserRepository::new(),
+            MockUserRepository::default(),
             MockPasswordHasher,
-            MockTokenGenerator,
+            MockTokenIssuer::default(),
+            MockEmailVerification::default(),
         );
 
         let request = RegisterUserRequest {
@@ -187,7 +186,7 @@ mod tests {
             password: "password123".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
     }
@@ -195,9 +194,10 @@ mod tests {
     #[tokio::test]
     async fn test_register_user_short_password() {
         let use_case = RegisterUserUseCase::new(
-            MockUserRepository::new(),
+            MockUserRepository::default(),
             MockPasswordHasher,
-            MockTokenGenerator,
+            MockTokenIssuer::default(),
+            MockEmailVerification::default(),
         );
 
         let request = RegisterUserRequest {
@@ -205,7 +205,7 @@ mod tests {
             password: "short".to_string(),
         };
 
-        let result = use_case.execute(request).await;
+        let result = use_case.execute(request, &ClientInfo::default()).await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
     }
@@ -0,0 +1,95 @@
+// crates/application/src/users/request_password_reset.rs
+
+use chrono::{Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{Email, PasswordResetToken, PasswordResetTokenRepository, UserRepository};
+
+use super::dtos::RequestPasswordResetRequest;
+use super::mailer::{EmailMessage, Mailer};
+use super::token_issuer::OpaqueTokenGenerator;
+
+pub struct RequestPasswordResetUseCase<R, T, O, M>
+where
+    R: UserRepository,
+    T: PasswordResetTokenRepository,
+    O: OpaqueTokenGenerator,
+    M: Mailer,
+{
+    user_repository: R,
+    token_repository: T,
+    opaque_token_generator: O,
+    mailer: M,
+    reset_url: String,
+    token_ttl: Duration,
+}
+
+impl<R, T, O, M> RequestPasswordResetUseCase<R, T, O, M>
+where
+    R: UserRepository,
+    T: PasswordResetTokenRepository,
+    O: OpaqueTokenGenerator,
+    M: Mailer,
+{
+    /// `reset_url` is the page the mailed link points to; the token is appended as
+    /// the `token` query parameter.
+    pub fn new(
+        user_repository: R,
+        token_repository: T,
+        opaque_token_generator: O,
+        mailer: M,
+        reset_url: String,
+        token_ttl: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            token_repository,
+            opaque_token_generator,
+            mailer,
+            reset_url,
+            token_ttl,
+        }
+    }
+
+    /// Mail a reset link if the email belongs to an account. Succeeds either way, so
+    /// callers cannot tell whether the email is registered.
+    pub async fn execute(&self, request: RequestPasswordResetRequest) -> Result<(), DomainError> {
+        let email = Email::new(request.email)?;
+
+        let Some(user) = self.user_repository.find_by_email(&email).await? else {
+            return Ok(());
+        };
+
+        // Only the most recently sent link works
+        self.token_repository.delete_for_user(user.id).await?;
+
+        let token = self.opaque_token_generator.generate();
+        let expires_at = Utc::now() + self.token_ttl;
+        let record = PasswordResetToken::new(
+            user.id,
+            self.opaque_token_generator.hash(&token),
+            expires_at,
+        );
+        self.token_repository.save(record).await?;
+
+        let separator = if self.reset_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        self.mailer
+            .send(EmailMessage {
+                to: user.email.as_str().to_string(),
+                subject: "Reset your password".to_string(),
+                body: format!(
+                    "Choose a new password by opening the link below:\n\n{}{}token={}\n\n\
+                     The link expires at {}. If you did not ask to reset your password, \
+                     ignore this email.\n",
+                    self.reset_url,
+                    separator,
+                    token,
+                    expires_at.to_rfc3339(),
+                ),
+            })
+            .await
+    }
+}
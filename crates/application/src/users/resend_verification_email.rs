@@ -0,0 +1,43 @@
+// crates/application/src/users/resend_verification_email.rs
+
+use domain::common::error::DomainError;
+use domain::users::UserRepository;
+use uuid::Uuid;
+
+use super::email_verification::EmailVerificationIssuer;
+
+pub struct ResendVerificationEmailUseCase<R, V>
+where
+    R: UserRepository,
+    V: EmailVerificationIssuer,
+{
+    user_repository: R,
+    email_verification: V,
+}
+
+impl<R, V> ResendVerificationEmailUseCase<R, V>
+where
+    R: UserRepository,
+    V: EmailVerificationIssuer,
+{
+    pub fn new(user_repository: R, email_verification: V) -> Self {
+        Self {
+            user_repository,
+            email_verification,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<(), DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        if user.email_verified {
+            return Err(DomainError::Validation("Email is already verified".into()));
+        }
+
+        self.email_verification.send(&user).await
+    }
+}
@@ -0,0 +1,239 @@
+// crates/application/src/users/reset_password.rs
+
+use chrono::{SubsecRound, Utc};
+use domain::common::error::DomainError;
+use domain::users::{
+    PasswordHasher, PasswordResetTokenRepository, PlainPassword, RevokedTokenRepository,
+    UserRepository,
+};
+
+use super::dtos::ResetPasswordRequest;
+use super::token_issuer::{AuthTokenIssuer, OpaqueTokenGenerator};
+
+pub struct ResetPasswordUseCase<R, H, T, O, V, I>
+where
+    R: UserRepository,
+    H: PasswordHasher,
+    T: PasswordResetTokenRepository,
+    O: OpaqueTokenGenerator,
+    V: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    user_repository: R,
+    password_hasher: H,
+    token_repository: T,
+    opaque_token_generator: O,
+    revoked_token_repository: V,
+    token_issuer: I,
+}
+
+impl<R, H, T, O, V, I> ResetPasswordUseCase<R, H, T, O, V, I>
+where
+    R: UserRepository,
+    H: PasswordHasher,
+    T: PasswordResetTokenRepository,
+    O: OpaqueTokenGenerator,
+    V: RevokedTokenRepository,
+    I: AuthTokenIssuer,
+{
+    pub fn new(
+        user_repository: R,
+        password_hasher: H,
+        token_repository: T,
+        opaque_token_generator: O,
+        revoked_token_repository: V,
+        token_issuer: I,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_hasher,
+            token_repository,
+            opaque_token_generator,
+            revoked_token_repository,
+            token_issuer,
+        }
+    }
+
+    /// Set a new password with a mailed reset token, then sign the user out everywhere
+    pub async fn execute(&self, request: ResetPasswordRequest) -> Result<(), DomainError> {
+        let invalid = || DomainError::Validation("Invalid or expired password reset token".into());
+
+        // Validate the new password first so a weak one does not burn the token
+        let password = PlainPassword::new(request.new_password)?;
+
+        let token_hash = self.opaque_token_generator.hash(&request.token);
+        let record = self
+            .token_repository
+            .find_by_hash(&token_hash)
+            .await?
+            .ok_or_else(invalid)?;
+
+        if record.is_expired() || record.is_used() {
+            return Err(invalid());
+        }
+
+        // Conditional consume so two concurrent resets cannot both succeed
+        if !self.token_repository.consume(record.id).await? {
+            return Err(invalid());
+        }
+
+        let password_hash = self.password_hasher.hash(password).await?;
+        self.user_repository
+            .update_password(record.user_id, password_hash)
+            .await?;
+        self.token_repository
+            .delete_for_user(record.user_id)
+            .await?;
+
+        // Access tokens carry `iat` in whole seconds; truncating the cutoff keeps tokens
+        // issued later in the same second (e.g. an immediate login) valid.
+        let cutoff = Utc::now().trunc_subsecs(0);
+        self.revoked_token_repository
+            .revoke_all_for_user(record.user_id, cutoff)
+            .await?;
+        self.token_issuer.revoke_all(record.user_id).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockMailer, MockOpaqueTokenGenerator, MockPasswordHasher, MockPasswordResetTokenRepository,
+        MockRevokedTokenRepository, MockTokenIssuer, MockUserRepository,
+    };
+    use crate::users::{RequestPasswordResetRequest, RequestPasswordResetUseCase};
+    use chrono::Duration;
+    use domain::users::{Email, PasswordHash, User};
+    use uuid::Uuid;
+
+    struct Fixture {
+        users: MockUserRepository,
+        tokens: MockPasswordResetTokenRepository,
+        mailer: MockMailer,
+        revoked_tokens: MockRevokedTokenRepository,
+        token_issuer: MockTokenIssuer,
+        user: User,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let user = User::new(
+                Email::new("test@example.com").unwrap(),
+                PasswordHash::new("hashed_oldpassword".to_string()),
+            );
+            let users = MockUserRepository::default();
+            users.users.lock().unwrap().push(user.clone());
+
+            Self {
+                users,
+                tokens: MockPasswordResetTokenRepository::default(),
+                mailer: MockMailer::default(),
+                revoked_tokens: MockRevokedTokenRepository::default(),
+                token_issuer: MockTokenIssuer::default(),
+                user,
+            }
+        }
+
+        async fn request(&self, email: &str) -> Result<(), DomainError> {
+            RequestPasswordResetUseCase::new(
+                self.users.clone(),
+                self.tokens.clone(),
+                MockOpaqueTokenGenerator,
+                self.mailer.clone(),
+                "http://localhost:3000/reset-password".to_string(),
+                Duration::hours(1),
+            )
+            .execute(RequestPasswordResetRequest {
+                email: email.to_string(),
+            })
+            .await
+        }
+
+        async fn reset(&self, token: &str, new_password: &str) -> Result<(), DomainError> {
+            ResetPasswordUseCase::new(
+                self.users.clone(),
+                MockPasswordHasher,
+                self.tokens.clone(),
+                MockOpaqueTokenGenerator,
+                self.revoked_tokens.clone(),
+                self.token_issuer.clone(),
+            )
+            .execute(ResetPasswordRequest {
+                token: token.to_string(),
+                new_password: new_password.to_string(),
+            })
+            .await
+        }
+
+        fn password_hash(&self) -> String {
+            self.users.users.lock().unwrap()[0]
+                .password_hash
+                .as_ref()
+                .unwrap()
+                .as_str()
+                .to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_success() {
+        let fixture = Fixture::new();
+
+        fixture.request("test@example.com").await.unwrap();
+        let token = fixture.mailer.last_token();
+        fixture.reset(&token, "newpassword123").await.unwrap();
+
+        assert_eq!(fixture.password_hash(), "hashed_newpassword123");
+
+        // Every session is invalidated
+        assert_eq!(
+            *fixture.token_issuer.revoked_users.lock().unwrap(),
+            vec![fixture.user.id]
+        );
+        let issued_earlier = Utc::now() - Duration::minutes(1);
+        assert!(
+            fixture
+                .revoked_tokens
+                .is_revoked(Uuid::new_v4(), fixture.user.id, None, issued_earlier)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_password_reset_unknown_email() {
+        let fixture = Fixture::new();
+
+        // Same result as for a registered email, but nothing is sent
+        fixture.request("nobody@example.com").await.unwrap();
+        assert!(fixture.mailer.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_token_is_single_use() {
+        let fixture = Fixture::new();
+
+        fixture.request("test@example.com").await.unwrap();
+        let token = fixture.mailer.last_token();
+        fixture.reset(&token, "newpassword123").await.unwrap();
+
+        let result = fixture.reset(&token, "anotherpassword").await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert_eq!(fixture.password_hash(), "hashed_newpassword123");
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_weak_password_keeps_token() {
+        let fixture = Fixture::new();
+
+        fixture.request("test@example.com").await.unwrap();
+        let token = fixture.mailer.last_token();
+
+        let result = fixture.reset(&token, "short").await;
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert!(fixture.reset(&token, "newpassword123").await.is_ok());
+    }
+}
@@ -0,0 +1,297 @@
+// crates/application/src/users/sessions.rs
+
+use chrono::{Duration, SubsecRound, Utc};
+use domain::common::error::DomainError;
+use domain::users::{RefreshTokenRepository, RevokedTokenRepository, Session, SessionRepository};
+use uuid::Uuid;
+
+use super::dtos::SessionResponse;
+
+pub struct ListSessionsUseCase<S>
+where
+    S: SessionRepository,
+{
+    session_repository: S,
+}
+
+impl<S> ListSessionsUseCase<S>
+where
+    S: SessionRepository,
+{
+    pub fn new(session_repository: S) -> Self {
+        Self { session_repository }
+    }
+
+    /// The user's active sessions, most recently seen first. `current_session` is the
+    /// session of the token the request was made with, if it carries one.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        current_session: Option<Uuid>,
+    ) -> Result<Vec<SessionResponse>, DomainError> {
+        let sessions = self.session_repository.find_active_by_user(user_id).await?;
+        Ok(sessions
+            .iter()
+            .map(|session| session_response(session, current_session))
+            .collect())
+    }
+}
+
+pub struct RevokeSessionUseCase<S, T, V>
+where
+    S: SessionRepository,
+    T: RefreshTokenRepository,
+    V: RevokedTokenRepository,
+{
+    session_repository: S,
+    refresh_token_repository: T,
+    revoked_token_repository: V,
+    access_token_ttl: Duration,
+}
+
+impl<S, T, V> RevokeSessionUseCase<S, T, V>
+where
+    S: SessionRepository,
+    T: RefreshTokenRepository,
+    V: RevokedTokenRepository,
+{
+    pub fn new(
+        session_repository: S,
+        refresh_token_repository: T,
+        revoked_token_repository: V,
+        access_token_ttl: Duration,
+    ) -> Self {
+        Self {
+            session_repository,
+            refresh_token_repository,
+            revoked_token_repository,
+            access_token_ttl,
+        }
+    }
+
+    /// Sign one of the user's devices out: its refresh tokens stop working right away
+    /// and so do the access tokens already handed out to it. Other users' sessions are
+    /// reported as not found.
+    pub async fn execute(&self, session_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        let owned = self
+            .session_repository
+            .find_active_by_user(user_id)
+            .await?
+            .iter()
+            .any(|session| session.id == session_id);
+        if !owned {
+            return Err(DomainError::NotFound("Session not found".into()));
+        }
+
+        self.refresh_token_repository
+            .revoke_family(session_id)
+            .await?;
+        // Access tokens of the session cannot outlive this, so neither does the record
+        self.revoked_token_repository
+            .revoke_session(session_id, Utc::now() + self.access_token_ttl)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct LogoutEverywhereUseCase<T, V>
+where
+    T: RefreshTokenRepository,
+    V: RevokedTokenRepository,
+{
+    refresh_token_repository: T,
+    revoked_token_repository: V,
+}
+
+impl<T, V> LogoutEverywhereUseCase<T, V>
+where
+    T: RefreshTokenRepository,
+    V: RevokedTokenRepository,
+{
+    pub fn new(refresh_token_repository: T, revoked_token_repository: V) -> Self {
+        Self {
+            refresh_token_repository,
+            revoked_token_repository,
+        }
+    }
+
+    /// Sign the user out of every session, including the one making the request
+    pub async fn execute(&self, user_id: Uuid) -> Result<(), DomainError> {
+        // Access tokens carry `iat` in whole seconds; truncating the cutoff keeps tokens
+        // from a login later in the same second valid.
+        self.revoked_token_repository
+            .revoke_all_for_user(user_id, Utc::now().trunc_subsecs(0))
+            .await?;
+        self.refresh_token_repository
+            .revoke_all_for_user(user_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn session_response(session: &Session, current_session: Option<Uuid>) -> SessionResponse {
+    SessionResponse {
+        id: session.id.to_string(),
+        user_agent: session.user_agent.clone(),
+        ip_address: session.ip_address.clone(),
+        created_at: session.created_at.to_rfc3339(),
+        last_seen_at: session.last_seen_at.to_rfc3339(),
+        current: current_session == Some(session.id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockRefreshTokenRepository, MockRevokedTokenRepository, MockSessionRepository,
+    };
+    use domain::users::RefreshToken;
+
+    fn session_for(user_id: Uuid, user_agent: &str) -> Session {
+        Session::new(
+            Uuid::new_v4(),
+            user_id,
+            Some(user_agent.to_string()),
+            Some("203.0.113.7".to_string()),
+        )
+    }
+
+    fn refresh_token(user_id: Uuid, family_id: Uuid) -> RefreshToken {
+        RefreshToken::new(
+            user_id,
+            family_id,
+            Uuid::new_v4().to_string(),
+            Utc::now() + Duration::days(30),
+        )
+    }
+
+    // Whether each of the repository's refresh tokens is revoked, in the order saved
+    fn revoked(refresh_tokens: &MockRefreshTokenRepository) -> Vec<bool> {
+        refresh_tokens
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|token| token.revoked_at.is_some())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_sessions_marks_current() {
+        let user_id = Uuid::new_v4();
+        let repository = MockSessionRepository::default();
+        let laptop = session_for(user_id, "Firefox");
+        let phone = session_for(user_id, "Safari");
+        repository.save(laptop.clone()).await.unwrap();
+        repository.save(phone.clone()).await.unwrap();
+        repository
+            .save(session_for(Uuid::new_v4(), "Chrome"))
+            .await
+            .unwrap();
+
+        let sessions = ListSessionsUseCase::new(repository)
+            .execute(user_id, Some(phone.id))
+            .await
+            .unwrap();
+
+        assert_eq!(sessions.len(), 2);
+        let current: Vec<_> = sessions.iter().filter(|s| s.current).collect();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].id, phone.id.to_string());
+        assert_eq!(current[0].user_agent.as_deref(), Some("Safari"));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session_revokes_refresh_and_access_tokens() {
+        let user_id = Uuid::new_v4();
+        let sessions = MockSessionRepository::default();
+        let session = session_for(user_id, "Firefox");
+        sessions.save(session.clone()).await.unwrap();
+        let refresh_tokens = MockRefreshTokenRepository::default();
+        for family_id in [session.id, Uuid::new_v4()] {
+            refresh_tokens
+                .save(refresh_token(user_id, family_id))
+                .await
+                .unwrap();
+        }
+        let revoked_tokens = MockRevokedTokenRepository::default();
+        let use_case = RevokeSessionUseCase::new(
+            sessions,
+            refresh_tokens.clone(),
+            revoked_tokens.clone(),
+            Duration::minutes(15),
+        );
+
+        use_case.execute(session.id, user_id).await.unwrap();
+
+        assert_eq!(revoked(&refresh_tokens), vec![true, false]);
+        assert!(
+            revoked_tokens
+                .is_revoked(Uuid::new_v4(), user_id, Some(session.id), Utc::now())
+                .await
+                .unwrap()
+        );
+        // Other sessions of the user keep working
+        assert!(
+            !revoked_tokens
+                .is_revoked(Uuid::new_v4(), user_id, Some(Uuid::new_v4()), Utc::now())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session_of_other_user_is_not_found() {
+        let owner = Uuid::new_v4();
+        let sessions = MockSessionRepository::default();
+        let session = session_for(owner, "Firefox");
+        sessions.save(session.clone()).await.unwrap();
+        let refresh_tokens = MockRefreshTokenRepository::default();
+        refresh_tokens
+            .save(refresh_token(owner, session.id))
+            .await
+            .unwrap();
+        let use_case = RevokeSessionUseCase::new(
+            sessions,
+            refresh_tokens.clone(),
+            MockRevokedTokenRepository::default(),
+            Duration::minutes(15),
+        );
+
+        let result = use_case.execute(session.id, Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+        assert_eq!(revoked(&refresh_tokens), vec![false]);
+    }
+
+    #[tokio::test]
+    async fn test_logout_everywhere_revokes_all_tokens() {
+        let user_id = Uuid::new_v4();
+        let refresh_tokens = MockRefreshTokenRepository::default();
+        for owner in [user_id, user_id, Uuid::new_v4()] {
+            refresh_tokens
+                .save(refresh_token(owner, Uuid::new_v4()))
+                .await
+                .unwrap();
+        }
+        let revoked_tokens = MockRevokedTokenRepository::default();
+        let issued_earlier = Utc::now() - Duration::minutes(1);
+
+        LogoutEverywhereUseCase::new(refresh_tokens.clone(), revoked_tokens.clone())
+            .execute(user_id)
+            .await
+            .unwrap();
+
+        // Another user's tokens are left alone
+        assert_eq!(revoked(&refresh_tokens), vec![true, true, false]);
+        assert!(
+            revoked_tokens
+                .is_revoked(Uuid::new_v4(), user_id, None, issued_earlier)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,323 @@
+// crates/application/src/users/token_issuer.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{RefreshToken, RefreshTokenRepository, Session, SessionRepository, User};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+/// A signed access token and its expiry
+#[derive(Debug, Clone)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Trait for generating authentication tokens for a session
+pub trait TokenGenerator: Send + Sync {
+    fn generate(&self, user: &User, session_id: Uuid) -> Result<AccessToken, DomainError>;
+}
+
+/// Trait for generating random opaque tokens and hashing them for storage
+pub trait OpaqueTokenGenerator: Send + Sync {
+    fn generate(&self) -> String;
+    fn hash(&self, token: &str) -> String;
+}
+
+/// Where a login comes from, recorded with the session it starts
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+/// Access/refresh token pair handed out to clients
+#[derive(Debug, Clone)]
+pub struct AuthTokens {
+    pub access_token: String,
+    pub access_token_expires_at: DateTime<Utc>,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: DateTime<Utc>,
+}
+
+/// Trait for issuing and redeeming token pairs
+#[async_trait]
+pub trait AuthTokenIssuer: Send + Sync {
+    /// Issue a new pair for a new session, started by a login from `client`
+    async fn issue(&self, user: &User, client: &ClientInfo) -> Result<AuthTokens, DomainError>;
+
+    /// Issue a new pair continuing an existing session's rotation chain
+    async fn rotate(&self, user: &User, session_id: Uuid) -> Result<AuthTokens, DomainError>;
+
+    /// Consume a refresh token so it cannot be used again. Presenting a token that
+    /// was already consumed revokes every token in its family.
+    async fn redeem(&self, refresh_token: &str) -> Result<RefreshToken, DomainError>;
+
+    /// Revoke every token in the refresh token's family. Tokens that are unknown or
+    /// belong to another user are ignored.
+    async fn revoke(&self, user_id: Uuid, refresh_token: &str) -> Result<(), DomainError>;
+
+    /// Revoke every refresh token the user holds, across all families
+    async fn revoke_all(&self, user_id: Uuid) -> Result<(), DomainError>;
+}
+
+/// Issues short-lived access tokens alongside rotating refresh tokens, and keeps
+/// track of the sessions they belong to
+#[derive(Clone)]
+pub struct TokenIssuer<J, T, S, O>
+where
+    J: TokenGenerator,
+    T: RefreshTokenRepository,
+    S: SessionRepository,
+    O: OpaqueTokenGenerator,
+{
+    token_generator: J,
+    refresh_token_repository: T,
+    session_repository: S,
+    opaque_token_generator: O,
+    refresh_token_ttl: Duration,
+}
+
+impl<J, T, S, O> TokenIssuer<J, T, S, O>
+where
+    J: TokenGenerator,
+    T: RefreshTokenRepository,
+    S: SessionRepository,
+    O: OpaqueTokenGenerator,
+{
+    pub fn new(
+        token_generator: J,
+        refresh_token_repository: T,
+        session_repository: S,
+        opaque_token_generator: O,
+        refresh_token_ttl: Duration,
+    ) -> Self {
+        Self {
+            token_generator,
+            refresh_token_repository,
+            session_repository,
+            opaque_token_generator,
+            refresh_token_ttl,
+        }
+    }
+
+    // The session id doubles as the refresh token family id
+    async fn issue_for_session(
+        &self,
+        user: &User,
+        session_id: Uuid,
+    ) -> Result<AuthTokens, DomainError> {
+        let access_token = self.token_generator.generate(user, session_id)?;
+
+        let refresh_token = self.opaque_token_generator.generate();
+        let refresh_token_expires_at = Utc::now() + self.refresh_token_ttl;
+        let record = RefreshToken::new(
+            user.id,
+            session_id,
+            self.opaque_token_generator.hash(&refresh_token),
+            refresh_token_expires_at,
+        );
+        self.refresh_token_repository.save(record).await?;
+
+        Ok(AuthTokens {
+            access_token: access_token.token,
+            access_token_expires_at: access_token.expires_at,
+            refresh_token,
+            refresh_token_expires_at,
+        })
+    }
+}
+
+#[async_trait]
+impl<J, T, S, O> AuthTokenIssuer for TokenIssuer<J, T, S, O>
+where
+    J: TokenGenerator,
+    T: RefreshTokenRepository,
+    S: SessionRepository,
+    O: OpaqueTokenGenerator,
+{
+    async fn issue(&self, user: &User, client: &ClientInfo) -> Result<AuthTokens, DomainError> {
+        let session_id = Uuid::new_v4();
+        // The refresh token goes first, so that purging sessions without one cannot
+        // catch the new session in between
+        let tokens = self.issue_for_session(user, session_id).await?;
+        self.session_repository
+            .save(Session::new(
+                session_id,
+                user.id,
+                client.user_agent.clone(),
+                client.ip_address.map(|ip| ip.to_string()),
+            ))
+            .await?;
+
+        Ok(tokens)
+    }
+
+    async fn rotate(&self, user: &User, session_id: Uuid) -> Result<AuthTokens, DomainError> {
+        let tokens = self.issue_for_session(user, session_id).await?;
+        self.session_repository
+            .touch(session_id, Utc::now())
+            .await?;
+
+        Ok(tokens)
+    }
+
+    async fn redeem(&self, refresh_token: &str) -> Result<RefreshToken, DomainError> {
+        let token_hash = self.opaque_token_generator.hash(refresh_token);
+        let token = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await?
+            .ok_or_else(|| DomainError::Unauthorized("Invalid refresh token".into()))?;
+
+        // A consumed token coming back means it leaked; kill the whole chain
+        if token.is_revoked() || !self.refresh_token_repository.revoke(token.id).await? {
+            self.refresh_token_repository
+                .revoke_family(token.family_id)
+                .await?;
+            return Err(DomainError::Unauthorized(
+                "Refresh token has already been used".into(),
+            ));
+        }
+
+        if token.is_expired() {
+            return Err(DomainError::Unauthorized(
+                "Refresh token has expired".into(),
+            ));
+        }
+
+        Ok(token)
+    }
+
+    async fn revoke(&self, user_id: Uuid, refresh_token: &str) -> Result<(), DomainError> {
+        let token_hash = self.opaque_token_generator.hash(refresh_token);
+        if let Some(token) = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await?
+            && token.user_id == user_id
+        {
+            self.refresh_token_repository
+                .revoke_family(token.family_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn revoke_all(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.refresh_token_repository
+            .revoke_all_for_user(user_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockOpaqueTokenGenerator, MockRefreshTokenRepository, MockSessionRepository,
+        MockTokenGenerator,
+    };
+    use domain::users::{Email, PasswordHash};
+
+    fn user() -> User {
+        User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        )
+    }
+
+    type MockIssuer = TokenIssuer<
+        MockTokenGenerator,
+        MockRefreshTokenRepository,
+        MockSessionRepository,
+        MockOpaqueTokenGenerator,
+    >;
+
+    fn issuer(repo: MockRefreshTokenRepository) -> MockIssuer {
+        issuer_with_sessions(repo, MockSessionRepository::default())
+    }
+
+    fn issuer_with_sessions(
+        repo: MockRefreshTokenRepository,
+        sessions: MockSessionRepository,
+    ) -> MockIssuer {
+        TokenIssuer::new(
+            MockTokenGenerator,
+            repo,
+            sessions,
+            MockOpaqueTokenGenerator,
+            Duration::days(30),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_redeem_rotates_within_family() {
+        let repo = MockRefreshTokenRepository::default();
+        let issuer = issuer(repo.clone());
+        let user = user();
+
+        let first = issuer.issue(&user, &ClientInfo::default()).await.unwrap();
+        let redeemed = issuer.redeem(&first.refresh_token).await.unwrap();
+        assert_eq!(redeemed.user_id, user.id);
+
+        let second = issuer.rotate(&user, redeemed.family_id).await.unwrap();
+        assert_ne!(first.refresh_token, second.refresh_token);
+        assert!(issuer.redeem(&second.refresh_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_reuse_revokes_family() {
+        let repo = MockRefreshTokenRepository::default();
+        let issuer = issuer(repo.clone());
+        let user = user();
+
+        let first = issuer.issue(&user, &ClientInfo::default()).await.unwrap();
+        let redeemed = issuer.redeem(&first.refresh_token).await.unwrap();
+        let second = issuer.rotate(&user, redeemed.family_id).await.unwrap();
+
+        // Replaying the first token is detected as reuse
+        let result = issuer.redeem(&first.refresh_token).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+
+        // ...and the legitimately rotated token is revoked with it
+        let result = issuer.redeem(&second.refresh_token).await;
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_issue_records_session() {
+        let repo = MockRefreshTokenRepository::default();
+        let sessions = MockSessionRepository::default();
+        let issuer = issuer_with_sessions(repo.clone(), sessions.clone());
+        let user = user();
+        let client = ClientInfo {
+            ip_address: Some("203.0.113.7".parse().unwrap()),
+            user_agent: Some("curl/8.0".into()),
+        };
+
+        let first = issuer.issue(&user, &client).await.unwrap();
+        let session = sessions.sessions.lock().unwrap()[0].clone();
+        assert_eq!(session.user_id, user.id);
+        assert_eq!(session.ip_address.as_deref(), Some("203.0.113.7"));
+        assert_eq!(session.user_agent.as_deref(), Some("curl/8.0"));
+        // The session is the refresh token family
+        let redeemed = issuer.redeem(&first.refresh_token).await.unwrap();
+        assert_eq!(redeemed.family_id, session.id);
+
+        // Refreshing continues the session instead of starting another one
+        issuer.rotate(&user, session.id).await.unwrap();
+        let recorded = sessions.sessions.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 1);
+        assert!(recorded[0].last_seen_at >= session.last_seen_at);
+    }
+
+    #[tokio::test]
+    async fn test_redeem_unknown_token() {
+        let issuer = issuer(MockRefreshTokenRepository::default());
+
+        let result = issuer.redeem("does-not-exist").await;
+        assert!(matches!(result.unwrap_err(), DomainError::Unauthorized(_)));
+    }
+}
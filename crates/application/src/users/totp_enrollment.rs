@@ -0,0 +1,322 @@
+// crates/application/src/users/totp_enrollment.rs
+
+use domain::common::error::DomainError;
+use domain::users::{TotpCredential, TotpCredentialRepository, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{
+    ConfirmTotpRequest, DisableTotpRequest, RecoveryCodesResponse, TotpEnrollmentResponse,
+};
+use super::login_user::{PasswordVerifier, confirm_password};
+use super::mfa::{TotpProvider, accept_totp_code, hash_recovery_code};
+use super::token_issuer::OpaqueTokenGenerator;
+
+// Recovery codes handed out when two-factor authentication is turned on
+const RECOVERY_CODE_COUNT: usize = 10;
+
+pub struct EnrollTotpUseCase<R, T, P>
+where
+    R: UserRepository,
+    T: TotpCredentialRepository,
+    P: TotpProvider,
+{
+    user_repository: R,
+    credential_repository: T,
+    totp_provider: P,
+}
+
+impl<R, T, P> EnrollTotpUseCase<R, T, P>
+where
+    R: UserRepository,
+    T: TotpCredentialRepository,
+    P: TotpProvider,
+{
+    pub fn new(user_repository: R, credential_repository: T, totp_provider: P) -> Self {
+        Self {
+            user_repository,
+            credential_repository,
+            totp_provider,
+        }
+    }
+
+    /// Start enrolment with a fresh secret. Starting again before confirming replaces
+    /// the pending secret; once confirmed, two-factor has to be disabled first.
+    pub async fn execute(&self, user_id: Uuid) -> Result<TotpEnrollmentResponse, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        if self
+            .credential_repository
+            .find_by_user(user.id)
+            .await?
+            .is_some_and(|credential| credential.is_confirmed())
+        {
+            return Err(DomainError::AlreadyExists(
+                "Two-factor authentication is already enabled".into(),
+            ));
+        }
+
+        let secret = self.totp_provider.generate_secret();
+        self.credential_repository
+            .save(TotpCredential::new(user.id, secret.clone()))
+            .await?;
+
+        Ok(TotpEnrollmentResponse {
+            otpauth_uri: self
+                .totp_provider
+                .provisioning_uri(&secret, user.email.as_str()),
+            secret,
+        })
+    }
+}
+
+pub struct ConfirmTotpUseCase<T, P, O>
+where
+    T: TotpCredentialRepository,
+    P: TotpProvider,
+    O: OpaqueTokenGenerator,
+{
+    credential_repository: T,
+    totp_provider: P,
+    opaque_token_generator: O,
+}
+
+impl<T, P, O> ConfirmTotpUseCase<T, P, O>
+where
+    T: TotpCredentialRepository,
+    P: TotpProvider,
+    O: OpaqueTokenGenerator,
+{
+    pub fn new(credential_repository: T, totp_provider: P, opaque_token_generator: O) -> Self {
+        Self {
+            credential_repository,
+            totp_provider,
+            opaque_token_generator,
+        }
+    }
+
+    /// Turn two-factor authentication on with a first code from the authenticator app.
+    /// The recovery codes are only ever shown here.
+    pub async fn execute(
+        &self,
+        request: ConfirmTotpRequest,
+        user_id: Uuid,
+    ) -> Result<RecoveryCodesResponse, DomainError> {
+        let credential = self
+            .credential_repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|credential| !credential.is_confirmed())
+            .ok_or_else(|| DomainError::NotFound("No two-factor enrolment is pending".into()))?;
+
+        if !accept_totp_code(
+            &self.credential_repository,
+            &self.totp_provider,
+            &credential,
+            &request.code,
+        )
+        .await?
+        {
+            return Err(DomainError::Validation("Invalid code".into()));
+        }
+
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| self.totp_provider.generate_recovery_code())
+            .collect();
+        let hashes: Vec<String> = recovery_codes
+            .iter()
+            .map(|code| hash_recovery_code(&self.opaque_token_generator, code))
+            .collect();
+        self.credential_repository.confirm(user_id, &hashes).await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+}
+
+pub struct DisableTotpUseCase<R, V, T>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    T: TotpCredentialRepository,
+{
+    user_repository: R,
+    password_verifier: V,
+    credential_repository: T,
+}
+
+impl<R, V, T> DisableTotpUseCase<R, V, T>
+where
+    R: UserRepository,
+    V: PasswordVerifier,
+    T: TotpCredentialRepository,
+{
+    pub fn new(user_repository: R, password_verifier: V, credential_repository: T) -> Self {
+        Self {
+            user_repository,
+            password_verifier,
+            credential_repository,
+        }
+    }
+
+    /// Turn two-factor authentication off, or abandon a pending enrolment
+    pub async fn execute(
+        &self,
+        request: DisableTotpRequest,
+        user_id: Uuid,
+    ) -> Result<(), DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        confirm_password(&self.password_verifier, &request.password, &user).await?;
+
+        if self
+            .credential_repository
+            .find_by_user(user.id)
+            .await?
+            .is_none()
+        {
+            return Err(DomainError::NotFound(
+                "Two-factor authentication is not enabled".into(),
+            ));
+        }
+
+        self.credential_repository.delete(user.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockOpaqueTokenGenerator, MockPasswordVerifier, MockTotpCredentialRepository,
+        MockTotpProvider, MockUserRepository,
+    };
+    use domain::users::{Email, PasswordHash, User};
+
+    fn test_user() -> User {
+        User::new(
+            Email::new("admin@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_enroll_and_confirm() {
+        let user = test_user();
+        let credentials = MockTotpCredentialRepository::default();
+        let enroll = EnrollTotpUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            credentials.clone(),
+            MockTotpProvider::default(),
+        );
+        let confirm = ConfirmTotpUseCase::new(
+            credentials.clone(),
+            MockTotpProvider::default(),
+            MockOpaqueTokenGenerator,
+        );
+
+        let enrollment = enroll.execute(user.id).await.unwrap();
+        assert_eq!(enrollment.secret, "SECRET");
+        assert!(enrollment.otpauth_uri.contains("admin@example.com"));
+
+        let wrong = confirm
+            .execute(
+                ConfirmTotpRequest {
+                    code: "000000".into(),
+                },
+                user.id,
+            )
+            .await;
+        assert!(matches!(wrong, Err(DomainError::Validation(_))));
+
+        let codes = confirm
+            .execute(
+                ConfirmTotpRequest {
+                    code: "123456".into(),
+                },
+                user.id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(codes.recovery_codes.len(), RECOVERY_CODE_COUNT);
+        // Only hashes are kept
+        let stored = credentials.recovery_codes.lock().unwrap().clone();
+        assert!(stored.iter().all(|(_, hash)| hash.starts_with("hash_")));
+
+        // Enabled accounts cannot enrol again until two-factor is disabled
+        let again = enroll.execute(user.id).await;
+        assert!(matches!(again, Err(DomainError::AlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn test_disable_requires_password() {
+        let user = test_user();
+        let credentials = MockTotpCredentialRepository::default();
+        credentials
+            .save(TotpCredential::new(user.id, "SECRET".to_string()))
+            .await
+            .unwrap();
+        let disable = DisableTotpUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            MockPasswordVerifier,
+            credentials.clone(),
+        );
+
+        let wrong = disable
+            .execute(
+                DisableTotpRequest {
+                    password: "wrong".into(),
+                },
+                user.id,
+            )
+            .await;
+        assert!(matches!(wrong, Err(DomainError::Validation(_))));
+
+        disable
+            .execute(
+                DisableTotpRequest {
+                    password: "password123".into(),
+                },
+                user.id,
+            )
+            .await
+            .unwrap();
+        assert!(credentials.find_by_user(user.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disable_without_password_asks_for_one() {
+        let user = User::new_passwordless(Email::new("oidc@example.com").unwrap(), true);
+        let credentials = MockTotpCredentialRepository::default();
+        credentials
+            .save(TotpCredential::new(user.id, "SECRET".to_string()))
+            .await
+            .unwrap();
+        let disable = DisableTotpUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            MockPasswordVerifier,
+            credentials.clone(),
+        );
+
+        let result = disable
+            .execute(
+                DisableTotpRequest {
+                    password: "anything".into(),
+                },
+                user.id,
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            DomainError::Validation(message) if message.contains("password reset")
+        ));
+        assert!(credentials.find_by_user(user.id).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,135 @@
+// crates/application/src/users/update_profile.rs
+
+use domain::common::error::DomainError;
+use domain::users::{AvatarUrl, Bio, DisplayName, UserRepository};
+use uuid::Uuid;
+
+use super::dtos::{CurrentUserResponse, UpdateProfileRequest};
+use super::get_profile::current_user_response;
+
+pub struct UpdateProfileUseCase<R>
+where
+    R: UserRepository,
+{
+    user_repository: R,
+}
+
+impl<R> UpdateProfileUseCase<R>
+where
+    R: UserRepository,
+{
+    pub fn new(user_repository: R) -> Self {
+        Self { user_repository }
+    }
+
+    /// Apply a partial update to the user's own profile. Omitted fields are left
+    /// alone and blank ones are cleared.
+    pub async fn execute(
+        &self,
+        request: UpdateProfileRequest,
+        user_id: Uuid,
+    ) -> Result<CurrentUserResponse, DomainError> {
+        let mut user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        if let Some(display_name) = request.display_name {
+            user.profile.display_name =
+                non_blank(display_name).map(DisplayName::new).transpose()?;
+        }
+        if let Some(bio) = request.bio {
+            user.profile.bio = non_blank(bio).map(Bio::new).transpose()?;
+        }
+        if let Some(avatar_url) = request.avatar_url {
+            user.profile.avatar_url = non_blank(avatar_url).map(AvatarUrl::new).transpose()?;
+        }
+
+        self.user_repository
+            .update_profile(user.id, &user.profile)
+            .await?;
+
+        Ok(current_user_response(&user))
+    }
+}
+
+fn non_blank(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockUserRepository;
+
+    use domain::users::{Email, PasswordHash, User};
+
+    fn user_with_bio() -> User {
+        let mut user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hashed_password123".to_string()),
+        );
+        user.profile.bio = Some(Bio::new("Writes about Rust").unwrap());
+        user
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_partial() {
+        let user = user_with_bio();
+        let users = MockUserRepository::with_user(user.clone());
+        let use_case = UpdateProfileUseCase::new(users.clone());
+
+        let request = UpdateProfileRequest {
+            display_name: Some("  Ferris  ".to_string()),
+            avatar_url: Some("https://example.com/ferris.png".to_string()),
+            ..Default::default()
+        };
+        let response = use_case.execute(request, user.id).await.unwrap();
+
+        assert_eq!(response.display_name.as_deref(), Some("Ferris"));
+        assert_eq!(response.bio.as_deref(), Some("Writes about Rust"));
+        let stored = users.users.lock().unwrap()[0].profile.clone();
+        assert_eq!(stored.display_name.unwrap().as_str(), "Ferris");
+        assert_eq!(
+            stored.avatar_url.unwrap().as_str(),
+            "https://example.com/ferris.png"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_blank_clears_field() {
+        let user = user_with_bio();
+        let users = MockUserRepository::with_user(user.clone());
+        let use_case = UpdateProfileUseCase::new(users.clone());
+
+        let request = UpdateProfileRequest {
+            bio: Some("".to_string()),
+            ..Default::default()
+        };
+        let response = use_case.execute(request, user.id).await.unwrap();
+
+        assert!(response.bio.is_none());
+        assert!(users.users.lock().unwrap()[0].profile.bio.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_invalid_avatar_url() {
+        let user = user_with_bio();
+        let users = MockUserRepository::with_user(user.clone());
+        let use_case = UpdateProfileUseCase::new(users.clone());
+
+        let request = UpdateProfileRequest {
+            avatar_url: Some("javascript:alert(1)".to_string()),
+            ..Default::default()
+        };
+        let result = use_case.execute(request, user.id).await;
+
+        assert!(matches!(result.unwrap_err(), DomainError::Validation(_)));
+        assert!(users.users.lock().unwrap()[0].profile.avatar_url.is_none());
+    }
+}
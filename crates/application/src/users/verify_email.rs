@@ -0,0 +1,51 @@
+// crates/application/src/users/verify_email.rs
+
+use domain::common::error::DomainError;
+use domain::users::UserRepository;
+
+use super::dtos::{VerifyEmailRequest, VerifyEmailResponse};
+use super::email_verification::EmailVerificationIssuer;
+
+pub struct VerifyEmailUseCase<R, V>
+where
+    R: UserRepository,
+    V: EmailVerificationIssuer,
+{
+    user_repository: R,
+    email_verification: V,
+}
+
+impl<R, V> VerifyEmailUseCase<R, V>
+where
+    R: UserRepository,
+    V: EmailVerificationIssuer,
+{
+    pub fn new(user_repository: R, email_verification: V) -> Self {
+        Self {
+            user_repository,
+            email_verification,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: VerifyEmailRequest,
+    ) -> Result<VerifyEmailResponse, DomainError> {
+        // Consume the token (single use)
+        let redeemed = self.email_verification.redeem(&request.token).await?;
+
+        let user = self
+            .user_repository
+            .find_by_id(redeemed.user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        self.user_repository.mark_email_verified(user.id).await?;
+
+        Ok(VerifyEmailResponse {
+            user_id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            email_verified: true,
+        })
+    }
+}
@@ -0,0 +1,224 @@
+// crates/application/src/users/verify_mfa.rs
+
+use chrono::Utc;
+use domain::common::error::DomainError;
+use domain::users::{LoginAttemptRepository, UserRepository};
+
+use super::dtos::{LoginUserResponse, VerifyMfaRequest};
+use super::login_throttle::{
+    LoginThrottlePolicy, account_key, ensure_not_locked, record_failures, throttle_keys,
+};
+use super::login_user::login_response;
+use super::mfa::MfaChallengeIssuer;
+use super::token_issuer::{AuthTokenIssuer, ClientInfo};
+
+pub struct VerifyMfaUseCase<R, M, I, A>
+where
+    R: UserRepository,
+    M: MfaChallengeIssuer,
+    I: AuthTokenIssuer,
+    A: LoginAttemptRepository,
+{
+    user_repository: R,
+    mfa: M,
+    token_issuer: I,
+    login_attempts: A,
+    throttle: LoginThrottlePolicy,
+}
+
+impl<R, M, I, A> VerifyMfaUseCase<R, M, I, A>
+where
+    R: UserRepository,
+    M: MfaChallengeIssuer,
+    I: AuthTokenIssuer,
+    A: LoginAttemptRepository,
+{
+    pub fn new(
+        user_repository: R,
+        mfa: M,
+        token_issuer: I,
+        login_attempts: A,
+        throttle: LoginThrottlePolicy,
+    ) -> Self {
+        Self {
+            user_repository,
+            mfa,
+            token_issuer,
+            login_attempts,
+            throttle,
+        }
+    }
+
+    /// Finish a login by answering its MFA challenge, then issue tokens as a plain
+    /// login would. Wrong answers count as failed logins against the account and the
+    /// client IP, so the lockout that guards the password guards the code as well.
+    pub async fn execute(
+        &self,
+        request: VerifyMfaRequest,
+        client: &ClientInfo,
+    ) -> Result<LoginUserResponse, DomainError> {
+        let invalid = || DomainError::Unauthorized("Invalid or expired MFA token".into());
+
+        let user = match self.mfa.challenged_user(&request.mfa_token).await? {
+            Some(user_id) => self.user_repository.find_by_id(user_id).await?,
+            None => None,
+        };
+        let keys = throttle_keys(&self.throttle, user.as_ref().map(|u| &u.email), client);
+        let now = Utc::now();
+        ensure_not_locked(&self.login_attempts, &keys, now).await?;
+
+        let user_id = match self.mfa.redeem(&request.mfa_token, &request.code).await {
+            Ok(user_id) => user_id,
+            Err(error @ DomainError::Unauthorized(_)) => {
+                record_failures(&self.login_attempts, &self.throttle, &keys, now).await?;
+                return Err(error);
+            }
+            Err(error) => return Err(error),
+        };
+        let user = user.filter(|user| user.id == user_id).ok_or_else(invalid)?;
+
+        // Both factors are in, so the account starts over; the IP keeps its count
+        self.login_attempts.clear(&account_key(&user.email)).await?;
+
+        let tokens = self.token_issuer.issue(&user, client).await?;
+
+        Ok(login_response(&user, tokens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        MockLoginAttemptRepository, MockMfa, MockTokenIssuer, MockUserRepository,
+    };
+    use chrono::Duration;
+    use domain::users::{Email, PasswordHash, User};
+
+    // The challenge `MockMfa` has outstanding, and its answer
+    const MFA_TOKEN: &str = "mfa_token";
+    const CODE: &str = "123456";
+
+    fn user() -> User {
+        User::new(
+            Email::new("admin@example.com").unwrap(),
+            PasswordHash::new("hashed_password".to_string()),
+        )
+    }
+
+    fn client() -> ClientInfo {
+        ClientInfo {
+            ip_address: Some("203.0.113.7".parse().unwrap()),
+            user_agent: None,
+        }
+    }
+
+    fn use_case(
+        user: &User,
+        attempts: MockLoginAttemptRepository,
+        throttle: LoginThrottlePolicy,
+    ) -> VerifyMfaUseCase<MockUserRepository, MockMfa, MockTokenIssuer, MockLoginAttemptRepository>
+    {
+        VerifyMfaUseCase::new(
+            MockUserRepository::with_user(user.clone()),
+            MockMfa {
+                user_id: Some(user.id),
+                ..Default::default()
+            },
+            MockTokenIssuer::default(),
+            attempts,
+            throttle,
+        )
+    }
+
+    fn request(mfa_token: &str, code: &str) -> VerifyMfaRequest {
+        VerifyMfaRequest {
+            mfa_token: mfa_token.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_mfa_issues_tokens_and_clears_account_failures() {
+        let user = user();
+        let attempts = MockLoginAttemptRepository::default();
+        attempts
+            .record_failure("account:admin@example.com", Duration::minutes(15))
+            .await
+            .unwrap();
+        let use_case = use_case(&user, attempts.clone(), LoginThrottlePolicy::default());
+
+        let response = use_case
+            .execute(request(MFA_TOKEN, CODE), &client())
+            .await
+            .unwrap();
+
+        assert_eq!(response.user_id, user.id.to_string());
+        assert_eq!(response.token, format!("token_{}", user.id));
+        let account = attempts.find("account:admin@example.com").await.unwrap();
+        assert_eq!(account.failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_code_counts_against_account_and_ip() {
+        let user = user();
+        let attempts = MockLoginAttemptRepository::default();
+        let use_case = use_case(&user, attempts.clone(), LoginThrottlePolicy::default());
+
+        let result = use_case
+            .execute(request(MFA_TOKEN, "000000"), &client())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        let account = attempts.find("account:admin@example.com").await.unwrap();
+        let ip = attempts.find("ip:203.0.113.7").await.unwrap();
+        assert_eq!(account.failures, 1);
+        assert_eq!(ip.failures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_codes_lock_the_account_out() {
+        let user = user();
+        let attempts = MockLoginAttemptRepository::default();
+        let throttle = LoginThrottlePolicy {
+            max_account_failures: 3,
+            ..Default::default()
+        };
+        let use_case = use_case(&user, attempts, throttle);
+
+        for _ in 0..3 {
+            let result = use_case
+                .execute(request(MFA_TOKEN, "000000"), &ClientInfo::default())
+                .await;
+            assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        }
+
+        // Even the right code is refused while the lockout lasts
+        let result = use_case
+            .execute(request(MFA_TOKEN, CODE), &ClientInfo::default())
+            .await;
+        match result {
+            Err(DomainError::TooManyRequests {
+                retry_after_secs, ..
+            }) => {
+                assert!(retry_after_secs > 0 && retry_after_secs <= 60);
+            }
+            other => panic!("expected lockout, got {:?}", other.map(|r| r.user_id)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_token_counts_against_ip_only() {
+        let user = user();
+        let attempts = MockLoginAttemptRepository::default();
+        let use_case = use_case(&user, attempts.clone(), LoginThrottlePolicy::default());
+
+        let result = use_case.execute(request("guessed", CODE), &client()).await;
+
+        assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+        let account = attempts.find("account:admin@example.com").await.unwrap();
+        let ip = attempts.find("ip:203.0.113.7").await.unwrap();
+        assert_eq!(account.failures, 0);
+        assert_eq!(ip.failures, 1);
+    }
+}
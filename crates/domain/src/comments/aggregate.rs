@@ -10,17 +10,24 @@ pub struct Comment {
     pub content: CommentContent,
     pub post_id: Uuid,
     pub author_id: Uuid,
+    pub parent_id: Option<Uuid>, // None for top-level comments
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl Comment {
-    // Factory method: Create a new comment
-    pub fn new(content: CommentContent, post_id: Uuid, author_id: Uuid) -> Self {
+    // Factory method: Create a new comment, optionally as a reply to another comment
+    pub fn new(
+        content: CommentContent,
+        post_id: Uuid,
+        author_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Self {
         Self {
             id: Uuid::new_v4(),
             content,
             post_id,
             author_id,
+            parent_id,
             created_at: chrono::Utc::now(),
         }
     }
@@ -2,6 +2,7 @@
 
 use super::aggregate::Comment;
 use crate::common::error::DomainError;
+use crate::common::pagination::PageRequest;
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -9,6 +10,29 @@ use uuid::Uuid;
 #[async_trait]
 pub trait CommentRepository: Send + Sync + Clone {
     async fn save(&self, comment: Comment) -> Result<(), DomainError>;
+    // Deletes the comment together with its replies
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, DomainError>;
-    async fn find_by_post(&self, post_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Comment>, DomainError>;
+    // Oldest first
+    async fn find_by_post(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError>;
+    async fn count_by_post(&self, post_id: Uuid) -> Result<i64, DomainError>;
+    // Newest first
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError>;
+    // Page of top-level comments together with all of their replies, oldest first. The
+    // page's offset or cursor counts top-level comments only.
+    async fn find_thread(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError>;
+    // How many top-level comments, and so threads, the post has
+    async fn count_threads(&self, post_id: Uuid) -> Result<i64, DomainError>;
 }
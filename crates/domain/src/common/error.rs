@@ -10,6 +10,18 @@ pub enum DomainError {
     #[error("Validation failed: {0}")]
     Validation(String),
 
+    // Authentication Failure
+    // Occurs when the caller's identity cannot be established, such as an invalid or expired credential.
+    // Good to map to 401 Unauthorized.
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    // Permission Denied
+    // Occurs when the caller is known but not allowed to act on the resource, such as editing another user's post.
+    // Good to map to 403 Forbidden.
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     // Data Conflict
     // Occurs when trying to create a resource that already exists, such as a duplicate email during registration.
     // Good to map to 409 Conflict.
@@ -22,6 +34,23 @@ pub enum DomainError {
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    // Rate Limited
+    // Occurs when the caller has to back off before trying again, such as after too many failed logins.
+    // `retry_after_secs` says how long to wait.
+    // Good to map to 429 Too Many Requests with a Retry-After header.
+    #[error("Too many requests: {message}")]
+    TooManyRequests {
+        message: String,
+        retry_after_secs: u64,
+    },
+
+    // Overloaded
+    // Occurs when the service is too busy to take the request on, such as when the password hashing queue is full.
+    // Not the caller's fault; retrying shortly is expected to succeed.
+    // Good to map to 503 Service Unavailable.
+    #[error("Service overloaded: {0}")]
+    Overloaded(String),
+
     // Infrastructure Error
     // Occurs due to issues outside domain logic, such as DB connection failure or hashing library errors.
     // Protects the domain from depending on infrastructure technology by wrapping specific error types (e.g., sqlx::Error) in a String.
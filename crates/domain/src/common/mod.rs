@@ -1,4 +1,5 @@
 // crates/domain/src/common/mod.rs
 
 pub mod error;
+pub mod pagination;
 pub mod value_objects;
@@ -0,0 +1,132 @@
+// crates/domain/src/common/pagination.rs
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::error::DomainError;
+
+// PageKey: Where an item sits in a listing ordered by creation time, with ties broken by
+// id. Encoded, it is the opaque cursor handed out to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PageKey {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl PageKey {
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    // Microseconds since the epoch followed by the id, which is as precise as the database
+    pub fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(24);
+        bytes.extend_from_slice(&self.created_at.timestamp_micros().to_be_bytes());
+        bytes.extend_from_slice(self.id.as_bytes());
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, DomainError> {
+        let invalid = || DomainError::Validation("Invalid cursor".into());
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        if bytes.len() != 24 {
+            return Err(invalid());
+        }
+        let (micros, id) = bytes.split_at(8);
+        let micros = i64::from_be_bytes(micros.try_into().map_err(|_| invalid())?);
+        let created_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+        let id = Uuid::from_slice(id).map_err(|_| invalid())?;
+        Ok(Self { created_at, id })
+    }
+}
+
+// PagePosition: Where a page starts, relative to the listing's own order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagePosition {
+    Offset(i64),     // After skipping this many items
+    After(PageKey),  // Right after the item with this key
+    Before(PageKey), // Ending right before the item with this key
+}
+
+// PageRequest: Which page of a listing to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    limit: i64,
+    position: PagePosition,
+}
+
+impl PageRequest {
+    // The first `limit` items
+    pub fn first(limit: i64) -> Self {
+        Self {
+            limit,
+            position: PagePosition::Offset(0),
+        }
+    }
+
+    // The `limit` items following `key`
+    pub fn after(key: PageKey, limit: i64) -> Self {
+        Self {
+            limit,
+            position: PagePosition::After(key),
+        }
+    }
+
+    // A page as asked for by a client: either an offset or one of the `after` and `before`
+    // cursors. `limit` is brought within 1..=`max_limit`.
+    pub fn parse(
+        limit: i64,
+        max_limit: i64,
+        offset: i64,
+        after: Option<&str>,
+        before: Option<&str>,
+    ) -> Result<Self, DomainError> {
+        if offset < 0 {
+            return Err(DomainError::Validation("Offset cannot be negative".into()));
+        }
+        let position = match (after, before) {
+            (None, None) => PagePosition::Offset(offset),
+            (Some(_), Some(_)) => {
+                return Err(DomainError::Validation(
+                    "Only one of after and before can be given".into(),
+                ));
+            }
+            _ if offset > 0 => {
+                return Err(DomainError::Validation(
+                    "A cursor cannot be combined with an offset".into(),
+                ));
+            }
+            (Some(cursor), None) => PagePosition::After(PageKey::decode(cursor)?),
+            (None, Some(cursor)) => PagePosition::Before(PageKey::decode(cursor)?),
+        };
+        Ok(Self {
+            limit: limit.clamp(1, max_limit.max(1)),
+            position,
+        })
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+    }
+
+    pub fn position(&self) -> PagePosition {
+        self.position
+    }
+
+    // The offset, unless the page is positioned by a cursor
+    pub fn offset(&self) -> Option<i64> {
+        match self.position {
+            PagePosition::Offset(offset) => Some(offset),
+            PagePosition::After(_) | PagePosition::Before(_) => None,
+        }
+    }
+
+    // The same page with one more item, read to tell whether the listing goes on past it
+    pub fn with_lookahead(&self) -> Self {
+        Self {
+            limit: self.limit + 1,
+            position: self.position,
+        }
+    }
+}
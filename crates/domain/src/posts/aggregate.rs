@@ -1,6 +1,8 @@
 // crates/domain/src/posts/aggregate.rs
 
-use super::value_objects::{PostContent, PostTitle};
+use super::value_objects::{PostContent, PostSlug, PostStatus, PostTitle, Tag};
+use crate::common::error::DomainError;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,23 +10,158 @@ use uuid::Uuid;
 pub struct Post {
     pub id: Uuid,
     pub title: PostTitle,
+    // Unique among posts; kept in step with the title by the use cases that change it
+    pub slug: PostSlug,
+    // The number the slug ends in to tell it apart from another post's slug made from the
+    // same words, if it needed one
+    pub slug_suffix: Option<u32>,
     pub content: PostContent,
     pub author_id: Uuid,
+    // Sorted and without duplicates
+    pub tags: Vec<Tag>,
+    pub status: PostStatus,
+    // When the post went live; for a scheduled post, when it will
+    pub published_at: Option<DateTime<Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl Post {
-    // Factory method: Create a new post
+    pub const MAX_TAGS: usize = 10;
+
+    // Factory method: Create a new post as a draft, with a slug made from its title
     pub fn new(title: PostTitle, content: PostContent, author_id: Uuid) -> Self {
         let now = chrono::Utc::now();
         Self {
             id: Uuid::new_v4(),
+            slug: PostSlug::from_title(&title),
+            slug_suffix: None,
             title,
             content,
             author_id,
+            tags: Vec::new(),
+            status: PostStatus::Draft,
+            published_at: None,
             created_at: now,
             updated_at: now,
         }
     }
+
+    // Slug made from `base`, the words of a title, followed by `suffix` if there is one
+    pub fn set_slug(&mut self, base: &PostSlug, suffix: Option<u32>) {
+        self.slug = match suffix {
+            Some(n) => base.with_suffix(n),
+            None => base.clone(),
+        };
+        self.slug_suffix = suffix;
+    }
+
+    // Whether the slug was made from `base`, rather than from the words of an earlier title
+    pub fn slug_made_from(&self, base: &PostSlug) -> bool {
+        match self.slug_suffix {
+            Some(n) => self.slug == base.with_suffix(n),
+            None => self.slug == *base,
+        }
+    }
+
+    // Only the author may modify or delete a post
+    pub fn ensure_author(&self, user_id: Uuid) -> Result<(), DomainError> {
+        if self.author_id != user_id {
+            return Err(DomainError::Forbidden(
+                "Only the author can modify this post".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Apply an edit; fields left as None keep their current value
+    pub fn edit(&mut self, title: Option<PostTitle>, content: Option<PostContent>) {
+        if let Some(title) = title {
+            self.title = title;
+        }
+        if let Some(content) = content {
+            self.content = content;
+        }
+        self.updated_at = chrono::Utc::now();
+    }
+
+    // Replace the post's tags; duplicates are dropped
+    pub fn set_tags(&mut self, tags: Vec<Tag>) -> Result<(), DomainError> {
+        let mut tags = tags;
+        tags.sort();
+        tags.dedup();
+        if tags.len() > Self::MAX_TAGS {
+            return Err(DomainError::Validation(format!(
+                "A post cannot have more than {} tags",
+                Self::MAX_TAGS
+            )));
+        }
+        self.tags = tags;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    // Published posts are visible to everyone, others only to their author
+    pub fn is_visible_to(&self, viewer: Option<Uuid>) -> bool {
+        self.status == PostStatus::Published || viewer == Some(self.author_id)
+    }
+
+    // Draft or scheduled -> scheduled: publish at `at`, which must be in the future
+    pub fn schedule(&mut self, at: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if !matches!(self.status, PostStatus::Draft | PostStatus::Scheduled) {
+            return Err(self.invalid_transition(PostStatus::Scheduled));
+        }
+        if at <= now {
+            return Err(DomainError::Validation(
+                "Scheduled publication time must be in the future".into(),
+            ));
+        }
+        self.status = PostStatus::Scheduled;
+        self.published_at = Some(at);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // Draft or scheduled -> published. A scheduled post that has come due keeps its
+    // scheduled time as the publication time.
+    pub fn publish(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        let published_at = match self.status {
+            PostStatus::Draft => now,
+            PostStatus::Scheduled => self.published_at.filter(|at| *at <= now).unwrap_or(now),
+            _ => return Err(self.invalid_transition(PostStatus::Published)),
+        };
+        self.status = PostStatus::Published;
+        self.published_at = Some(published_at);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // Scheduled -> draft: call off a scheduled publication
+    pub fn unschedule(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PostStatus::Scheduled {
+            return Err(self.invalid_transition(PostStatus::Draft));
+        }
+        self.status = PostStatus::Draft;
+        self.published_at = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // Published -> archived: take the post out of listings, keeping its publication time
+    pub fn archive(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PostStatus::Published {
+            return Err(self.invalid_transition(PostStatus::Archived));
+        }
+        self.status = PostStatus::Archived;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    fn invalid_transition(&self, to: PostStatus) -> DomainError {
+        DomainError::Validation(format!(
+            "A {} post cannot become {}",
+            self.status.as_str(),
+            to.as_str()
+        ))
+    }
 }
@@ -2,9 +2,15 @@
 
 mod aggregate;
 mod repository;
+mod revision;
+mod search;
 mod value_objects;
 
 // Re-exports
 pub use aggregate::Post;
-pub use repository::PostRepository;
-pub use value_objects::{PostContent, PostTitle};
+pub use repository::{PostRepository, PostSearchRepository};
+pub use revision::PostRevision;
+pub use search::PostSearchHit;
+pub use value_objects::{
+    PostContent, PostSlug, PostStatus, PostTitle, SearchQuery, Tag, TagFilter, TagMatch,
+};
@@ -1,15 +1,94 @@
 // crates/domain/src/posts/repository.rs
 
 use super::aggregate::Post;
+use super::revision::PostRevision;
+use super::search::PostSearchHit;
+use super::value_objects::{PostSlug, SearchQuery, Tag, TagFilter};
 use crate::common::error::DomainError;
+use crate::common::pagination::PageRequest;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 // Repository interface for posts
 #[async_trait]
 pub trait PostRepository: Send + Sync + Clone {
+    // Saved together with revision 1, the post as its author created it
     async fn save(&self, post: Post) -> Result<(), DomainError>;
+    // A changed slug retires the old one into the post's slug history
+    async fn update(&self, post: Post) -> Result<(), DomainError>;
+    // `update` plus a snapshot of the post as the revision after its latest one, written
+    // together or not at all
+    async fn update_with_revision(
+        &self,
+        post: Post,
+        editor_id: Uuid,
+        restored_from: Option<i32>,
+    ) -> Result<(), DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError>;
-    async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Post>, DomainError>;
-    async fn find_by_author(&self, author_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Post>, DomainError>;
+    async fn find_by_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError>;
+    // The post that used to have `slug` before its title changed
+    async fn find_by_previous_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError>;
+    // `base` and its suffixed forms ("base-2", ...) that are current or previous slugs of
+    // posts other than `except_post`
+    async fn find_taken_slugs(
+        &self,
+        base: &PostSlug,
+        except_post: Option<Uuid>,
+    ) -> Result<Vec<PostSlug>, DomainError>;
+    // Posts from before slugs existed, oldest first. Until they are given one they read as
+    // having the slug made from their title.
+    async fn find_without_slug(&self, limit: i64) -> Result<Vec<Post>, DomainError>;
+    // Stores the post's slug and suffix if it has none yet. False when it already had one.
+    async fn backfill_slug(&self, post: &Post) -> Result<bool, DomainError>;
+    // Published posts only, newest first, optionally limited to posts with certain tags.
+    // Pages before a cursor come back in the same newest-first order.
+    async fn find_published(
+        &self,
+        tags: Option<&TagFilter>,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError>;
+    // All of the author's posts, whatever their status, newest first
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError>;
+    // How many posts `find_published` and `find_by_author` list across all pages
+    async fn count_published(&self, tags: Option<&TagFilter>) -> Result<i64, DomainError>;
+    async fn count_by_author(&self, author_id: Uuid) -> Result<i64, DomainError>;
+    // Scheduled posts whose publication time is at or before `now`
+    async fn find_due_scheduled(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Post>, DomainError>;
+    // Publishes the post only if it is still scheduled and due at `now`, keeping its
+    // scheduled time as the publication time. False when an edit or unscheduling got to it
+    // first.
+    async fn publish_if_due(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, DomainError>;
+    // Every tag in use on a published post, with the number of such posts carrying it
+    async fn find_tag_counts(&self) -> Result<Vec<(Tag, i64)>, DomainError>;
+    // All of the post's revisions, oldest first
+    async fn find_revisions(&self, post_id: Uuid) -> Result<Vec<PostRevision>, DomainError>;
+    async fn find_revision(
+        &self,
+        post_id: Uuid,
+        number: i32,
+    ) -> Result<Option<PostRevision>, DomainError>;
+}
+
+// Full-text search over published posts
+#[async_trait]
+pub trait PostSearchRepository: Send + Sync + Clone {
+    // Best matches first
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PostSearchHit>, DomainError>;
+    // How many posts `search` finds across all pages
+    async fn count(&self, query: &SearchQuery) -> Result<i64, DomainError>;
 }
@@ -0,0 +1,44 @@
+// crates/domain/src/posts/revision.rs
+
+use super::aggregate::Post;
+use super::value_objects::{PostContent, PostTitle};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// PostRevision: An immutable snapshot of a post's title and content, taken when the
+// post is created and on every edit that changes either. Revisions are numbered from
+// 1 per post.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostRevision {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub number: i32,
+    pub title: PostTitle,
+    pub content: PostContent,
+    pub editor_id: Uuid,
+    // The revision this one brought back, when it was made by a restore
+    pub restored_from: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PostRevision {
+    // Factory method: Snapshot the post as it is now
+    pub fn new(post: &Post, number: i32, editor_id: Uuid, restored_from: Option<i32>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            post_id: post.id,
+            number,
+            title: post.title.clone(),
+            content: post.content.clone(),
+            editor_id,
+            restored_from,
+            created_at: post.updated_at,
+        }
+    }
+
+    // Whether the post still has this revision's title and content
+    pub fn matches(&self, post: &Post) -> bool {
+        self.title == post.title && self.content == post.content
+    }
+}
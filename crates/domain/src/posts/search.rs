@@ -0,0 +1,13 @@
+// crates/domain/src/posts/search.rs
+
+use super::aggregate::Post;
+
+// PostSearchHit: A published post matching a search, with how well it matches and the
+// passages that do. Snippets are HTML-escaped, with matches wrapped in <mark> tags.
+#[derive(Debug, Clone)]
+pub struct PostSearchHit {
+    pub post: Post,
+    pub rank: f32,
+    pub title_snippet: String,
+    pub content_snippet: String,
+}
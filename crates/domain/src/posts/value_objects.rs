@@ -14,7 +14,9 @@ impl PostTitle {
             return Err(DomainError::Validation("Post title cannot be empty".into()));
         }
         if title.len() > 200 {
-            return Err(DomainError::Validation("Post title cannot exceed 200 characters".into()));
+            return Err(DomainError::Validation(
+                "Post title cannot exceed 200 characters".into(),
+            ));
         }
         Ok(Self(title))
     }
@@ -24,6 +26,71 @@ impl PostTitle {
     }
 }
 
+// PostSlug: The human-readable identifier of a post in links, made from its title
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PostSlug(String);
+
+impl PostSlug {
+    pub const MAX_LENGTH: usize = 100;
+    // Slugs made from titles are kept shorter, leaving room for a de-duplicating suffix
+    const MAX_BASE_LENGTH: usize = 80;
+
+    // Validate an existing slug: lowercase ASCII letters and digits in hyphen-separated words
+    pub fn new(slug: impl Into<String>) -> Result<Self, DomainError> {
+        let slug = slug.into();
+        let valid = !slug.is_empty()
+            && slug.len() <= Self::MAX_LENGTH
+            && slug.split('-').all(|word| {
+                !word.is_empty()
+                    && word
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            });
+        if !valid {
+            return Err(DomainError::Validation(
+                "Slug must be lowercase letters and digits separated by single hyphens".into(),
+            ));
+        }
+        Ok(Self(slug))
+    }
+
+    // Transliterate the title to ASCII and keep its words, e.g. "Größe & Über" becomes
+    // "grosse-uber". Titles without any letters or digits get "post".
+    pub fn from_title(title: &PostTitle) -> Self {
+        let ascii = deunicode::deunicode(title.as_str()).to_ascii_lowercase();
+        let mut slug = String::with_capacity(ascii.len());
+        for word in ascii.split(|c: char| !c.is_ascii_alphanumeric()) {
+            if word.is_empty() {
+                continue;
+            }
+            if slug.len() + word.len() + 1 > Self::MAX_BASE_LENGTH {
+                // Words are only cut when the first one is already too long
+                if slug.is_empty() {
+                    slug.push_str(&word[..Self::MAX_BASE_LENGTH]);
+                }
+                break;
+            }
+            if !slug.is_empty() {
+                slug.push('-');
+            }
+            slug.push_str(word);
+        }
+        if slug.is_empty() {
+            slug.push_str("post");
+        }
+        Self(slug)
+    }
+
+    // The n-th alternative to this slug, for when it is taken: "title-2", "title-3", ...
+    pub fn with_suffix(&self, n: u32) -> Self {
+        Self(format!("{}-{}", self.0, n))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 // PostContent: The body content of a post
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PostContent(String);
@@ -32,7 +99,9 @@ impl PostContent {
     pub fn new(content: impl Into<String>) -> Result<Self, DomainError> {
         let content = content.into();
         if content.trim().is_empty() {
-            return Err(DomainError::Validation("Post content cannot be empty".into()));
+            return Err(DomainError::Validation(
+                "Post content cannot be empty".into(),
+            ));
         }
         Ok(Self(content))
     }
@@ -41,3 +110,187 @@ impl PostContent {
         &self.0
     }
 }
+
+// PostStatus: Where a post is in its lifecycle. Only published posts are shown to
+// anyone but their author.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PostStatus {
+    #[default]
+    Draft,
+    Scheduled,
+    Published,
+    Archived,
+}
+
+impl PostStatus {
+    pub fn parse(status: &str) -> Result<Self, DomainError> {
+        match status {
+            "draft" => Ok(PostStatus::Draft),
+            "scheduled" => Ok(PostStatus::Scheduled),
+            "published" => Ok(PostStatus::Published),
+            "archived" => Ok(PostStatus::Archived),
+            _ => Err(DomainError::Validation(
+                "Status must be one of: draft, scheduled, published, archived".into(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Published => "published",
+            PostStatus::Archived => "archived",
+        }
+    }
+}
+
+// Tag: A topic label, kept as a slug so "Rust Lang" and "rust-lang" are the same tag
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Tag(String);
+
+impl Tag {
+    pub const MAX_LENGTH: usize = 32;
+
+    // Transliterated to ASCII like slugs are, so "Café" is "cafe", then lowercased, with
+    // runs of spaces, underscores and hyphens turned into one hyphen
+    pub fn new(tag: impl Into<String>) -> Result<Self, DomainError> {
+        let tag = deunicode::deunicode(tag.into().trim());
+        let mut slug = String::with_capacity(tag.len());
+        for c in tag.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if c.is_whitespace() || c == '-' || c == '_' {
+                if !slug.is_empty() && !slug.ends_with('-') {
+                    slug.push('-');
+                }
+            } else {
+                return Err(DomainError::Validation(
+                    "Tags may only contain letters, digits, spaces and hyphens".into(),
+                ));
+            }
+        }
+        let slug = slug.trim_end_matches('-').to_string();
+
+        if slug.is_empty() {
+            return Err(DomainError::Validation("Tag cannot be empty".into()));
+        }
+        if slug.len() > Self::MAX_LENGTH {
+            return Err(DomainError::Validation(format!(
+                "Tag cannot exceed {} characters",
+                Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(slug))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// TagMatch: Whether a tag filter wants posts with any or with all of its tags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+impl TagMatch {
+    pub fn parse(mode: &str) -> Result<Self, DomainError> {
+        match mode {
+            "any" => Ok(TagMatch::Any),
+            "all" => Ok(TagMatch::All),
+            _ => Err(DomainError::Validation(
+                "Tag match must be one of: any, all".into(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TagMatch::Any => "any",
+            TagMatch::All => "all",
+        }
+    }
+}
+
+// TagFilter: Limits a listing to posts carrying the given tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagFilter {
+    tags: Vec<Tag>,
+    mode: TagMatch,
+}
+
+impl TagFilter {
+    pub fn new(tags: Vec<Tag>, mode: TagMatch) -> Result<Self, DomainError> {
+        let mut tags = tags;
+        tags.sort();
+        tags.dedup();
+        if tags.is_empty() {
+            return Err(DomainError::Validation(
+                "Tag filter needs at least one tag".into(),
+            ));
+        }
+        Ok(Self { tags, mode })
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn mode(&self) -> TagMatch {
+        self.mode
+    }
+
+    // Whether a post with `tags` passes the filter
+    pub fn matches(&self, tags: &[Tag]) -> bool {
+        match self.mode {
+            TagMatch::Any => self.tags.iter().any(|tag| tags.contains(tag)),
+            TagMatch::All => self.tags.iter().all(|tag| tags.contains(tag)),
+        }
+    }
+}
+
+// SearchQuery: What a reader typed into the search box, in web search syntax ("quoted
+// phrases", -excluded words, or)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    text: String,
+    include_comments: bool,
+}
+
+impl SearchQuery {
+    pub const MAX_LENGTH: usize = 200;
+
+    // With `include_comments`, posts also match through the text of their comments
+    pub fn new(text: impl Into<String>, include_comments: bool) -> Result<Self, DomainError> {
+        let text = text.into().trim().to_string();
+        if text.is_empty() {
+            return Err(DomainError::Validation(
+                "Search query cannot be empty".into(),
+            ));
+        }
+        if text.chars().count() > Self::MAX_LENGTH {
+            return Err(DomainError::Validation(format!(
+                "Search query cannot exceed {} characters",
+                Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self {
+            text,
+            include_comments,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn include_comments(&self) -> bool {
+        self.include_comments
+    }
+}
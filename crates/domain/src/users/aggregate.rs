@@ -1,6 +1,6 @@
 // crates/domain/src/users/aggregate.rs
 
-use super::value_objects::{Email, PasswordHash};
+use super::value_objects::{AvatarUrl, Bio, DisplayName, Email, PasswordHash, Role};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,7 +8,14 @@ use uuid::Uuid;
 pub struct User {
     pub id: Uuid,
     pub email: Email,
-    pub password_hash: PasswordHash, // 평문 비밀번호는 절대 들고 있지 않음
+    // 평문 비밀번호는 절대 들고 있지 않음. None for accounts that only sign in through an
+    // external identity provider.
+    pub password_hash: Option<PasswordHash>,
+    pub role: Role,
+    pub email_verified: bool,
+    pub profile: Profile,
+    // Set while the account is waiting out the grace period before it is removed
+    pub deletion_scheduled_for: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>, // chrono 추가 필요
 }
 
@@ -18,8 +25,34 @@ impl User {
         Self {
             id: Uuid::new_v4(),
             email,
-            password_hash,
+            password_hash: Some(password_hash),
+            role: Role::User,
+            email_verified: false,
+            profile: Profile::default(),
+            deletion_scheduled_for: None,
             created_at: chrono::Utc::now(),
         }
     }
+
+    // Account created through an external identity provider, with no password to log in with
+    pub fn new_passwordless(email: Email, email_verified: bool) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            email,
+            password_hash: None,
+            role: Role::User,
+            email_verified,
+            profile: Profile::default(),
+            deletion_scheduled_for: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+}
+
+// Profile: The public part of a user, shown alongside their content. Every field is optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profile {
+    pub display_name: Option<DisplayName>,
+    pub bio: Option<Bio>,
+    pub avatar_url: Option<AvatarUrl>,
 }
@@ -0,0 +1,118 @@
+// crates/domain/src/users/api_key.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::common::error::DomainError;
+
+// ApiKeyScope: What a personal API key may be used for. Reading is public, so the
+// scopes only cover writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiKeyScope {
+    #[serde(rename = "posts:write")]
+    PostsWrite,
+    #[serde(rename = "comments:write")]
+    CommentsWrite,
+}
+
+impl ApiKeyScope {
+    pub fn parse(scope: &str) -> Result<Self, DomainError> {
+        match scope {
+            "posts:write" => Ok(ApiKeyScope::PostsWrite),
+            "comments:write" => Ok(ApiKeyScope::CommentsWrite),
+            _ => Err(DomainError::Validation(
+                "Scope must be one of: posts:write, comments:write".into(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::PostsWrite => "posts:write",
+            ApiKeyScope::CommentsWrite => "comments:write",
+        }
+    }
+}
+
+// ApiKey: A long-lived credential a user creates for scripts and integrations.
+// The key is shown once; only its SHA-256 hash is stored, next to a short random
+// prefix that is part of the key and is used to find the record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    // Maximum length of a key name, in characters
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    // Factory method: Create a new API key record; the name must not be blank
+    pub fn new(
+        user_id: Uuid,
+        name: String,
+        prefix: String,
+        key_hash: String,
+        scopes: Vec<ApiKeyScope>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, DomainError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::Validation(
+                "API key name cannot be empty".into(),
+            ));
+        }
+        if name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(DomainError::Validation(format!(
+                "API key name cannot be longer than {} characters",
+                Self::MAX_NAME_LENGTH
+            )));
+        }
+        if scopes.is_empty() {
+            return Err(DomainError::Validation(
+                "An API key needs at least one scope".into(),
+            ));
+        }
+
+        let mut unique_scopes = Vec::with_capacity(scopes.len());
+        for scope in scopes {
+            if !unique_scopes.contains(&scope) {
+                unique_scopes.push(scope);
+            }
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            prefix,
+            key_hash,
+            scopes: unique_scopes,
+            expires_at,
+            last_used_at: None,
+            created_at: Utc::now(),
+            revoked_at: None,
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Utc::now())
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
@@ -0,0 +1,18 @@
+// crates/domain/src/users/login_attempts.rs
+
+use chrono::{DateTime, Utc};
+
+// LoginAttempts: Recent failed logins counted against one throttling key, such as an
+// account or a client IP. While `locked_until` is in the future no login is attempted
+// for that key.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoginAttempts {
+    pub failures: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttempts {
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+}
@@ -0,0 +1,36 @@
+// crates/domain/src/users/mfa_challenge.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// MfaChallenge: Handed out when a password was correct but the account also needs a
+// second factor. The token is exchanged, together with a valid code, for real tokens.
+// Only a hash of the token is ever stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaChallenge {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub failed_attempts: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MfaChallenge {
+    // Factory method: Create a new challenge record
+    pub fn new(user_id: Uuid, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash,
+            expires_at,
+            failed_attempts: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
@@ -1,11 +1,36 @@
 // crates/domain/src/users/mod.rs
 
 mod aggregate;
+mod api_key;
+mod email_verification_token;
 mod errors;
+mod login_attempts;
+mod mfa_challenge;
+mod oidc_authorization;
+mod password_reset_token;
+mod refresh_token;
 mod repository;
+mod session;
+mod totp_credential;
+mod user_identity;
 mod value_objects;
 
 // Re-exports
-pub use aggregate::User;
-pub use repository::{PasswordHasher, UserRepository};
-pub use value_objects::{Email, PasswordHash, PlainPassword};
+pub use aggregate::{Profile, User};
+pub use api_key::{ApiKey, ApiKeyScope};
+pub use email_verification_token::EmailVerificationToken;
+pub use login_attempts::LoginAttempts;
+pub use mfa_challenge::MfaChallenge;
+pub use oidc_authorization::OidcAuthorization;
+pub use password_reset_token::PasswordResetToken;
+pub use refresh_token::RefreshToken;
+pub use repository::{
+    ApiKeyRepository, EmailVerificationTokenRepository, LoginAttemptRepository,
+    MfaChallengeRepository, OidcAuthorizationRepository, PasswordHasher,
+    PasswordResetTokenRepository, RefreshTokenRepository, RevokedTokenRepository,
+    SessionRepository, TotpCredentialRepository, UserIdentityRepository, UserRepository,
+};
+pub use session::Session;
+pub use totp_credential::TotpCredential;
+pub use user_identity::UserIdentity;
+pub use value_objects::{AvatarUrl, Bio, DisplayName, Email, PasswordHash, PlainPassword, Role};
@@ -0,0 +1,44 @@
+// crates/domain/src/users/oidc_authorization.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// OidcAuthorization: A sign-in started at an external provider and not yet completed.
+// It is found again by the hash of the `state` the provider hands back, and holds the
+// nonce and PKCE code verifier needed to finish it. Each one can be completed once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcAuthorization {
+    pub id: Uuid,
+    pub state_hash: String,
+    pub provider: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OidcAuthorization {
+    // Factory method: Create a new pending authorization
+    pub fn new(
+        state_hash: String,
+        provider: String,
+        nonce: String,
+        code_verifier: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            state_hash,
+            provider,
+            nonce,
+            code_verifier,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
@@ -0,0 +1,39 @@
+// crates/domain/src/users/password_reset_token.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// PasswordResetToken: A single-use, short-lived token mailed to a user who forgot their
+// password. Only a hash of the token is ever stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl PasswordResetToken {
+    // Factory method: Create a new reset token record
+    pub fn new(user_id: Uuid, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash,
+            expires_at,
+            created_at: Utc::now(),
+            used_at: None,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+}
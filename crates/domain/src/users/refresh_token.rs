@@ -0,0 +1,47 @@
+// crates/domain/src/users/refresh_token.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// RefreshToken: An opaque, long-lived credential exchanged for new access tokens.
+// Only a hash of the token is ever stored. Every rotation issues a new token in the
+// same family, so that reuse of an already rotated token can revoke the whole chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl RefreshToken {
+    // Factory method: Create a new refresh token record
+    pub fn new(
+        user_id: Uuid,
+        family_id: Uuid,
+        token_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            family_id,
+            token_hash,
+            expires_at,
+            created_at: Utc::now(),
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
@@ -1,15 +1,218 @@
 // crates/domain/src/users/repository.rs
 
-use super::aggregate::User;
-use super::value_objects::{Email, PasswordHash, PlainPassword};
+use super::aggregate::{Profile, User};
+use super::api_key::ApiKey;
+use super::email_verification_token::EmailVerificationToken;
+use super::login_attempts::LoginAttempts;
+use super::mfa_challenge::MfaChallenge;
+use super::oidc_authorization::OidcAuthorization;
+use super::password_reset_token::PasswordResetToken;
+use super::refresh_token::RefreshToken;
+use super::session::Session;
+use super::totp_credential::TotpCredential;
+use super::user_identity::UserIdentity;
+use super::value_objects::{Email, PasswordHash, PlainPassword, Role};
 use crate::common::error::DomainError;
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
 
 // Repository interface
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn save(&self, user: User) -> Result<(), DomainError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError>;
+    // Users among `ids`; unknown IDs are skipped
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, DomainError>;
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError>;
+    async fn update_role(&self, id: Uuid, role: Role) -> Result<(), DomainError>;
+    async fn count_by_role(&self, role: Role) -> Result<i64, DomainError>;
+    async fn mark_email_verified(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn update_password(
+        &self,
+        id: Uuid,
+        password_hash: PasswordHash,
+    ) -> Result<(), DomainError>;
+    // Also resets `email_verified`, since the new address has not been confirmed
+    async fn update_email(&self, id: Uuid, email: &Email) -> Result<(), DomainError>;
+    async fn update_profile(&self, id: Uuid, profile: &Profile) -> Result<(), DomainError>;
+    async fn schedule_deletion(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), DomainError>;
+    // Returns false if no deletion was scheduled
+    async fn cancel_deletion(&self, id: Uuid) -> Result<bool, DomainError>;
+    // Users whose scheduled deletion is at or before `now`, oldest first
+    async fn find_due_for_deletion(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<User>, DomainError>;
+    // Removes the user; their posts and comments go with them
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    // Scrubs the user's personal data but keeps the row, so their posts and comments stay
+    // up under an empty profile. The account can no longer be found by email.
+    async fn anonymize(&self, id: Uuid) -> Result<(), DomainError>;
+}
+
+// Refresh token storage interface
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    async fn save(&self, token: RefreshToken) -> Result<(), DomainError>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, DomainError>;
+    // Marks a single token as used; returns false if it was already revoked
+    async fn revoke(&self, id: Uuid) -> Result<bool, DomainError>;
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), DomainError>;
+    async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<(), DomainError>;
+}
+
+// Email verification token storage interface
+#[async_trait]
+pub trait EmailVerificationTokenRepository: Send + Sync {
+    async fn save(&self, token: EmailVerificationToken) -> Result<(), DomainError>;
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailVerificationToken>, DomainError>;
+    // Marks the token as used; returns false if it was already used
+    async fn consume(&self, id: Uuid) -> Result<bool, DomainError>;
+    // Removes all of a user's tokens, e.g. when a new one is sent
+    async fn delete_for_user(&self, user_id: Uuid) -> Result<(), DomainError>;
+}
+
+// Password reset token storage interface
+#[async_trait]
+pub trait PasswordResetTokenRepository: Send + Sync {
+    async fn save(&self, token: PasswordResetToken) -> Result<(), DomainError>;
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, DomainError>;
+    // Marks the token as used; returns false if it was already used
+    async fn consume(&self, id: Uuid) -> Result<bool, DomainError>;
+    // Removes all of a user's tokens, e.g. when a new one is sent
+    async fn delete_for_user(&self, user_id: Uuid) -> Result<(), DomainError>;
+}
+
+// Revoked access token list, keyed by the token's `jti`.
+// Entries only need to live until the token would have expired anyway.
+// A user can also have every token issued before a cutoff revoked at once, and a
+// session every token carrying its `sid`.
+#[async_trait]
+pub trait RevokedTokenRepository: Send + Sync {
+    async fn revoke(&self, token_id: Uuid, expires_at: DateTime<Utc>) -> Result<(), DomainError>;
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        issued_before: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+    // `expires_at` is when the last access token of the session expires
+    async fn revoke_session(
+        &self,
+        session_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+    async fn is_revoked(
+        &self,
+        token_id: Uuid,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+        issued_at: DateTime<Utc>,
+    ) -> Result<bool, DomainError>;
+    // Removes entries whose tokens have expired; returns how many were removed
+    async fn purge_expired(&self) -> Result<u64, DomainError>;
+}
+
+// Logins and the devices they were made from
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn save(&self, session: Session) -> Result<(), DomainError>;
+    // The user's sessions that still hold a usable refresh token, most recently seen first
+    async fn find_active_by_user(&self, user_id: Uuid) -> Result<Vec<Session>, DomainError>;
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), DomainError>;
+    // Removes sessions without a usable refresh token; returns how many were removed
+    async fn purge_inactive(&self) -> Result<u64, DomainError>;
+}
+
+// Failed login tracking for brute-force protection, keyed by an opaque string such as
+// "account:<email>" or "ip:<address>". Unknown keys have no failures.
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    async fn find(&self, key: &str) -> Result<LoginAttempts, DomainError>;
+    // Counts a failure and returns the new total. A key that has seen no failure and
+    // no lockout for longer than `window` starts again from zero.
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError>;
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError>;
+    async fn clear(&self, key: &str) -> Result<(), DomainError>;
+    // Removes keys that are not locked and whose last failure is before `before`;
+    // returns how many were removed
+    async fn purge_stale(&self, before: DateTime<Utc>) -> Result<u64, DomainError>;
+}
+
+// TOTP second factor storage. A user has at most one credential, with the hashes of
+// their recovery codes alongside it.
+#[async_trait]
+pub trait TotpCredentialRepository: Send + Sync {
+    // Stores a new enrolment, replacing any the user had
+    async fn save(&self, credential: TotpCredential) -> Result<(), DomainError>;
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<TotpCredential>, DomainError>;
+    // Marks the credential confirmed and replaces the user's recovery codes
+    async fn confirm(
+        &self,
+        user_id: Uuid,
+        recovery_code_hashes: &[String],
+    ) -> Result<(), DomainError>;
+    // Records the time step of an accepted code; returns false if that step or a later
+    // one was already used
+    async fn use_step(&self, user_id: Uuid, step: i64) -> Result<bool, DomainError>;
+    // Consumes a recovery code; returns false if it is unknown or already used
+    async fn use_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool, DomainError>;
+    // Removes the credential together with its recovery codes
+    async fn delete(&self, user_id: Uuid) -> Result<(), DomainError>;
+}
+
+// Pending second-factor logins, looked up by token hash
+#[async_trait]
+pub trait MfaChallengeRepository: Send + Sync {
+    async fn save(&self, challenge: MfaChallenge) -> Result<(), DomainError>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError>;
+    // Counts a wrong code against the challenge; returns the new total
+    async fn record_failure(&self, id: Uuid) -> Result<u32, DomainError>;
+    // Removes the challenge; returns false if it was already gone
+    async fn delete(&self, id: Uuid) -> Result<bool, DomainError>;
+    // Removes expired challenges; returns how many were removed
+    async fn purge_expired(&self) -> Result<u64, DomainError>;
+}
+
+// Links between users and their accounts at external identity providers
+#[async_trait]
+pub trait UserIdentityRepository: Send + Sync {
+    async fn save(&self, identity: UserIdentity) -> Result<(), DomainError>;
+    async fn find_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, DomainError>;
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<UserIdentity>, DomainError>;
+}
+
+// Sign-ins in progress at external identity providers, looked up by state hash
+#[async_trait]
+pub trait OidcAuthorizationRepository: Send + Sync {
+    async fn save(&self, authorization: OidcAuthorization) -> Result<(), DomainError>;
+    // Removes and returns the authorization, so that it can only be completed once
+    async fn take(&self, state_hash: &str) -> Result<Option<OidcAuthorization>, DomainError>;
+    // Removes expired authorizations; returns how many were removed
+    async fn purge_expired(&self) -> Result<u64, DomainError>;
+}
+
+// Personal API keys, looked up by their public prefix
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn save(&self, key: ApiKey) -> Result<(), DomainError>;
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError>;
+    // All of a user's keys, revoked ones included, newest first
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, DomainError>;
+    // Revokes one of the user's keys; returns false if there is no such active key
+    async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), DomainError>;
 }
 
 // Password hasing interface (important: domain should not know about Argon2)
@@ -0,0 +1,42 @@
+// crates/domain/src/users/session.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Session: One login on one device. Its id is the family id of the refresh tokens
+// issued for the login and the `sid` claim of its access tokens; the session is
+// active as long as the family holds a usable refresh token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl Session {
+    // Longest user agent that is kept, in characters
+    pub const MAX_USER_AGENT_LENGTH: usize = 512;
+
+    // Factory method: Record a new login
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            user_id,
+            user_agent: user_agent
+                .map(|agent| agent.chars().take(Self::MAX_USER_AGENT_LENGTH).collect()),
+            ip_address,
+            created_at: now,
+            last_seen_at: now,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// crates/domain/src/users/totp_credential.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// TotpCredential: The shared secret behind a user's authenticator app. It only guards
+// logins once confirmed with a first valid code; until then it is a pending enrolment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpCredential {
+    pub user_id: Uuid,
+    // Base32, as shown to authenticator apps
+    pub secret: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    // Time step of the last accepted code, so a code cannot be replayed
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TotpCredential {
+    // Factory method: Start a new, unconfirmed enrolment
+    pub fn new(user_id: Uuid, secret: String) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
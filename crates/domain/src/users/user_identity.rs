@@ -0,0 +1,31 @@
+// crates/domain/src/users/user_identity.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// UserIdentity: Links an account at an external OpenID Connect provider to a user.
+// The provider's `sub` claim is the stable identifier; the email is kept for reference only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdentity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserIdentity {
+    // Factory method: Link a provider account to a user
+    pub fn new(user_id: Uuid, provider: String, subject: String, email: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at: Utc::now(),
+        }
+    }
+}
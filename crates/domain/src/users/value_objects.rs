@@ -54,3 +54,125 @@ impl PasswordHash {
         &self.0
     }
 }
+
+// DisplayName: The public name shown next to a user's posts and comments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayName(String);
+
+impl DisplayName {
+    pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::Validation(
+                "Display name cannot be empty".into(),
+            ));
+        }
+        if name.chars().count() > 50 {
+            return Err(DomainError::Validation(
+                "Display name cannot exceed 50 characters".into(),
+            ));
+        }
+        if name.chars().any(char::is_control) {
+            return Err(DomainError::Validation(
+                "Display name cannot contain control characters".into(),
+            ));
+        }
+        Ok(Self(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Bio: A short free-text description on a user's profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bio(String);
+
+impl Bio {
+    pub fn new(bio: impl Into<String>) -> Result<Self, DomainError> {
+        let bio = bio.into();
+        if bio.chars().count() > 500 {
+            return Err(DomainError::Validation(
+                "Bio cannot exceed 500 characters".into(),
+            ));
+        }
+        Ok(Self(bio))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// AvatarUrl: An absolute http(s) URL to the user's avatar image
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarUrl(String);
+
+impl AvatarUrl {
+    pub fn new(url: impl Into<String>) -> Result<Self, DomainError> {
+        let url = url.into();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or_else(|| {
+                DomainError::Validation("Avatar URL must start with http:// or https://".into())
+            })?;
+        if host.is_empty() || host.starts_with('/') {
+            return Err(DomainError::Validation(
+                "Avatar URL must include a host".into(),
+            ));
+        }
+        if url.len() > 2048 {
+            return Err(DomainError::Validation(
+                "Avatar URL cannot exceed 2048 characters".into(),
+            ));
+        }
+        if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(DomainError::Validation(
+                "Avatar URL cannot contain whitespace".into(),
+            ));
+        }
+        Ok(Self(url))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Role: What a user is allowed to do beyond managing their own content
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    User,
+    Moderator,
+    Admin,
+}
+
+impl Role {
+    pub fn parse(role: &str) -> Result<Self, DomainError> {
+        match role {
+            "user" => Ok(Role::User),
+            "moderator" => Ok(Role::Moderator),
+            "admin" => Ok(Role::Admin),
+            _ => Err(DomainError::Validation(
+                "Role must be one of: user, moderator, admin".into(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+
+    // Moderators and admins may moderate other users' content
+    pub fn can_moderate(&self) -> bool {
+        matches!(self, Role::Moderator | Role::Admin)
+    }
+}
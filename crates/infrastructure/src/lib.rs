@@ -1,5 +1,5 @@
 // crates/infrastructure/src/lib.rs
 
+pub mod mail;
 pub mod persistence;
 pub mod security;
-
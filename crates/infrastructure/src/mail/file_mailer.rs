@@ -0,0 +1,76 @@
+// crates/infrastructure/src/mail/file_mailer.rs
+
+use chrono::Utc;
+use domain::common::error::DomainError;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Local development stand-in for SMTP: writes every email to a `.eml` file in a
+/// directory instead of delivering it
+pub struct FileMailer {
+    dir: PathBuf,
+    from: String,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<PathBuf>, from: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            from: from.into(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Write the email and return the path of the file it was written to
+    pub async fn send(&self, to: &str, subject: &str, body: &str) -> Result<PathBuf, DomainError> {
+        let now = Utc::now();
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            now.format("%Y%m%dT%H%M%S"),
+            Uuid::new_v4()
+        ));
+        let contents = format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}",
+            self.from,
+            to,
+            subject,
+            now.to_rfc2822(),
+            body
+        );
+
+        tokio::fs::create_dir_all(&self.dir).await.map_err(|e| {
+            DomainError::InfraError(format!("Failed to create mail directory: {}", e))
+        })?;
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Failed to write email: {}", e)))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_writes_eml_file() {
+        let dir = std::env::temp_dir().join(format!("ferro-mail-{}", Uuid::new_v4()));
+        let mailer = FileMailer::new(&dir, "no-reply@example.com");
+
+        let path = mailer
+            .send("user@example.com", "Hello", "Body text")
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("To: user@example.com\r\n"));
+        assert!(contents.contains("Subject: Hello\r\n"));
+        assert!(contents.ends_with("\r\n\r\nBody text"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,7 @@
+// crates/infrastructure/src/mail/mod.rs
+
+mod file_mailer;
+mod smtp_mailer;
+
+pub use file_mailer::FileMailer;
+pub use smtp_mailer::SmtpMailer;
@@ -0,0 +1,58 @@
+// crates/infrastructure/src/mail/smtp_mailer.rs
+
+use domain::common::error::DomainError;
+use lettre::message::{Mailbox, header::ContentType};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+/// Sends email through an SMTP relay using STARTTLS
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+        from: &str,
+    ) -> Result<Self, DomainError> {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .map_err(|e| DomainError::InfraError(format!("Invalid SMTP host: {}", e)))?
+            .port(port);
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = from
+            .parse()
+            .map_err(|e| DomainError::InfraError(format!("Invalid sender address: {}", e)))?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+
+    pub async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), DomainError> {
+        let to: Mailbox = to
+            .parse()
+            .map_err(|e| DomainError::Validation(format!("Invalid recipient address: {}", e)))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| DomainError::InfraError(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Failed to send email: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+// crates/infrastructure/src/persistence/in_memory_login_attempt_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{LoginAttemptRepository, LoginAttempts};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+struct Entry {
+    failures: u32,
+    last_failure_at: DateTime<Utc>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+/// Process-local failed login tracking for tests and single-instance development setups
+#[derive(Clone, Default)]
+pub struct InMemoryLoginAttemptRepository {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl InMemoryLoginAttemptRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for InMemoryLoginAttemptRepository {
+    async fn find(&self, key: &str) -> Result<LoginAttempts, DomainError> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|entry| LoginAttempts {
+                failures: entry.failures,
+                locked_until: entry.locked_until,
+            })
+            .unwrap_or_default())
+    }
+
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        let now = Utc::now();
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(key.to_string()).or_insert(Entry {
+            failures: 0,
+            last_failure_at: now,
+            locked_until: None,
+        });
+
+        let last_activity = entry.locked_until.map_or(entry.last_failure_at, |until| {
+            until.max(entry.last_failure_at)
+        });
+        if last_activity < now - window {
+            entry.failures = 0;
+        }
+        entry.failures += 1;
+        entry.last_failure_at = now;
+        Ok(entry.failures)
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(key) {
+            entry.locked_until = Some(
+                entry
+                    .locked_until
+                    .map_or(until, |current| current.max(until)),
+            );
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), DomainError> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn purge_stale(&self, before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let now = Utc::now();
+        let mut entries = self.entries.lock().unwrap();
+        let count = entries.len();
+        entries.retain(|_, entry| {
+            entry.last_failure_at >= before || entry.locked_until.is_some_and(|until| until >= now)
+        });
+        Ok((count - entries.len()) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_failure_counts_within_window() {
+        let repo = InMemoryLoginAttemptRepository::new();
+
+        assert_eq!(
+            repo.find("ip:127.0.0.1").await.unwrap(),
+            LoginAttempts::default()
+        );
+        assert_eq!(
+            repo.record_failure("ip:127.0.0.1", Duration::minutes(15))
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            repo.record_failure("ip:127.0.0.1", Duration::minutes(15))
+                .await
+                .unwrap(),
+            2
+        );
+        // A zero-length window forgets every earlier failure
+        assert_eq!(
+            repo.record_failure("ip:127.0.0.1", Duration::zero())
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lock_and_clear() {
+        let repo = InMemoryLoginAttemptRepository::new();
+        let until = Utc::now() + Duration::minutes(1);
+
+        repo.record_failure("account:a@example.com", Duration::minutes(15))
+            .await
+            .unwrap();
+        repo.lock("account:a@example.com", until).await.unwrap();
+        assert!(
+            repo.find("account:a@example.com")
+                .await
+                .unwrap()
+                .is_locked(Utc::now())
+        );
+        assert_eq!(
+            repo.purge_stale(Utc::now() + Duration::hours(1))
+                .await
+                .unwrap(),
+            0
+        );
+
+        repo.clear("account:a@example.com").await.unwrap();
+        assert_eq!(
+            repo.find("account:a@example.com").await.unwrap(),
+            LoginAttempts::default()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+// crates/infrastructure/src/persistence/in_memory_revoked_token_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::users::RevokedTokenRepository;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Process-local revocation list for tests and single-instance development setups
+#[derive(Clone, Default)]
+pub struct InMemoryRevokedTokenRepository {
+    entries: Arc<Mutex<HashMap<Uuid, DateTime<Utc>>>>,
+    user_cutoffs: Arc<Mutex<HashMap<Uuid, DateTime<Utc>>>>,
+    sessions: Arc<Mutex<HashMap<Uuid, DateTime<Utc>>>>,
+}
+
+impl InMemoryRevokedTokenRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for InMemoryRevokedTokenRepository {
+    async fn revoke(&self, token_id: Uuid, expires_at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.entries.lock().unwrap().insert(token_id, expires_at);
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        issued_before: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut cutoffs = self.user_cutoffs.lock().unwrap();
+        let cutoff = cutoffs.entry(user_id).or_insert(issued_before);
+        *cutoff = (*cutoff).max(issued_before);
+        Ok(())
+    }
+
+    async fn revoke_session(
+        &self,
+        session_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let entry = sessions.entry(session_id).or_insert(expires_at);
+        *entry = (*entry).max(expires_at);
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        token_id: Uuid,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+        issued_at: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        let revoked = self
+            .entries
+            .lock()
+            .unwrap()
+            .get(&token_id)
+            .is_some_and(|expires_at| *expires_at > Utc::now());
+        let cut_off = self
+            .user_cutoffs
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .is_some_and(|revoked_before| *revoked_before > issued_at);
+        let session_revoked = session_id.is_some_and(|session_id| {
+            self.sessions
+                .lock()
+                .unwrap()
+                .get(&session_id)
+                .is_some_and(|expires_at| *expires_at > Utc::now())
+        });
+        Ok(revoked || cut_off || session_revoked)
+    }
+
+    async fn purge_expired(&self) -> Result<u64, DomainError> {
+        let now = Utc::now();
+        let mut purged = 0;
+        for list in [&self.entries, &self.sessions] {
+            let mut entries = list.lock().unwrap();
+            let before = entries.len();
+            entries.retain(|_, expires_at| *expires_at > now);
+            purged += (before - entries.len()) as u64;
+        }
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::{JwtService, JwtSigningKey};
+    use chrono::{Duration, SubsecRound};
+    use domain::users::{Email, PasswordHash, User};
+
+    // Checks a signed access token the way the services' auth middleware does
+    async fn accepts(repo: &InMemoryRevokedTokenRepository, jwt: &JwtService, token: &str) -> bool {
+        let claims = jwt.verify_token(token).unwrap();
+        let session_id = claims
+            .sid
+            .as_deref()
+            .map(|sid| Uuid::parse_str(sid).unwrap());
+        !repo
+            .is_revoked(
+                Uuid::parse_str(&claims.jti).unwrap(),
+                Uuid::parse_str(&claims.sub).unwrap(),
+                session_id,
+                DateTime::from_timestamp(claims.iat, 0).unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_revoke_and_check() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let token_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let issued_at = Utc::now();
+
+        assert!(
+            !repo
+                .is_revoked(token_id, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+        repo.revoke(token_id, Utc::now() + Duration::minutes(15))
+            .await
+            .unwrap();
+        assert!(
+            repo.is_revoked(token_id, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_for_user() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let user_id = Uuid::new_v4();
+        let cutoff = Utc::now();
+
+        repo.revoke_all_for_user(user_id, cutoff).await.unwrap();
+
+        let old = cutoff - Duration::minutes(1);
+        let new = cutoff + Duration::seconds(1);
+        assert!(
+            repo.is_revoked(Uuid::new_v4(), user_id, None, old)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !repo
+                .is_revoked(Uuid::new_v4(), user_id, None, new)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !repo
+                .is_revoked(Uuid::new_v4(), Uuid::new_v4(), None, old)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let user_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let issued_at = Utc::now();
+
+        repo.revoke_session(session_id, Utc::now() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        let token_id = Uuid::new_v4();
+        assert!(
+            repo.is_revoked(token_id, user_id, Some(session_id), issued_at)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !repo
+                .is_revoked(token_id, user_id, Some(Uuid::new_v4()), issued_at)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !repo
+                .is_revoked(token_id, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let live = Uuid::new_v4();
+        let expired = Uuid::new_v4();
+
+        repo.revoke(live, Utc::now() + Duration::minutes(15))
+            .await
+            .unwrap();
+        repo.revoke(expired, Utc::now() - Duration::minutes(1))
+            .await
+            .unwrap();
+
+        let user_id = Uuid::new_v4();
+        let issued_at = Utc::now();
+        assert!(
+            !repo
+                .is_revoked(expired, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+        assert_eq!(repo.purge_expired().await.unwrap(), 1);
+        assert!(
+            repo.is_revoked(live, user_id, None, issued_at)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_tokens_after_logout_and_rotation() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let jwt = JwtService::new(JwtSigningKey::generate().unwrap(), Vec::new(), 15);
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        );
+        let session_id = Uuid::new_v4();
+        let (first, expires_at) = jwt.generate_token(&user, session_id).unwrap();
+        // Refreshing hands out a new access token in the same session
+        let (rotated, _) = jwt.generate_token(&user, session_id).unwrap();
+
+        // Logging out revokes only the presented token
+        let jti = jwt.verify_token(&first).unwrap().jti;
+        repo.revoke(Uuid::parse_str(&jti).unwrap(), expires_at)
+            .await
+            .unwrap();
+        assert!(!accepts(&repo, &jwt, &first).await);
+        assert!(accepts(&repo, &jwt, &rotated).await);
+
+        // Revoking the session takes the rotated token with it
+        repo.revoke_session(session_id, expires_at).await.unwrap();
+        assert!(!accepts(&repo, &jwt, &rotated).await);
+    }
+
+    #[tokio::test]
+    async fn test_signed_tokens_after_user_cutoff() {
+        let repo = InMemoryRevokedTokenRepository::new();
+        let jwt = JwtService::new(JwtSigningKey::generate().unwrap(), Vec::new(), 15);
+        let user = User::new(
+            Email::new("test@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        );
+        let other = User::new(
+            Email::new("other@example.com").unwrap(),
+            PasswordHash::new("hash".to_string()),
+        );
+        let (token, _) = jwt.generate_token(&user, Uuid::new_v4()).unwrap();
+        let (other_token, _) = jwt.generate_token(&other, Uuid::new_v4()).unwrap();
+
+        // Tokens carry whole seconds, so cutoffs are too; one a second from now covers
+        // every token issued so far
+        let cutoff = Utc::now().trunc_subsecs(0) + Duration::seconds(1);
+        repo.revoke_all_for_user(user.id, cutoff).await.unwrap();
+
+        assert!(!accepts(&repo, &jwt, &token).await);
+        assert!(accepts(&repo, &jwt, &other_token).await);
+    }
+}
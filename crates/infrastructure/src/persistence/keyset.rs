@@ -0,0 +1,78 @@
+// crates/infrastructure/src/persistence/keyset.rs
+
+// Paging through listings ordered by (created_at, id)
+
+use chrono::{DateTime, Utc};
+use domain::common::pagination::{PageKey, PagePosition, PageRequest};
+use sqlx::postgres::{PgArguments, Postgres};
+use sqlx::query::QueryAs;
+use uuid::Uuid;
+
+pub(super) struct Keyset {
+    cursor: Option<PageKey>,
+    limit: i64,
+    offset: i64,
+    // Rows before a cursor are read against the listing's order, nearest first, and
+    // flipped back afterwards
+    backwards: bool,
+    newest_first: bool,
+}
+
+impl Keyset {
+    pub fn new(page: &PageRequest, newest_first: bool) -> Self {
+        let (cursor, offset, backwards) = match page.position() {
+            PagePosition::Offset(offset) => (None, offset, false),
+            PagePosition::After(key) => (Some(key), 0, false),
+            PagePosition::Before(key) => (Some(key), 0, true),
+        };
+        Self {
+            cursor,
+            limit: page.limit(),
+            offset,
+            backwards,
+            newest_first,
+        }
+    }
+
+    // The cursor condition, ordering and limit ending a query over `created_at` and `id`
+    // columns, taking four parameters from `$first`
+    pub fn clause(&self, first: usize) -> String {
+        // Reading towards older rows whether the listing is newest first and read forwards,
+        // or oldest first and read backwards
+        let descending = self.newest_first != self.backwards;
+        let (op, dir) = if descending {
+            ("<", "DESC")
+        } else {
+            (">", "ASC")
+        };
+        format!(
+            "AND (${0}::timestamptz IS NULL OR (created_at, id) {op} (${0}::timestamptz, ${1}::uuid))
+            ORDER BY created_at {dir}, id {dir}
+            LIMIT ${2} OFFSET ${3}",
+            first,
+            first + 1,
+            first + 2,
+            first + 3,
+        )
+    }
+
+    // Binds the parameters of `clause`, after the query's own
+    pub fn bind<'q, O>(
+        &self,
+        query: QueryAs<'q, Postgres, O, PgArguments>,
+    ) -> QueryAs<'q, Postgres, O, PgArguments> {
+        query
+            .bind(self.cursor.map(|key| key.created_at) as Option<DateTime<Utc>>)
+            .bind(self.cursor.map(|key| key.id) as Option<Uuid>)
+            .bind(self.limit)
+            .bind(self.offset)
+    }
+
+    // Puts the rows read back into the listing's order
+    pub fn finish<T>(&self, mut rows: Vec<T>) -> Vec<T> {
+        if self.backwards {
+            rows.reverse();
+        }
+        rows
+    }
+}
@@ -1,9 +1,36 @@
 // crates/infrastructure/src/persistence/mod.rs
 
+mod in_memory_login_attempt_repository;
+mod in_memory_revoked_token_repository;
+mod keyset;
+mod postgres_api_key_repository;
 mod postgres_comment_repository;
+mod postgres_email_verification_token_repository;
+mod postgres_login_attempt_repository;
+mod postgres_mfa_challenge_repository;
+mod postgres_oidc_authorization_repository;
+mod postgres_password_reset_token_repository;
 mod postgres_post_repository;
+mod postgres_refresh_token_repository;
+mod postgres_revoked_token_repository;
+mod postgres_session_repository;
+mod postgres_totp_credential_repository;
+mod postgres_user_identity_repository;
 mod postgres_user_repository;
 
+pub use in_memory_login_attempt_repository::InMemoryLoginAttemptRepository;
+pub use in_memory_revoked_token_repository::InMemoryRevokedTokenRepository;
+pub use postgres_api_key_repository::PostgresApiKeyRepository;
 pub use postgres_comment_repository::PostgresCommentRepository;
+pub use postgres_email_verification_token_repository::PostgresEmailVerificationTokenRepository;
+pub use postgres_login_attempt_repository::PostgresLoginAttemptRepository;
+pub use postgres_mfa_challenge_repository::PostgresMfaChallengeRepository;
+pub use postgres_oidc_authorization_repository::PostgresOidcAuthorizationRepository;
+pub use postgres_password_reset_token_repository::PostgresPasswordResetTokenRepository;
 pub use postgres_post_repository::PostgresPostRepository;
+pub use postgres_refresh_token_repository::PostgresRefreshTokenRepository;
+pub use postgres_revoked_token_repository::PostgresRevokedTokenRepository;
+pub use postgres_session_repository::PostgresSessionRepository;
+pub use postgres_totp_credential_repository::PostgresTotpCredentialRepository;
+pub use postgres_user_identity_repository::PostgresUserIdentityRepository;
 pub use postgres_user_repository::PostgresUserRepository;
@@ -0,0 +1,161 @@
+// crates/infrastructure/src/persistence/postgres_api_key_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::users::{ApiKey, ApiKeyRepository, ApiKeyScope};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+type ApiKeyRow = (
+    Uuid,
+    Uuid,
+    String,
+    String,
+    String,
+    Vec<String>,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+);
+
+#[derive(Clone)]
+pub struct PostgresApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_api_key(row: ApiKeyRow) -> Result<ApiKey, DomainError> {
+        let (
+            id,
+            user_id,
+            name,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at,
+            created_at,
+            revoked_at,
+        ) = row;
+        let scopes = scopes
+            .iter()
+            .map(|scope| ApiKeyScope::parse(scope))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::InfraError(format!("Invalid scope in DB: {}", e)))?;
+
+        Ok(ApiKey {
+            id,
+            user_id,
+            name,
+            prefix,
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at,
+            created_at,
+            revoked_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn save(&self, key: ApiKey) -> Result<(), DomainError> {
+        let scopes: Vec<&str> = key.scopes.iter().map(|scope| scope.as_str()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, created_at, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(key.id)
+        .bind(key.user_id)
+        .bind(&key.name)
+        .bind(&key.prefix)
+        .bind(&key.key_hash)
+        .bind(&scopes)
+        .bind(key.expires_at)
+        .bind(key.last_used_at)
+        .bind(key.created_at)
+        .bind(key.revoked_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>, DomainError> {
+        let result: Option<ApiKeyRow> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, created_at, revoked_at
+            FROM api_keys
+            WHERE prefix = $1
+            "#,
+        )
+        .bind(prefix)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        result.map(Self::row_to_api_key).transpose()
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, DomainError> {
+        let rows: Vec<ApiKeyRow> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, name, prefix, key_hash, scopes, expires_at, last_used_at, created_at, revoked_at
+            FROM api_keys
+            WHERE user_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        rows.into_iter().map(Self::row_to_api_key).collect()
+    }
+
+    async fn revoke(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET revoked_at = NOW()
+            WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET last_used_at = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
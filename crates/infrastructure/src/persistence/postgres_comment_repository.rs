@@ -3,9 +3,21 @@
 use async_trait::async_trait;
 use domain::comments::{Comment, CommentContent, CommentRepository};
 use domain::common::error::DomainError;
+use domain::common::pagination::PageRequest;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use super::keyset::Keyset;
+
+type CommentRow = (
+    Uuid,
+    String,
+    Uuid,
+    Uuid,
+    Option<Uuid>,
+    chrono::DateTime<chrono::Utc>,
+);
+
 #[derive(Clone)]
 pub struct PostgresCommentRepository {
     pool: PgPool,
@@ -15,6 +27,21 @@ impl PostgresCommentRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    fn row_to_comment(row: CommentRow) -> Result<Comment, DomainError> {
+        let (id, content, post_id, author_id, parent_id, created_at) = row;
+        let content = CommentContent::new(content)
+            .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
+
+        Ok(Comment {
+            id,
+            content,
+            post_id,
+            author_id,
+            parent_id,
+            created_at,
+        })
+    }
 }
 
 #[async_trait]
@@ -22,14 +49,15 @@ impl CommentRepository for PostgresCommentRepository {
     async fn save(&self, comment: Comment) -> Result<(), DomainError> {
         sqlx::query(
             r#"
-            INSERT INTO comments (id, content, post_id, author_id, created_at)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO comments (id, content, post_id, author_id, parent_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
             "#,
         )
         .bind(comment.id)
         .bind(comment.content.as_str())
         .bind(comment.post_id)
         .bind(comment.author_id)
+        .bind(comment.parent_id)
         .bind(comment.created_at)
         .execute(&self.pool)
         .await
@@ -38,16 +66,26 @@ impl CommentRepository for PostgresCommentRepository {
         Ok(())
     }
 
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        // Replies are removed by the parent_id foreign key cascade
+        sqlx::query(
+            r#"
+            DELETE FROM comments
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, DomainError> {
-        let result: Option<(
-            Uuid,
-            String,
-            Uuid,
-            Uuid,
-            chrono::DateTime<chrono::Utc>,
-        )> = sqlx::query_as(
+        let result: Option<CommentRow> = sqlx::query_as(
             r#"
-            SELECT id, content, post_id, author_id, created_at
+            SELECT id, content, post_id, author_id, parent_id, created_at
             FROM comments
             WHERE id = $1
             "#,
@@ -57,60 +95,134 @@ impl CommentRepository for PostgresCommentRepository {
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
-        match result {
-            Some((id, content, post_id, author_id, created_at)) => {
-                let content = CommentContent::new(content)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
-
-                Ok(Some(Comment {
-                    id,
-                    content,
-                    post_id,
-                    author_id,
-                    created_at,
-                }))
-            }
-            None => Ok(None),
-        }
+        result.map(Self::row_to_comment).transpose()
     }
 
-    async fn find_by_post(&self, post_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Comment>, DomainError> {
-        let results: Vec<(
-            Uuid,
-            String,
-            Uuid,
-            Uuid,
-            chrono::DateTime<chrono::Utc>,
-        )> = sqlx::query_as(
+    async fn find_by_post(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let keyset = Keyset::new(page, false);
+
+        let sql = format!(
             r#"
-            SELECT id, content, post_id, author_id, created_at
+            SELECT id, content, post_id, author_id, parent_id, created_at
+            FROM comments
+            WHERE post_id = $1
+            {}
+            "#,
+            keyset.clause(2)
+        );
+        let results: Vec<CommentRow> = keyset
+            .bind(sqlx::query_as(&sql).bind(post_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        keyset
+            .finish(results)
+            .into_iter()
+            .map(Self::row_to_comment)
+            .collect()
+    }
+
+    async fn count_by_post(&self, post_id: Uuid) -> Result<i64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
             FROM comments
             WHERE post_id = $1
-            ORDER BY created_at ASC
-            LIMIT $2 OFFSET $3
             "#,
         )
         .bind(post_id)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
+        .fetch_one(&self.pool)
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
-        results
+        Ok(count)
+    }
+
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let keyset = Keyset::new(page, true);
+
+        let sql = format!(
+            r#"
+            SELECT id, content, post_id, author_id, parent_id, created_at
+            FROM comments
+            WHERE author_id = $1
+            {}
+            "#,
+            keyset.clause(2)
+        );
+        let results: Vec<CommentRow> = keyset
+            .bind(sqlx::query_as(&sql).bind(author_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        keyset
+            .finish(results)
             .into_iter()
-            .map(|(id, content, post_id, author_id, created_at)| {
-                let content = CommentContent::new(content)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
-
-                Ok(Comment {
-                    id,
-                    content,
-                    post_id,
-                    author_id,
-                    created_at,
-                })
-            })
+            .map(Self::row_to_comment)
             .collect()
     }
+
+    async fn find_thread(
+        &self,
+        post_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Comment>, DomainError> {
+        let keyset = Keyset::new(page, false);
+
+        // The whole thread comes back oldest first, so rows read backwards from a cursor
+        // need no flipping
+        let sql = format!(
+            r#"
+            WITH RECURSIVE thread AS (
+                (
+                    SELECT id, content, post_id, author_id, parent_id, created_at
+                    FROM comments
+                    WHERE post_id = $1 AND parent_id IS NULL
+                    {}
+                )
+                UNION ALL
+                SELECT c.id, c.content, c.post_id, c.author_id, c.parent_id, c.created_at
+                FROM comments c
+                JOIN thread t ON c.parent_id = t.id
+            )
+            SELECT id, content, post_id, author_id, parent_id, created_at
+            FROM thread
+            ORDER BY created_at ASC, id ASC
+            "#,
+            keyset.clause(2)
+        );
+        let results: Vec<CommentRow> = keyset
+            .bind(sqlx::query_as(&sql).bind(post_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        results.into_iter().map(Self::row_to_comment).collect()
+    }
+
+    async fn count_threads(&self, post_id: Uuid) -> Result<i64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM comments
+            WHERE post_id = $1 AND parent_id IS NULL
+            "#,
+        )
+        .bind(post_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(count)
+    }
 }
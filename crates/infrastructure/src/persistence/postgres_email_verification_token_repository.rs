@@ -0,0 +1,108 @@
+// crates/infrastructure/src/persistence/postgres_email_verification_token_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{EmailVerificationToken, EmailVerificationTokenRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresEmailVerificationTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresEmailVerificationTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepository for PostgresEmailVerificationTokenRepository {
+    async fn save(&self, token: EmailVerificationToken) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO email_verification_tokens (id, user_id, token_hash, expires_at, created_at, used_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(token.id)
+        .bind(token.user_id)
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .bind(token.created_at)
+        .bind(token.used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailVerificationToken>, DomainError> {
+        let result: Option<(
+            Uuid,
+            Uuid,
+            String,
+            chrono::DateTime<chrono::Utc>,
+            chrono::DateTime<chrono::Utc>,
+            Option<chrono::DateTime<chrono::Utc>>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, created_at, used_at
+            FROM email_verification_tokens
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(
+            |(id, user_id, token_hash, expires_at, created_at, used_at)| EmailVerificationToken {
+                id,
+                user_id,
+                token_hash,
+                expires_at,
+                created_at,
+                used_at,
+            },
+        ))
+    }
+
+    async fn consume(&self, id: Uuid) -> Result<bool, DomainError> {
+        // Conditional update so that two concurrent redemptions cannot both succeed
+        let result = sqlx::query(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = NOW()
+            WHERE id = $1 AND used_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete_for_user(&self, user_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            DELETE FROM email_verification_tokens
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// crates/infrastructure/src/persistence/postgres_login_attempt_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::common::error::DomainError;
+use domain::users::{LoginAttemptRepository, LoginAttempts};
+use sqlx::PgPool;
+
+#[derive(Clone)]
+pub struct PostgresLoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn find(&self, key: &str) -> Result<LoginAttempts, DomainError> {
+        let result: Option<(i32, Option<DateTime<Utc>>)> = sqlx::query_as(
+            r#"
+            SELECT failures, locked_until
+            FROM login_attempts
+            WHERE key = $1
+            "#,
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result
+            .map(|(failures, locked_until)| LoginAttempts {
+                failures: failures.max(0) as u32,
+                locked_until,
+            })
+            .unwrap_or_default())
+    }
+
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, DomainError> {
+        let (failures,): (i32,) = sqlx::query_as(
+            r#"
+            INSERT INTO login_attempts (key, failures, last_failure_at)
+            VALUES ($1, 1, NOW())
+            ON CONFLICT (key) DO UPDATE
+            SET failures = CASE
+                    WHEN GREATEST(login_attempts.last_failure_at, login_attempts.locked_until)
+                         < NOW() - make_interval(secs => $2)
+                    THEN 1
+                    ELSE login_attempts.failures + 1
+                END,
+                last_failure_at = NOW()
+            RETURNING failures
+            "#,
+        )
+        .bind(key)
+        .bind(window.num_seconds() as f64)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(failures.max(0) as u32)
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE login_attempts
+            SET locked_until = GREATEST(locked_until, $2)
+            WHERE key = $1
+            "#,
+        )
+        .bind(key)
+        .bind(until)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            DELETE FROM login_attempts
+            WHERE key = $1
+            "#,
+        )
+        .bind(key)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn purge_stale(&self, before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM login_attempts
+            WHERE last_failure_at < $1
+              AND (locked_until IS NULL OR locked_until < NOW())
+            "#,
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
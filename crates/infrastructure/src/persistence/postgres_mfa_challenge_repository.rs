@@ -0,0 +1,111 @@
+// crates/infrastructure/src/persistence/postgres_mfa_challenge_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{MfaChallenge, MfaChallengeRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresMfaChallengeRepository {
+    pool: PgPool,
+}
+
+impl PostgresMfaChallengeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MfaChallengeRepository for PostgresMfaChallengeRepository {
+    async fn save(&self, challenge: MfaChallenge) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO mfa_challenges (id, user_id, token_hash, expires_at, failed_attempts, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(challenge.id)
+        .bind(challenge.user_id)
+        .bind(&challenge.token_hash)
+        .bind(challenge.expires_at)
+        .bind(challenge.failed_attempts as i32)
+        .bind(challenge.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<MfaChallenge>, DomainError> {
+        let result: Option<(
+            Uuid,
+            Uuid,
+            String,
+            chrono::DateTime<chrono::Utc>,
+            i32,
+            chrono::DateTime<chrono::Utc>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, failed_attempts, created_at
+            FROM mfa_challenges
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(
+            |(id, user_id, token_hash, expires_at, failed_attempts, created_at)| MfaChallenge {
+                id,
+                user_id,
+                token_hash,
+                expires_at,
+                failed_attempts: failed_attempts.max(0) as u32,
+                created_at,
+            },
+        ))
+    }
+
+    async fn record_failure(&self, id: Uuid) -> Result<u32, DomainError> {
+        let failed_attempts: Option<(i32,)> = sqlx::query_as(
+            r#"
+            UPDATE mfa_challenges
+            SET failed_attempts = failed_attempts + 1
+            WHERE id = $1
+            RETURNING failed_attempts
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        let (failed_attempts,) = failed_attempts
+            .ok_or_else(|| DomainError::NotFound("MFA challenge not found".into()))?;
+        Ok(failed_attempts.max(0) as u32)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, DomainError> {
+        let result = sqlx::query("DELETE FROM mfa_challenges WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn purge_expired(&self) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM mfa_challenges WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
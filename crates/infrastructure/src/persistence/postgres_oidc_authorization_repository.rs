@@ -0,0 +1,88 @@
+// crates/infrastructure/src/persistence/postgres_oidc_authorization_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{OidcAuthorization, OidcAuthorizationRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresOidcAuthorizationRepository {
+    pool: PgPool,
+}
+
+impl PostgresOidcAuthorizationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OidcAuthorizationRepository for PostgresOidcAuthorizationRepository {
+    async fn save(&self, authorization: OidcAuthorization) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO oidc_authorizations (id, state_hash, provider, nonce, code_verifier,
+                                             expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(authorization.id)
+        .bind(&authorization.state_hash)
+        .bind(&authorization.provider)
+        .bind(&authorization.nonce)
+        .bind(&authorization.code_verifier)
+        .bind(authorization.expires_at)
+        .bind(authorization.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn take(&self, state_hash: &str) -> Result<Option<OidcAuthorization>, DomainError> {
+        let result: Option<(
+            Uuid,
+            String,
+            String,
+            String,
+            String,
+            chrono::DateTime<chrono::Utc>,
+            chrono::DateTime<chrono::Utc>,
+        )> = sqlx::query_as(
+            r#"
+            DELETE FROM oidc_authorizations
+            WHERE state_hash = $1
+            RETURNING id, state_hash, provider, nonce, code_verifier, expires_at, created_at
+            "#,
+        )
+        .bind(state_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(
+            |(id, state_hash, provider, nonce, code_verifier, expires_at, created_at)| {
+                OidcAuthorization {
+                    id,
+                    state_hash,
+                    provider,
+                    nonce,
+                    code_verifier,
+                    expires_at,
+                    created_at,
+                }
+            },
+        ))
+    }
+
+    async fn purge_expired(&self) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM oidc_authorizations WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
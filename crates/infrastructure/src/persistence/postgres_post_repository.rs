@@ -1,11 +1,74 @@
 // crates/infrastructure/src/persistence/postgres_post_repository.rs
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::common::error::DomainError;
-use domain::posts::{Post, PostContent, PostRepository, PostTitle};
-use sqlx::PgPool;
+use domain::common::pagination::PageRequest;
+use domain::posts::{
+    Post, PostContent, PostRepository, PostRevision, PostSearchHit, PostSearchRepository, PostSlug,
+    PostStatus, PostTitle, SearchQuery, Tag, TagFilter, TagMatch,
+};
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
+use super::keyset::Keyset;
+
+type PostRow = (
+    Uuid,
+    String,
+    Option<String>,
+    Option<i32>,
+    String,
+    Uuid,
+    Vec<String>,
+    String,
+    Option<DateTime<Utc>>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+// A post row followed by its rank and title and content snippets
+type PostSearchRow = (
+    Uuid,
+    String,
+    Option<String>,
+    Option<i32>,
+    String,
+    Uuid,
+    Vec<String>,
+    String,
+    Option<DateTime<Utc>>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+    f32,
+    String,
+    String,
+);
+
+// Published posts passing a tag filter bound as $1 (tag slugs, none for no filter) and
+// $2 (whether a post needs all of them rather than one)
+const PUBLISHED_WITH_TAGS: &str = r#"
+    status = 'published'
+    AND (
+        cardinality($1::text[]) = 0
+        OR (
+            SELECT COUNT(*) FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+            WHERE pt.post_id = posts.id AND t.slug = ANY($1)
+        ) >= CASE WHEN $2 THEN cardinality($1::text[]) ELSE 1 END
+    )
+"#;
+
+type PostRevisionRow = (
+    Uuid,
+    Uuid,
+    i32,
+    String,
+    String,
+    Uuid,
+    Option<i32>,
+    DateTime<Utc>,
+);
+
 #[derive(Clone)]
 pub struct PostgresPostRepository {
     pool: PgPool,
@@ -15,23 +78,330 @@ impl PostgresPostRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Index and search in `language`, a Postgres text search configuration such as
+    /// "english" or "simple". Existing posts and comments are re-indexed when it differs
+    /// from the one in use; returns whether it did.
+    pub async fn set_search_language(&self, language: &str) -> Result<bool, DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        let changed = sqlx::query(
+            r#"
+            UPDATE search_settings
+            SET language = $1::regconfig
+            WHERE language <> $1::regconfig
+            "#,
+        )
+        .bind(language)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected()
+            > 0;
+
+        if changed {
+            sqlx::query("UPDATE posts SET search_vector = post_search_vector(title, content)")
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            sqlx::query("UPDATE comments SET search_vector = comment_search_vector(content)")
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+
+        tx.commit().await.map_err(db_error)?;
+        Ok(changed)
+    }
+
+    fn row_to_post(row: PostRow) -> Result<Post, DomainError> {
+        let (
+            id,
+            title,
+            slug,
+            slug_suffix,
+            content,
+            author_id,
+            tags,
+            status,
+            published_at,
+            created_at,
+            updated_at,
+        ) = row;
+        let title = PostTitle::new(title)
+            .map_err(|e| DomainError::InfraError(format!("Invalid title in DB: {}", e)))?;
+        // Posts from before slugs existed have none until the backfill reaches them
+        let slug = match slug {
+            Some(slug) => PostSlug::new(slug)
+                .map_err(|e| DomainError::InfraError(format!("Invalid slug in DB: {}", e)))?,
+            None => PostSlug::from_title(&title),
+        };
+        let slug_suffix = slug_suffix
+            .map(u32::try_from)
+            .transpose()
+            .map_err(|e| DomainError::InfraError(format!("Invalid slug suffix in DB: {}", e)))?;
+        let content = PostContent::new(content)
+            .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
+        let tags = tags
+            .into_iter()
+            .map(Tag::new)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::InfraError(format!("Invalid tag in DB: {}", e)))?;
+        let status = PostStatus::parse(&status)
+            .map_err(|e| DomainError::InfraError(format!("Invalid status in DB: {}", e)))?;
+
+        Ok(Post {
+            id,
+            title,
+            slug,
+            slug_suffix,
+            content,
+            author_id,
+            tags,
+            status,
+            published_at,
+            created_at,
+            updated_at,
+        })
+    }
+
+    // The binds of `PUBLISHED_WITH_TAGS`. Without a filter every post passes; with one, a
+    // post needs one or all of the tags.
+    fn tag_filter_binds(tags: Option<&TagFilter>) -> (Vec<&str>, bool) {
+        let slugs = tags
+            .map(|filter| filter.tags().iter().map(|tag| tag.as_str()).collect())
+            .unwrap_or_default();
+        let match_all = tags.is_some_and(|filter| filter.mode() == TagMatch::All);
+        (slugs, match_all)
+    }
+
+    fn row_to_revision(row: PostRevisionRow) -> Result<PostRevision, DomainError> {
+        let (id, post_id, number, title, content, editor_id, restored_from, created_at) = row;
+        let title = PostTitle::new(title)
+            .map_err(|e| DomainError::InfraError(format!("Invalid title in DB: {}", e)))?;
+        let content = PostContent::new(content)
+            .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
+
+        Ok(PostRevision {
+            id,
+            post_id,
+            number,
+            title,
+            content,
+            editor_id,
+            restored_from,
+            created_at,
+        })
+    }
+
+    // Two posts raced for the same slug
+    fn write_error(e: sqlx::Error) -> DomainError {
+        if let sqlx::Error::Database(db_err) = &e
+            && db_err.is_unique_violation()
+        {
+            return DomainError::AlreadyExists(
+                "A post with this slug already exists, try again".into(),
+            );
+        }
+        DomainError::InfraError(format!("Database error: {}", e))
+    }
+
+    // Point the post at exactly `tags`, creating tags that are new
+    async fn replace_tags(
+        conn: &mut PgConnection,
+        post_id: Uuid,
+        tags: &[Tag],
+    ) -> Result<(), sqlx::Error> {
+        let slugs: Vec<&str> = tags.iter().map(|tag| tag.as_str()).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO tags (slug)
+            SELECT UNNEST($1::text[])
+            ON CONFLICT (slug) DO NOTHING
+            "#,
+        )
+        .bind(&slugs)
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query("DELETE FROM post_tags WHERE post_id = $1")
+            .bind(post_id)
+            .execute(&mut *conn)
+            .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO post_tags (post_id, tag_id)
+            SELECT $1, id FROM tags WHERE slug = ANY($2)
+            "#,
+        )
+        .bind(post_id)
+        .bind(&slugs)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    // The statements behind `update`, run on the caller's transaction
+    async fn write_update(conn: &mut PgConnection, post: &Post) -> Result<(), DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+
+        // Keep the old slug pointing at the post; a post taking back one of its old slugs
+        // takes it out of the history
+        sqlx::query(
+            r#"
+            INSERT INTO post_slug_history (slug, post_id)
+            SELECT slug, id FROM posts WHERE id = $1 AND slug <> $2
+            ON CONFLICT (slug) DO UPDATE SET post_id = EXCLUDED.post_id, retired_at = NOW()
+            "#,
+        )
+        .bind(post.id)
+        .bind(post.slug.as_str())
+        .execute(&mut *conn)
+        .await
+        .map_err(db_error)?;
+
+        sqlx::query("DELETE FROM post_slug_history WHERE slug = $1")
+            .bind(post.slug.as_str())
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+
+        sqlx::query(
+            r#"
+            UPDATE posts
+            SET title = $2, slug = $3, slug_suffix = $4, content = $5, status = $6,
+                published_at = $7, updated_at = $8
+            WHERE id = $1
+            "#,
+        )
+        .bind(post.id)
+        .bind(post.title.as_str())
+        .bind(post.slug.as_str())
+        .bind(post.slug_suffix.map(|n| n as i32))
+        .bind(post.content.as_str())
+        .bind(post.status.as_str())
+        .bind(post.published_at)
+        .bind(post.updated_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(Self::write_error)?;
+
+        Self::replace_tags(conn, post.id, &post.tags)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    // Snapshots the post as the revision after its latest one. Callers update the post row
+    // first, so an edit racing on the same post waits on its row lock and numbers after this
+    // one; the unique (post_id, number) constraint catches anything else.
+    async fn insert_revision(
+        conn: &mut PgConnection,
+        post: &Post,
+        editor_id: Uuid,
+        restored_from: Option<i32>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO post_revisions (id, post_id, number, title, content, editor_id, restored_from, created_at)
+            SELECT $1, $2, COALESCE(MAX(number), 0) + 1, $3, $4, $5, $6, $7
+            FROM post_revisions
+            WHERE post_id = $2
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(post.id)
+        .bind(post.title.as_str())
+        .bind(post.content.as_str())
+        .bind(editor_id)
+        .bind(restored_from)
+        .bind(post.updated_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.is_unique_violation()
+            {
+                return DomainError::AlreadyExists("Post was edited concurrently, try again".into());
+            }
+            DomainError::InfraError(format!("Database error: {}", e))
+        })?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl PostRepository for PostgresPostRepository {
     async fn save(&self, post: Post) -> Result<(), DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
         sqlx::query(
             r#"
-            INSERT INTO posts (id, title, content, author_id, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO posts (id, title, slug, slug_suffix, content, author_id, status, published_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
         )
         .bind(post.id)
         .bind(post.title.as_str())
+        .bind(post.slug.as_str())
+        .bind(post.slug_suffix.map(|n| n as i32))
         .bind(post.content.as_str())
         .bind(post.author_id)
+        .bind(post.status.as_str())
+        .bind(post.published_at)
         .bind(post.created_at)
         .bind(post.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(Self::write_error)?;
+
+        Self::replace_tags(&mut tx, post.id, &post.tags)
+            .await
+            .map_err(db_error)?;
+        Self::insert_revision(&mut tx, &post, post.author_id, None).await?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn update(&self, post: Post) -> Result<(), DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        Self::write_update(&mut tx, &post).await?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn update_with_revision(
+        &self,
+        post: Post,
+        editor_id: Uuid,
+        restored_from: Option<i32>,
+    ) -> Result<(), DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        Self::write_update(&mut tx, &post).await?;
+        Self::insert_revision(&mut tx, &post, editor_id, restored_from).await?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            DELETE FROM posts
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
@@ -40,16 +410,14 @@ impl PostRepository for PostgresPostRepository {
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, DomainError> {
-        let result: Option<(
-            Uuid,
-            String,
-            String,
-            Uuid,
-            chrono::DateTime<chrono::Utc>,
-            chrono::DateTime<chrono::Utc>,
-        )> = sqlx::query_as(
-            r#"
-            SELECT id, title, content, author_id, created_at, updated_at
+        let result: Option<PostRow> = sqlx::query_as(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
             FROM posts
             WHERE id = $1
             "#,
@@ -59,109 +427,469 @@ impl PostRepository for PostgresPostRepository {
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
-        match result {
-            Some((id, title, content, author_id, created_at, updated_at)) => {
-                let title = PostTitle::new(title)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid title in DB: {}", e)))?;
-                let content = PostContent::new(content)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
+        result.map(Self::row_to_post).transpose()
+    }
 
-                Ok(Some(Post {
-                    id,
-                    title,
-                    content,
-                    author_id,
-                    created_at,
-                    updated_at,
-                }))
-            }
-            None => Ok(None),
-        }
+    async fn find_by_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError> {
+        let result: Option<PostRow> = sqlx::query_as(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
+            FROM posts
+            WHERE slug = $1
+            "#,
+        )
+        .bind(slug.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        result.map(Self::row_to_post).transpose()
     }
 
-    async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Post>, DomainError> {
-        let results: Vec<(
-            Uuid,
-            String,
-            String,
-            Uuid,
-            chrono::DateTime<chrono::Utc>,
-            chrono::DateTime<chrono::Utc>,
-        )> = sqlx::query_as(
+    async fn find_by_previous_slug(&self, slug: &PostSlug) -> Result<Option<Post>, DomainError> {
+        let result: Option<PostRow> = sqlx::query_as(
             r#"
-            SELECT id, title, content, author_id, created_at, updated_at
+            SELECT id, title, posts.slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
             FROM posts
-            ORDER BY created_at DESC
-            LIMIT $1 OFFSET $2
+            JOIN post_slug_history h ON h.post_id = posts.id
+            WHERE h.slug = $1
             "#,
         )
-        .bind(limit)
-        .bind(offset)
+        .bind(slug.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        result.map(Self::row_to_post).transpose()
+    }
+
+    async fn find_taken_slugs(
+        &self,
+        base: &PostSlug,
+        except_post: Option<Uuid>,
+    ) -> Result<Vec<PostSlug>, DomainError> {
+        // Slugs only hold letters, digits and hyphens, so the base needs no escaping
+        let results: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT slug FROM posts
+            WHERE (slug = $1 OR slug ~ ('^' || $1 || '-[0-9]+$'))
+              AND ($2::uuid IS NULL OR id <> $2)
+            UNION
+            SELECT slug FROM post_slug_history
+            WHERE (slug = $1 OR slug ~ ('^' || $1 || '-[0-9]+$'))
+              AND ($2::uuid IS NULL OR post_id <> $2)
+            "#,
+        )
+        .bind(base.as_str())
+        .bind(except_post)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
         results
             .into_iter()
-            .map(|(id, title, content, author_id, created_at, updated_at)| {
-                let title = PostTitle::new(title)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid title in DB: {}", e)))?;
-                let content = PostContent::new(content)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
-
-                Ok(Post {
-                    id,
-                    title,
-                    content,
-                    author_id,
-                    created_at,
-                    updated_at,
-                })
+            .map(|(slug,)| {
+                PostSlug::new(slug)
+                    .map_err(|e| DomainError::InfraError(format!("Invalid slug in DB: {}", e)))
             })
             .collect()
     }
 
-    async fn find_by_author(&self, author_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Post>, DomainError> {
-        let results: Vec<(
-            Uuid,
-            String,
-            String,
-            Uuid,
-            chrono::DateTime<chrono::Utc>,
-            chrono::DateTime<chrono::Utc>,
-        )> = sqlx::query_as(
+    async fn find_without_slug(&self, limit: i64) -> Result<Vec<Post>, DomainError> {
+        let results: Vec<PostRow> = sqlx::query_as(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
+            FROM posts
+            WHERE slug IS NULL
+            ORDER BY created_at, id
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        results.into_iter().map(Self::row_to_post).collect()
+    }
+
+    async fn backfill_slug(&self, post: &Post) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE posts
+            SET slug = $2, slug_suffix = $3
+            WHERE id = $1 AND slug IS NULL
+            "#,
+        )
+        .bind(post.id)
+        .bind(post.slug.as_str())
+        .bind(post.slug_suffix.map(|n| n as i32))
+        .execute(&self.pool)
+        .await
+        .map_err(Self::write_error)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_published(
+        &self,
+        tags: Option<&TagFilter>,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError> {
+        let (slugs, match_all) = Self::tag_filter_binds(tags);
+        let keyset = Keyset::new(page, true);
+
+        let sql = format!(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
+            FROM posts
+            WHERE {}
+            {}
+            "#,
+            PUBLISHED_WITH_TAGS,
+            keyset.clause(3)
+        );
+        let results: Vec<PostRow> = keyset
+            .bind(sqlx::query_as(&sql).bind(&slugs).bind(match_all))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        keyset
+            .finish(results)
+            .into_iter()
+            .map(Self::row_to_post)
+            .collect()
+    }
+
+    async fn find_by_author(
+        &self,
+        author_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Vec<Post>, DomainError> {
+        let keyset = Keyset::new(page, true);
+
+        let sql = format!(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
+            FROM posts
+            WHERE author_id = $1
+            {}
+            "#,
+            keyset.clause(2)
+        );
+        let results: Vec<PostRow> = keyset
+            .bind(sqlx::query_as(&sql).bind(author_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        keyset
+            .finish(results)
+            .into_iter()
+            .map(Self::row_to_post)
+            .collect()
+    }
+
+    async fn count_published(&self, tags: Option<&TagFilter>) -> Result<i64, DomainError> {
+        let (slugs, match_all) = Self::tag_filter_binds(tags);
+
+        let sql = format!("SELECT COUNT(*) FROM posts WHERE {}", PUBLISHED_WITH_TAGS);
+        let (count,): (i64,) = sqlx::query_as(&sql)
+            .bind(&slugs)
+            .bind(match_all)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(count)
+    }
+
+    async fn count_by_author(&self, author_id: Uuid) -> Result<i64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
             r#"
-            SELECT id, title, content, author_id, created_at, updated_at
+            SELECT COUNT(*)
             FROM posts
             WHERE author_id = $1
-            ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
             "#,
         )
         .bind(author_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(count)
+    }
+
+    async fn find_due_scheduled(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Post>, DomainError> {
+        let results: Vec<PostRow> = sqlx::query_as(
+            r#"
+            SELECT id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at
+            FROM posts
+            WHERE status = 'scheduled' AND published_at <= $1
+            ORDER BY published_at
+            LIMIT $2
+            "#,
+        )
+        .bind(now)
         .bind(limit)
-        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        results.into_iter().map(Self::row_to_post).collect()
+    }
+
+    async fn publish_if_due(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE posts
+            SET status = 'published', updated_at = $2
+            WHERE id = $1 AND status = 'scheduled' AND published_at <= $2
+            "#,
+        )
+        .bind(id)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn find_tag_counts(&self) -> Result<Vec<(Tag, i64)>, DomainError> {
+        let results: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT t.slug, COUNT(*)
+            FROM tags t
+            JOIN post_tags pt ON pt.tag_id = t.id
+            JOIN posts p ON p.id = pt.post_id
+            WHERE p.status = 'published'
+            GROUP BY t.slug
+            ORDER BY COUNT(*) DESC, t.slug
+            "#,
+        )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
         results
             .into_iter()
-            .map(|(id, title, content, author_id, created_at, updated_at)| {
-                let title = PostTitle::new(title)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid title in DB: {}", e)))?;
-                let content = PostContent::new(content)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid content in DB: {}", e)))?;
+            .map(|(slug, count)| {
+                let tag = Tag::new(slug)
+                    .map_err(|e| DomainError::InfraError(format!("Invalid tag in DB: {}", e)))?;
+                Ok((tag, count))
+            })
+            .collect()
+    }
+
+    async fn find_revisions(&self, post_id: Uuid) -> Result<Vec<PostRevision>, DomainError> {
+        let results: Vec<PostRevisionRow> = sqlx::query_as(
+            r#"
+            SELECT id, post_id, number, title, content, editor_id, restored_from, created_at
+            FROM post_revisions
+            WHERE post_id = $1
+            ORDER BY number
+            "#,
+        )
+        .bind(post_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        results.into_iter().map(Self::row_to_revision).collect()
+    }
+
+    async fn find_revision(
+        &self,
+        post_id: Uuid,
+        number: i32,
+    ) -> Result<Option<PostRevision>, DomainError> {
+        let result: Option<PostRevisionRow> = sqlx::query_as(
+            r#"
+            SELECT id, post_id, number, title, content, editor_id, restored_from, created_at
+            FROM post_revisions
+            WHERE post_id = $1 AND number = $2
+            "#,
+        )
+        .bind(post_id)
+        .bind(number)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        result.map(Self::row_to_revision).transpose()
+    }
+}
+
+#[async_trait]
+impl PostSearchRepository for PostgresPostRepository {
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PostSearchHit>, DomainError> {
+        // A post's rank adds up its own match and, when asked for, half of each matching
+        // comment's. Snippets are escaped before highlighting so only the marks are markup.
+        let results: Vec<PostSearchRow> = sqlx::query_as(
+            r#"
+            WITH q AS (
+                SELECT s.language, websearch_to_tsquery(s.language, $1) AS query
+                FROM search_settings s
+            ),
+            hits AS (
+                SELECT p.id, ts_rank(p.search_vector, q.query)::float8 AS rank
+                FROM posts p, q
+                WHERE p.status = 'published' AND p.search_vector @@ q.query
+                UNION ALL
+                SELECT c.post_id, 0.5 * ts_rank(c.search_vector, q.query)
+                FROM comments c, q
+                WHERE $2 AND c.search_vector @@ q.query
+            ),
+            ranked AS (
+                SELECT id, SUM(rank)::real AS rank FROM hits GROUP BY id
+            )
+            SELECT posts.id, title, slug, slug_suffix, content, author_id,
+                   ARRAY(
+                       SELECT t.slug FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                       WHERE pt.post_id = posts.id ORDER BY t.slug
+                   ),
+                   status, published_at, created_at, updated_at,
+                   ranked.rank,
+                   ts_headline(
+                       q.language,
+                       replace(replace(replace(title, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                       q.query,
+                       'HighlightAll=true, StartSel=<mark>, StopSel=</mark>'
+                   ),
+                   ts_headline(
+                       q.language,
+                       replace(replace(replace(content, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                       q.query,
+                       'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=35, '
+                       'MinWords=15, FragmentDelimiter=" ... "'
+                   )
+            FROM ranked
+            JOIN posts ON posts.id = ranked.id
+            CROSS JOIN q
+            WHERE posts.status = 'published'
+            ORDER BY ranked.rank DESC, posts.created_at DESC, posts.id
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(query.as_str())
+        .bind(query.include_comments())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
-                Ok(Post {
+        results
+            .into_iter()
+            .map(|row| {
+                let (
+                    id,
+                    title,
+                    slug,
+                    slug_suffix,
+                    content,
+                    author_id,
+                    tags,
+                    status,
+                    published_at,
+                    created_at,
+                    updated_at,
+                    rank,
+                    title_snippet,
+                    content_snippet,
+                ) = row;
+                let post = Self::row_to_post((
                     id,
                     title,
+                    slug,
+                    slug_suffix,
                     content,
                     author_id,
+                    tags,
+                    status,
+                    published_at,
                     created_at,
                     updated_at,
+                ))?;
+                Ok(PostSearchHit {
+                    post,
+                    rank,
+                    title_snippet,
+                    content_snippet,
                 })
             })
             .collect()
     }
+
+    async fn count(&self, query: &SearchQuery) -> Result<i64, DomainError> {
+        // The posts `search` ranks, without ranking them
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            WITH q AS (
+                SELECT websearch_to_tsquery(s.language, $1) AS query
+                FROM search_settings s
+            )
+            SELECT COUNT(*)
+            FROM (
+                SELECT p.id
+                FROM posts p, q
+                WHERE p.status = 'published' AND p.search_vector @@ q.query
+                UNION
+                SELECT p.id
+                FROM comments c
+                JOIN posts p ON p.id = c.post_id
+                CROSS JOIN q
+                WHERE $2 AND p.status = 'published' AND c.search_vector @@ q.query
+            ) hits
+            "#,
+        )
+        .bind(query.as_str())
+        .bind(query.include_comments())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(count)
+    }
 }
@@ -0,0 +1,127 @@
+// crates/infrastructure/src/persistence/postgres_refresh_token_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{RefreshToken, RefreshTokenRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresRefreshTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresRefreshTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for PostgresRefreshTokenRepository {
+    async fn save(&self, token: RefreshToken) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO refresh_tokens (id, user_id, family_id, token_hash, expires_at, created_at, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(token.id)
+        .bind(token.user_id)
+        .bind(token.family_id)
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .bind(token.created_at)
+        .bind(token.revoked_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, DomainError> {
+        let result: Option<(
+            Uuid,
+            Uuid,
+            Uuid,
+            String,
+            chrono::DateTime<chrono::Utc>,
+            chrono::DateTime<chrono::Utc>,
+            Option<chrono::DateTime<chrono::Utc>>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, family_id, token_hash, expires_at, created_at, revoked_at
+            FROM refresh_tokens
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(
+            |(id, user_id, family_id, token_hash, expires_at, created_at, revoked_at)| {
+                RefreshToken {
+                    id,
+                    user_id,
+                    family_id,
+                    token_hash,
+                    expires_at,
+                    created_at,
+                    revoked_at,
+                }
+            },
+        ))
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool, DomainError> {
+        // Conditional update so that two concurrent redemptions cannot both succeed
+        let result = sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE id = $1 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE family_id = $1 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(family_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = NOW()
+            WHERE user_id = $1 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// crates/infrastructure/src/persistence/postgres_revoked_token_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::users::RevokedTokenRepository;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresRevokedTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresRevokedTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for PostgresRevokedTokenRepository {
+    async fn revoke(&self, token_id: Uuid, expires_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_tokens (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+        )
+        .bind(token_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        issued_before: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_user_tokens (user_id, revoked_before)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET revoked_before = GREATEST(revoked_user_tokens.revoked_before, EXCLUDED.revoked_before)
+            "#,
+        )
+        .bind(user_id)
+        .bind(issued_before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn revoke_session(
+        &self,
+        session_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_sessions (session_id, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (session_id) DO UPDATE
+            SET expires_at = GREATEST(revoked_sessions.expires_at, EXCLUDED.expires_at)
+            "#,
+        )
+        .bind(session_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        token_id: Uuid,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+        issued_at: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        let (revoked,): (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM revoked_tokens
+                WHERE jti = $1 AND expires_at > NOW()
+            ) OR EXISTS (
+                SELECT 1 FROM revoked_user_tokens
+                WHERE user_id = $2 AND revoked_before > $3
+            ) OR EXISTS (
+                SELECT 1 FROM revoked_sessions
+                WHERE session_id = $4 AND expires_at > NOW()
+            )
+            "#,
+        )
+        .bind(token_id)
+        .bind(user_id)
+        .bind(issued_at)
+        .bind(session_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(revoked)
+    }
+
+    async fn purge_expired(&self) -> Result<u64, DomainError> {
+        let mut purged = 0;
+        for table in ["revoked_tokens", "revoked_sessions"] {
+            let result = sqlx::query(&format!("DELETE FROM {} WHERE expires_at <= NOW()", table))
+                .execute(&self.pool)
+                .await
+                .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+            purged += result.rows_affected();
+        }
+
+        Ok(purged)
+    }
+}
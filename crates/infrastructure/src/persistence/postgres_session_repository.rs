@@ -0,0 +1,118 @@
+// crates/infrastructure/src/persistence/postgres_session_repository.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::common::error::DomainError;
+use domain::users::{Session, SessionRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+type SessionRow = (
+    Uuid,
+    Uuid,
+    Option<String>,
+    Option<String>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+#[derive(Clone)]
+pub struct PostgresSessionRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_session(row: SessionRow) -> Session {
+        let (id, user_id, user_agent, ip_address, created_at, last_seen_at) = row;
+        Session {
+            id,
+            user_id,
+            user_agent,
+            ip_address,
+            created_at,
+            last_seen_at,
+        }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn save(&self, session: Session) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (id, user_id, user_agent, ip_address, created_at, last_seen_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(session.id)
+        .bind(session.user_id)
+        .bind(&session.user_agent)
+        .bind(&session.ip_address)
+        .bind(session.created_at)
+        .bind(session.last_seen_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_active_by_user(&self, user_id: Uuid) -> Result<Vec<Session>, DomainError> {
+        let rows: Vec<SessionRow> = sqlx::query_as(
+            r#"
+            SELECT s.id, s.user_id, s.user_agent, s.ip_address, s.created_at, s.last_seen_at
+            FROM sessions s
+            WHERE s.user_id = $1
+              AND EXISTS (
+                  SELECT 1 FROM refresh_tokens r
+                  WHERE r.family_id = s.id AND r.revoked_at IS NULL AND r.expires_at > NOW()
+              )
+            ORDER BY s.last_seen_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(rows.into_iter().map(Self::row_to_session).collect())
+    }
+
+    async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE sessions
+            SET last_seen_at = GREATEST(last_seen_at, $2)
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(seen_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn purge_inactive(&self) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM sessions s
+            WHERE NOT EXISTS (
+                SELECT 1 FROM refresh_tokens r
+                WHERE r.family_id = s.id AND r.revoked_at IS NULL AND r.expires_at > NOW()
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
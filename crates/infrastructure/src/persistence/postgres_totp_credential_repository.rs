@@ -0,0 +1,189 @@
+// crates/infrastructure/src/persistence/postgres_totp_credential_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{TotpCredential, TotpCredentialRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresTotpCredentialRepository {
+    pool: PgPool,
+}
+
+impl PostgresTotpCredentialRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TotpCredentialRepository for PostgresTotpCredentialRepository {
+    async fn save(&self, credential: TotpCredential) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO totp_credentials (user_id, secret, confirmed_at, last_used_step, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET secret = EXCLUDED.secret,
+                confirmed_at = EXCLUDED.confirmed_at,
+                last_used_step = EXCLUDED.last_used_step,
+                created_at = EXCLUDED.created_at
+            "#,
+        )
+        .bind(credential.user_id)
+        .bind(&credential.secret)
+        .bind(credential.confirmed_at)
+        .bind(credential.last_used_step)
+        .bind(credential.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<TotpCredential>, DomainError> {
+        let result: Option<(
+            Uuid,
+            String,
+            Option<chrono::DateTime<chrono::Utc>>,
+            Option<i64>,
+            chrono::DateTime<chrono::Utc>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT user_id, secret, confirmed_at, last_used_step, created_at
+            FROM totp_credentials
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(
+            |(user_id, secret, confirmed_at, last_used_step, created_at)| TotpCredential {
+                user_id,
+                secret,
+                confirmed_at,
+                last_used_step,
+                created_at,
+            },
+        ))
+    }
+
+    async fn confirm(
+        &self,
+        user_id: Uuid,
+        recovery_code_hashes: &[String],
+    ) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE totp_credentials
+            SET confirmed_at = NOW()
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound(
+                "No two-factor enrolment is pending".into(),
+            ));
+        }
+
+        sqlx::query("DELETE FROM totp_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO totp_recovery_codes (user_id, code_hash)
+            SELECT $1, UNNEST($2::TEXT[])
+            "#,
+        )
+        .bind(user_id)
+        .bind(recovery_code_hashes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn use_step(&self, user_id: Uuid, step: i64) -> Result<bool, DomainError> {
+        // Conditional update so that a code cannot be accepted twice, even concurrently
+        let result = sqlx::query(
+            r#"
+            UPDATE totp_credentials
+            SET last_used_step = $2
+            WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+        )
+        .bind(user_id)
+        .bind(step)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn use_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM totp_recovery_codes
+            WHERE user_id = $1 AND code_hash = $2
+            "#,
+        )
+        .bind(user_id)
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        sqlx::query("DELETE FROM totp_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        sqlx::query("DELETE FROM totp_credentials WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+}
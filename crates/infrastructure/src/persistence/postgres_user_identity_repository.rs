@@ -0,0 +1,109 @@
+// crates/infrastructure/src/persistence/postgres_user_identity_repository.rs
+
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use domain::users::{UserIdentity, UserIdentityRepository};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+type IdentityRow = (
+    Uuid,
+    Uuid,
+    String,
+    String,
+    Option<String>,
+    chrono::DateTime<chrono::Utc>,
+);
+
+#[derive(Clone)]
+pub struct PostgresUserIdentityRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserIdentityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_identity(row: IdentityRow) -> UserIdentity {
+        let (id, user_id, provider, subject, email, created_at) = row;
+        UserIdentity {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl UserIdentityRepository for PostgresUserIdentityRepository {
+    async fn save(&self, identity: UserIdentity) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_identities (id, user_id, provider, subject, email, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(identity.id)
+        .bind(identity.user_id)
+        .bind(&identity.provider)
+        .bind(&identity.subject)
+        .bind(&identity.email)
+        .bind(identity.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.is_unique_violation()
+            {
+                return DomainError::AlreadyExists(
+                    "This identity is already linked to an account".into(),
+                );
+            }
+            DomainError::InfraError(format!("Database error: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, DomainError> {
+        let result: Option<IdentityRow> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, provider, subject, email, created_at
+            FROM user_identities
+            WHERE provider = $1 AND subject = $2
+            "#,
+        )
+        .bind(provider)
+        .bind(subject)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(result.map(Self::row_to_identity))
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<UserIdentity>, DomainError> {
+        let rows: Vec<IdentityRow> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, provider, subject, email, created_at
+            FROM user_identities
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(rows.into_iter().map(Self::row_to_identity).collect())
+    }
+}
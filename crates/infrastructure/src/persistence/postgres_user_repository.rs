@@ -2,9 +2,24 @@
 
 use async_trait::async_trait;
 use domain::common::error::DomainError;
-use domain::users::{Email, PasswordHash, User, UserRepository};
+use domain::users::{
+    AvatarUrl, Bio, DisplayName, Email, PasswordHash, Profile, Role, User, UserRepository,
+};
 use sqlx::PgPool;
 
+type UserRow = (
+    uuid::Uuid,
+    String,
+    Option<String>,
+    String,
+    bool,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<chrono::DateTime<chrono::Utc>>,
+    chrono::DateTime<chrono::Utc>,
+);
+
 #[derive(Clone)]
 pub struct PostgresUserRepository {
     pool: PgPool,
@@ -14,6 +29,49 @@ impl PostgresUserRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    fn row_to_user(row: UserRow) -> Result<User, DomainError> {
+        let (
+            id,
+            email_str,
+            password_hash_str,
+            role_str,
+            email_verified,
+            display_name,
+            bio,
+            avatar_url,
+            deletion_scheduled_for,
+            created_at,
+        ) = row;
+        let email = Email::new(email_str)
+            .map_err(|e| DomainError::InfraError(format!("Invalid email in DB: {}", e)))?;
+        let role = Role::parse(&role_str)
+            .map_err(|e| DomainError::InfraError(format!("Invalid role in DB: {}", e)))?;
+        let invalid_profile =
+            |e: DomainError| DomainError::InfraError(format!("Invalid profile in DB: {}", e));
+        let profile = Profile {
+            display_name: display_name
+                .map(DisplayName::new)
+                .transpose()
+                .map_err(invalid_profile)?,
+            bio: bio.map(Bio::new).transpose().map_err(invalid_profile)?,
+            avatar_url: avatar_url
+                .map(AvatarUrl::new)
+                .transpose()
+                .map_err(invalid_profile)?,
+        };
+
+        Ok(User {
+            id,
+            email,
+            password_hash: password_hash_str.map(PasswordHash::new),
+            role,
+            email_verified,
+            profile,
+            deletion_scheduled_for,
+            created_at,
+        })
+    }
 }
 
 #[async_trait]
@@ -21,21 +79,28 @@ impl UserRepository for PostgresUserRepository {
     async fn save(&self, user: User) -> Result<(), DomainError> {
         sqlx::query(
             r#"
-            INSERT INTO users (id, email, password_hash, created_at)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO users (id, email, password_hash, role, email_verified, display_name, bio,
+                               avatar_url, deletion_scheduled_for, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
         )
         .bind(user.id)
         .bind(user.email.as_str())
-        .bind(user.password_hash.as_str())
+        .bind(user.password_hash.as_ref().map(|hash| hash.as_str()))
+        .bind(user.role.as_str())
+        .bind(user.email_verified)
+        .bind(user.profile.display_name.as_ref().map(|name| name.as_str()))
+        .bind(user.profile.bio.as_ref().map(|bio| bio.as_str()))
+        .bind(user.profile.avatar_url.as_ref().map(|url| url.as_str()))
+        .bind(user.deletion_scheduled_for)
         .bind(user.created_at)
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.is_unique_violation() {
-                    return DomainError::AlreadyExists("User with this email already exists".into());
-                }
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.is_unique_violation()
+            {
+                return DomainError::AlreadyExists("User with this email already exists".into());
             }
             DomainError::InfraError(format!("Database error: {}", e))
         })?;
@@ -43,12 +108,47 @@ impl UserRepository for PostgresUserRepository {
         Ok(())
     }
 
+    async fn find_by_id(&self, id: uuid::Uuid) -> Result<Option<User>, DomainError> {
+        let result: Option<UserRow> = sqlx::query_as(
+            r#"
+            SELECT id, email, password_hash, role, email_verified, display_name, bio, avatar_url,
+                   deletion_scheduled_for, created_at
+            FROM users
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        result.map(Self::row_to_user).transpose()
+    }
+
+    async fn find_by_ids(&self, ids: &[uuid::Uuid]) -> Result<Vec<User>, DomainError> {
+        let rows: Vec<UserRow> = sqlx::query_as(
+            r#"
+            SELECT id, email, password_hash, role, email_verified, display_name, bio, avatar_url,
+                   deletion_scheduled_for, created_at
+            FROM users
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        rows.into_iter().map(Self::row_to_user).collect()
+    }
+
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, DomainError> {
-        let result: Option<(uuid::Uuid, String, String, chrono::DateTime<chrono::Utc>)> = sqlx::query_as(
+        let result: Option<UserRow> = sqlx::query_as(
             r#"
-            SELECT id, email, password_hash, created_at
+            SELECT id, email, password_hash, role, email_verified, display_name, bio, avatar_url,
+                   deletion_scheduled_for, created_at
             FROM users
-            WHERE email = $1
+            WHERE email = $1 AND deleted_at IS NULL
             "#,
         )
         .bind(email.as_str())
@@ -56,22 +156,277 @@ impl UserRepository for PostgresUserRepository {
         .await
         .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
-        match result {
-            Some((id, email_str, password_hash_str, created_at)) => {
-                let email = Email::new(email_str)
-                    .map_err(|e| DomainError::InfraError(format!("Invalid email in DB: {}", e)))?;
-                let password_hash = PasswordHash::new(password_hash_str);
-
-                Ok(Some(User {
-                    id,
-                    email,
-                    password_hash,
-                    created_at,
-                }))
+        result.map(Self::row_to_user).transpose()
+    }
+
+    async fn update_role(&self, id: uuid::Uuid, role: Role) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET role = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(role.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn count_by_role(&self, role: Role) -> Result<i64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM users
+            WHERE role = $1
+            "#,
+        )
+        .bind(role.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        Ok(count)
+    }
+
+    async fn mark_email_verified(&self, id: uuid::Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET email_verified = TRUE
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn update_password(
+        &self,
+        id: uuid::Uuid,
+        password_hash: PasswordHash,
+    ) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET password_hash = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(password_hash.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn update_email(&self, id: uuid::Uuid, email: &Email) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET email = $2, email_verified = FALSE
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(email.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.is_unique_violation()
+            {
+                return DomainError::AlreadyExists("User with this email already exists".into());
             }
-            None => Ok(None),
+            DomainError::InfraError(format!("Database error: {}", e))
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
         }
+
+        Ok(())
     }
-}
 
+    async fn update_profile(&self, id: uuid::Uuid, profile: &Profile) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET display_name = $2, bio = $3, avatar_url = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(profile.display_name.as_ref().map(|name| name.as_str()))
+        .bind(profile.bio.as_ref().map(|bio| bio.as_str()))
+        .bind(profile.avatar_url.as_ref().map(|url| url.as_str()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn schedule_deletion(
+        &self,
+        id: uuid::Uuid,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET deletion_scheduled_for = $2
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn cancel_deletion(&self, id: uuid::Uuid) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET deletion_scheduled_for = NULL
+            WHERE id = $1 AND deletion_scheduled_for IS NOT NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
 
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_due_for_deletion(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> Result<Vec<User>, DomainError> {
+        let rows: Vec<UserRow> = sqlx::query_as(
+            r#"
+            SELECT id, email, password_hash, role, email_verified, display_name, bio, avatar_url,
+                   deletion_scheduled_for, created_at
+            FROM users
+            WHERE deletion_scheduled_for <= $1
+            ORDER BY deletion_scheduled_for ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        rows.into_iter().map(Self::row_to_user).collect()
+    }
+
+    async fn delete(&self, id: uuid::Uuid) -> Result<(), DomainError> {
+        // Posts, comments and tokens are removed by the foreign key cascades
+        let result = sqlx::query(
+            r#"
+            DELETE FROM users
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Database error: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        Ok(())
+    }
+
+    async fn anonymize(&self, id: uuid::Uuid) -> Result<(), DomainError> {
+        let db_error = |e: sqlx::Error| DomainError::InfraError(format!("Database error: {}", e));
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        // The placeholder address keeps the email column unique and can never receive mail.
+        // Without a password hash no password matches.
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET email = 'deleted-' || id || '@deleted.invalid',
+                password_hash = NULL,
+                role = 'user',
+                email_verified = FALSE,
+                display_name = NULL,
+                bio = NULL,
+                avatar_url = NULL,
+                deletion_scheduled_for = NULL,
+                deleted_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("User not found".into()));
+        }
+
+        for table in [
+            "refresh_tokens",
+            "email_verification_tokens",
+            "password_reset_tokens",
+            "totp_recovery_codes",
+            "totp_credentials",
+            "mfa_challenges",
+            "user_identities",
+            "api_keys",
+            "sessions",
+        ] {
+            sqlx::query(&format!("DELETE FROM {} WHERE user_id = $1", table))
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+
+        tx.commit().await.map_err(db_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// crates/infrastructure/src/security/blocking_pool.rs
+
+use domain::common::error::DomainError;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Runs CPU-heavy work on Tokio's blocking threads instead of the async workers, at most
+/// `concurrency` jobs at a time. Up to `queue_size` more may wait for a slot; anything
+/// beyond that is turned away with `DomainError::Overloaded` instead of piling up.
+#[derive(Clone)]
+pub struct BlockingPool {
+    admitted: Arc<Semaphore>,
+    running: Arc<Semaphore>,
+}
+
+impl Default for BlockingPool {
+    fn default() -> Self {
+        let concurrency = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self::new(concurrency, concurrency * 4)
+    }
+}
+
+impl BlockingPool {
+    pub fn new(concurrency: usize, queue_size: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        Self {
+            admitted: Arc::new(Semaphore::new(concurrency + queue_size)),
+            running: Arc::new(Semaphore::new(concurrency)),
+        }
+    }
+
+    pub async fn run<F, T>(&self, job: F) -> Result<T, DomainError>
+    where
+        F: FnOnce() -> Result<T, DomainError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let admitted = self.admitted.clone().try_acquire_owned().map_err(|_| {
+            DomainError::Overloaded("Too many requests in progress, try again shortly".into())
+        })?;
+        let running = self
+            .running
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Blocking pool closed: {}", e)))?;
+
+        // The permits move into the job, so they are held until it has finished even if
+        // the caller stops waiting for it
+        tokio::task::spawn_blocking(move || {
+            let _permits = (admitted, running);
+            job()
+        })
+        .await
+        .map_err(|e| DomainError::InfraError(format!("Blocking task failed: {}", e)))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rejects_work_beyond_queue() {
+        let pool = BlockingPool::new(1, 0);
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+        let busy = tokio::spawn({
+            let pool = pool.clone();
+            async move {
+                pool.run(move || {
+                    wait.recv().ok();
+                    Ok(())
+                })
+                .await
+            }
+        });
+        while pool.admitted.available_permits() > 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let result = pool.run(|| Ok(())).await;
+        assert!(matches!(result, Err(DomainError::Overloaded(_))));
+
+        release.send(()).unwrap();
+        busy.await.unwrap().unwrap();
+        assert!(pool.run(|| Ok(())).await.is_ok());
+    }
+}
@@ -0,0 +1,57 @@
+// crates/infrastructure/src/security/jwks_client.rs
+
+use std::time::Duration;
+
+use domain::common::error::DomainError;
+use jsonwebtoken::jwk::JwkSet;
+
+use super::jwt_keys::JwtKeySet;
+
+// An unreachable auth service must not hang the refresh loop
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Fetches a published JWKS, such as the auth service's `/.well-known/jwks.json`
+#[derive(Clone)]
+pub struct JwksClient {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl JwksClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .connect_timeout(CONNECT_TIMEOUT)
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Failed to build HTTP client"),
+            url,
+        }
+    }
+
+    pub async fn fetch(&self) -> Result<JwkSet, DomainError> {
+        self.client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| DomainError::InfraError(format!("Failed to fetch JWKS: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Invalid JWKS: {}", e)))
+    }
+
+    /// Replace the keys in `key_set` with the currently published ones, returning
+    /// how many were accepted. A JWKS without a usable key is an error and leaves
+    /// `key_set` as it was.
+    pub async fn refresh(&self, key_set: &JwtKeySet) -> Result<usize, DomainError> {
+        let jwks = self.fetch().await?;
+        match key_set.replace(&jwks) {
+            0 => Err(DomainError::InfraError(
+                "JWKS contains no usable keys".into(),
+            )),
+            count => Ok(count),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+// crates/infrastructure/src/security/jwt_keys.rs
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use domain::common::error::DomainError;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey};
+use rsa::traits::PublicKeyParts;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, RwLock};
+
+/// Private key used to sign access tokens. Ed25519 keys sign with EdDSA, RSA keys with RS256.
+pub struct JwtSigningKey {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    public_jwk: Jwk,
+}
+
+impl JwtSigningKey {
+    /// Load an Ed25519 (PKCS#8) or RSA (PKCS#1 or PKCS#8) private key from PEM
+    pub fn from_pem(pem: &str) -> Result<Self, DomainError> {
+        if let Ok(key) = ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+            return Self::from_ed25519(&key);
+        }
+
+        let key = rsa::RsaPrivateKey::from_pkcs8_pem(pem)
+            .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(pem))
+            .map_err(|_| invalid_key("expected an Ed25519 or RSA private key"))?;
+        let encoding_key =
+            EncodingKey::from_rsa_pem(pem.as_bytes()).map_err(|e| invalid_key(&e.to_string()))?;
+
+        Ok(Self {
+            algorithm: Algorithm::RS256,
+            encoding_key,
+            public_jwk: rsa_jwk(&key.to_public_key()),
+        })
+    }
+
+    /// Generate a new random Ed25519 key
+    pub fn generate() -> Result<Self, DomainError> {
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        Self::from_ed25519(&key)
+    }
+
+    fn from_ed25519(key: &ed25519_dalek::SigningKey) -> Result<Self, DomainError> {
+        let der = key
+            .to_pkcs8_der()
+            .map_err(|e| invalid_key(&e.to_string()))?;
+
+        Ok(Self {
+            algorithm: Algorithm::EdDSA,
+            encoding_key: EncodingKey::from_ed_der(der.as_bytes()),
+            public_jwk: ed25519_jwk(&key.verifying_key()),
+        })
+    }
+
+    /// Key ID stamped into the `kid` header: the key's RFC 7638 thumbprint
+    pub fn kid(&self) -> &str {
+        self.public_jwk.common.key_id.as_deref().unwrap_or_default()
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    pub fn public_jwk(&self) -> &Jwk {
+        &self.public_jwk
+    }
+}
+
+/// Build the JWK for an Ed25519 or RSA public key in PEM form
+pub fn public_jwk_from_pem(pem: &str) -> Result<Jwk, DomainError> {
+    if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_pem(pem) {
+        return Ok(ed25519_jwk(&key));
+    }
+
+    let key = rsa::RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| rsa::RsaPublicKey::from_pkcs1_pem(pem))
+        .map_err(|_| invalid_key("expected an Ed25519 or RSA public key"))?;
+    Ok(rsa_jwk(&key))
+}
+
+fn ed25519_jwk(key: &ed25519_dalek::VerifyingKey) -> Jwk {
+    let x = URL_SAFE_NO_PAD.encode(key.as_bytes());
+    let thumbprint = format!(r#"{{"crv":"Ed25519","kty":"OKP","x":"{}"}}"#, x);
+
+    Jwk {
+        common: common_parameters(&thumbprint, KeyAlgorithm::EdDSA),
+        algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x,
+        }),
+    }
+}
+
+fn rsa_jwk(key: &rsa::RsaPublicKey) -> Jwk {
+    let n = URL_SAFE_NO_PAD.encode(key.n().to_bytes_be());
+    let e = URL_SAFE_NO_PAD.encode(key.e().to_bytes_be());
+    let thumbprint = format!(r#"{{"e":"{}","kty":"RSA","n":"{}"}}"#, e, n);
+
+    Jwk {
+        common: common_parameters(&thumbprint, KeyAlgorithm::RS256),
+        algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n,
+            e,
+        }),
+    }
+}
+
+fn common_parameters(thumbprint: &str, algorithm: KeyAlgorithm) -> CommonParameters {
+    CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(algorithm),
+        key_id: Some(URL_SAFE_NO_PAD.encode(Sha256::digest(thumbprint.as_bytes()))),
+        ..Default::default()
+    }
+}
+
+fn invalid_key(reason: &str) -> DomainError {
+    DomainError::InfraError(format!("Invalid JWT key: {}", reason))
+}
+
+#[derive(Clone)]
+struct VerificationKey {
+    kid: String,
+    algorithm: Algorithm,
+    decoding_key: DecodingKey,
+    jwk: Jwk,
+}
+
+/// Public keys accepted when verifying access tokens, looked up by `kid`.
+///
+/// Clones share the same underlying set, so it can be replaced in place when the
+/// published JWKS changes.
+#[derive(Clone, Default)]
+pub struct JwtKeySet {
+    keys: Arc<RwLock<Vec<VerificationKey>>>,
+}
+
+impl JwtKeySet {
+    pub fn from_jwks(jwks: &JwkSet) -> Self {
+        let key_set = Self::default();
+        key_set.replace(jwks);
+        key_set
+    }
+
+    /// Swap in the keys of `jwks`, returning how many were accepted. Keys without a
+    /// `kid` or using an unsupported algorithm are skipped. If none are left the
+    /// current keys are kept, so a bad JWKS cannot lock everyone out.
+    pub fn replace(&self, jwks: &JwkSet) -> usize {
+        let keys: Vec<VerificationKey> = jwks.keys.iter().filter_map(verification_key).collect();
+        let count = keys.len();
+        if count > 0 {
+            *self.keys.write().unwrap() = keys;
+        }
+        count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.read().unwrap().is_empty()
+    }
+
+    /// The keys in JWKS form, in the order they were added
+    pub fn to_jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self
+                .keys
+                .read()
+                .unwrap()
+                .iter()
+                .map(|k| k.jwk.clone())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn find(&self, kid: &str) -> Option<(Algorithm, DecodingKey)> {
+        self.keys
+            .read()
+            .unwrap()
+            .iter()
+            .find(|k| k.kid == kid)
+            .map(|k| (k.algorithm, k.decoding_key.clone()))
+    }
+}
+
+fn verification_key(jwk: &Jwk) -> Option<VerificationKey> {
+    let algorithm = match (&jwk.common.key_algorithm, &jwk.algorithm) {
+        (Some(KeyAlgorithm::EdDSA), _) | (None, AlgorithmParameters::OctetKeyPair(_)) => {
+            Algorithm::EdDSA
+        }
+        (Some(KeyAlgorithm::RS256), _) | (None, AlgorithmParameters::RSA(_)) => Algorithm::RS256,
+        _ => return None,
+    };
+
+    Some(VerificationKey {
+        kid: jwk.common.key_id.clone()?,
+        algorithm,
+        decoding_key: DecodingKey::from_jwk(jwk).ok()?,
+        jwk: jwk.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs8::EncodePublicKey;
+
+    #[test]
+    fn test_kid_is_stable_for_the_same_key() {
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let pem = key
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+
+        let signing_key = JwtSigningKey::from_ed25519(&key).unwrap();
+        let public_jwk = public_jwk_from_pem(&pem).unwrap();
+
+        assert_eq!(
+            signing_key.kid(),
+            public_jwk.common.key_id.as_deref().unwrap()
+        );
+        assert_eq!(signing_key.algorithm(), Algorithm::EdDSA);
+    }
+
+    #[test]
+    fn test_from_pem_rejects_garbage() {
+        assert!(JwtSigningKey::from_pem("not a key").is_err());
+        assert!(public_jwk_from_pem("not a key").is_err());
+    }
+
+    #[test]
+    fn test_key_set_replace() {
+        let first = JwtSigningKey::generate().unwrap();
+        let second = JwtSigningKey::generate().unwrap();
+
+        let key_set = JwtKeySet::from_jwks(&JwkSet {
+            keys: vec![first.public_jwk().clone()],
+        });
+        assert!(key_set.find(first.kid()).is_some());
+
+        let accepted = key_set.replace(&JwkSet {
+            keys: vec![second.public_jwk().clone()],
+        });
+        assert_eq!(accepted, 1);
+        assert!(key_set.find(first.kid()).is_none());
+        assert!(key_set.find(second.kid()).is_some());
+    }
+
+    #[test]
+    fn test_key_set_replace_keeps_keys_when_none_are_usable() {
+        let key = JwtSigningKey::generate().unwrap();
+        let key_set = JwtKeySet::from_jwks(&JwkSet {
+            keys: vec![key.public_jwk().clone()],
+        });
+
+        let mut without_kid = key.public_jwk().clone();
+        without_kid.common.key_id = None;
+        assert_eq!(key_set.replace(&JwkSet { keys: vec![] }), 0);
+        assert_eq!(
+            key_set.replace(&JwkSet {
+                keys: vec![without_kid]
+            }),
+            0
+        );
+        assert!(key_set.find(key.kid()).is_some());
+    }
+}
@@ -1,86 +1,205 @@
 // crates/infrastructure/src/security/jwt_service.rs
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use domain::common::error::DomainError;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use domain::users::{Role, User};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use uuid::Uuid;
 
+use super::jwt_keys::{JwtKeySet, JwtSigningKey};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
-    pub sub: String,      // User ID
-    pub email: String,    // User email
-    pub exp: i64,         // Expiration time
-    pub iat: i64,         // Issued at
+    pub sub: String,   // User ID
+    pub email: String, // User email
+    pub exp: i64,      // Expiration time
+    pub iat: i64,      // Issued at
+    #[serde(default)]
+    pub role: Role, // User role
+    #[serde(default)]
+    pub email_verified: bool, // Whether the user confirmed their email address
+    pub jti: String,   // Token ID, used for revocation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>, // Session the token belongs to, used for revocation
 }
 
+/// Signs and verifies access tokens.
+///
+/// The auth service holds the private key; the other services only get the public
+/// key set and can verify but not mint tokens.
+#[derive(Clone)]
 pub struct JwtService {
-    secret: String,
-    expiration_hours: i64,
+    signing_key: Option<Arc<JwtSigningKey>>,
+    keys: JwtKeySet,
+    expiration_minutes: i64,
 }
 
 impl JwtService {
-    pub fn new(secret: String, expiration_hours: i64) -> Self {
+    /// Create a service that signs with `signing_key`. Tokens are verified against the
+    /// signing key plus `additional_keys` (upcoming or retired keys during rotation).
+    pub fn new(
+        signing_key: JwtSigningKey,
+        additional_keys: Vec<Jwk>,
+        expiration_minutes: i64,
+    ) -> Self {
+        let mut jwks = vec![signing_key.public_jwk().clone()];
+        jwks.extend(additional_keys);
+
         Self {
-            secret,
-            expiration_hours,
+            signing_key: Some(Arc::new(signing_key)),
+            keys: JwtKeySet::from_jwks(&JwkSet { keys: jwks }),
+            expiration_minutes,
         }
     }
 
-    /// Generate a JWT token for a user
-    pub fn generate_token(&self, user_id: Uuid, email: &str) -> Result<String, DomainError> {
+    /// Create a service that can only verify tokens against `keys`
+    pub fn verifier(keys: JwtKeySet) -> Self {
+        Self {
+            signing_key: None,
+            keys,
+            expiration_minutes: 0,
+        }
+    }
+
+    /// Public keys tokens are verified against, for publishing as a JWKS
+    pub fn jwks(&self) -> JwkSet {
+        self.keys.to_jwks()
+    }
+
+    /// Generate a JWT token for a user's session, returning the token and its expiry
+    pub fn generate_token(
+        &self,
+        user: &User,
+        session_id: Uuid,
+    ) -> Result<(String, DateTime<Utc>), DomainError> {
+        let signing_key = self
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| DomainError::InfraError("No signing key configured".into()))?;
+
         let now = Utc::now();
-        let exp = now + Duration::hours(self.expiration_hours);
+        let exp = now + Duration::minutes(self.expiration_minutes);
 
         let claims = Claims {
-            sub: user_id.to_string(),
-            email: email.to_string(),
+            sub: user.id.to_string(),
+            email: user.email.as_str().to_string(),
             exp: exp.timestamp(),
             iat: now.timestamp(),
+            role: user.role,
+            email_verified: user.email_verified,
+            jti: Uuid::new_v4().to_string(),
+            sid: Some(session_id.to_string()),
         };
 
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.secret.as_bytes()),
-        )
-        .map_err(|e| DomainError::InfraError(format!("Failed to generate token: {}", e)))
+        let mut header = Header::new(signing_key.algorithm());
+        header.kid = Some(signing_key.kid().to_string());
+
+        let token = encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| DomainError::InfraError(format!("Failed to generate token: {}", e)))?;
+
+        Ok((token, exp))
     }
 
     /// Verify and decode a JWT token
     pub fn verify_token(&self, token: &str) -> Result<Claims, DomainError> {
-        decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(self.secret.as_bytes()),
-            &Validation::default(),
-        )
-        .map(|data| data.claims)
-        .map_err(|e| DomainError::InfraError(format!("Invalid token: {}", e)))
+        let header = decode_header(token)
+            .map_err(|e| DomainError::InfraError(format!("Invalid token: {}", e)))?;
+
+        // The key is picked by `kid` and pins the algorithm, so a token can't
+        // choose how it gets verified
+        let (algorithm, decoding_key) = header
+            .kid
+            .and_then(|kid| self.keys.find(&kid))
+            .ok_or_else(|| DomainError::InfraError("Invalid token: unknown signing key".into()))?;
+
+        decode::<Claims>(token, &decoding_key, &Validation::new(algorithm))
+            .map(|data| data.claims)
+            .map_err(|e| DomainError::InfraError(format!("Invalid token: {}", e)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use domain::users::{Email, PasswordHash};
+
+    fn jwt_service() -> JwtService {
+        JwtService::new(JwtSigningKey::generate().unwrap(), Vec::new(), 15)
+    }
+
+    fn user(email: &str) -> User {
+        User::new(
+            Email::new(email).unwrap(),
+            PasswordHash::new("hash".to_string()),
+        )
+    }
 
     #[test]
     fn test_jwt_generation_and_verification() {
-        let jwt_service = JwtService::new("test_secret_key".to_string(), 24);
-        let user_id = Uuid::new_v4();
-        let email = "test@example.com";
+        let jwt_service = jwt_service();
+        let mut user = user("test@example.com");
+        user.role = Role::Moderator;
+        user.email_verified = true;
 
-        let token = jwt_service.generate_token(user_id, email).unwrap();
+        let session_id = Uuid::new_v4();
+        let (token, expires_at) = jwt_service.generate_token(&user, session_id).unwrap();
         let claims = jwt_service.verify_token(&token).unwrap();
 
-        assert_eq!(claims.sub, user_id.to_string());
-        assert_eq!(claims.email, email);
+        assert_eq!(claims.sub, user.id.to_string());
+        assert_eq!(claims.email, "test@example.com");
+        assert_eq!(claims.role, Role::Moderator);
+        assert!(claims.email_verified);
+        assert_eq!(claims.exp, expires_at.timestamp());
+        assert!(Uuid::parse_str(&claims.jti).is_ok());
+        assert_eq!(claims.sid, Some(session_id.to_string()));
     }
 
     #[test]
     fn test_invalid_token() {
-        let jwt_service = JwtService::new("test_secret_key".to_string(), 24);
+        let jwt_service = jwt_service();
         let result = jwt_service.verify_token("invalid_token");
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_verifier_accepts_any_published_key() {
+        let old = jwt_service();
+        let new = JwtService::new(JwtSigningKey::generate().unwrap(), old.jwks().keys, 15);
+        let verifier = JwtService::verifier(JwtKeySet::from_jwks(&new.jwks()));
+
+        let (old_token, _) = old
+            .generate_token(&user("a@example.com"), Uuid::new_v4())
+            .unwrap();
+        let (new_token, _) = new
+            .generate_token(&user("b@example.com"), Uuid::new_v4())
+            .unwrap();
+
+        assert!(verifier.verify_token(&old_token).is_ok());
+        assert!(verifier.verify_token(&new_token).is_ok());
+    }
+
+    #[test]
+    fn test_verifier_rejects_unknown_key() {
+        let issuer = jwt_service();
+        let verifier = JwtService::verifier(JwtKeySet::from_jwks(&jwt_service().jwks()));
+
+        let (token, _) = issuer
+            .generate_token(&user("test@example.com"), Uuid::new_v4())
+            .unwrap();
+
+        assert!(verifier.verify_token(&token).is_err());
+    }
+
+    #[test]
+    fn test_verifier_cannot_sign() {
+        let verifier = JwtService::verifier(JwtKeySet::from_jwks(&jwt_service().jwks()));
+
+        let result = verifier.generate_token(&user("test@example.com"), Uuid::new_v4());
+
+        assert!(result.is_err());
+    }
 }
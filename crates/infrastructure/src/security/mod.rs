@@ -1,7 +1,20 @@
 // crates/infrastructure/src/security/mod.rs
 
-mod password_hasher;
+mod blocking_pool;
+mod jwks_client;
+mod jwt_keys;
 mod jwt_service;
+mod oidc_client;
+mod opaque_token;
+mod password_hasher;
+mod totp;
 
+pub use blocking_pool::BlockingPool;
+pub use jsonwebtoken::jwk::JwkSet;
+pub use jwks_client::JwksClient;
+pub use jwt_keys::{JwtKeySet, JwtSigningKey, public_jwk_from_pem};
+pub use jwt_service::{Claims, JwtService};
+pub use oidc_client::{IdTokenClaims, OidcClient, OidcProviderConfig};
+pub use opaque_token::OpaqueTokenService;
 pub use password_hasher::Argon2PasswordHasher;
-pub use jwt_service::{JwtService, Claims};
+pub use totp::TotpService;
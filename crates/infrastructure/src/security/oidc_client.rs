@@ -0,0 +1,439 @@
+// crates/infrastructure/src/security/oidc_client.rs
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use domain::common::error::DomainError;
+use jsonwebtoken::{Validation, decode, decode_header};
+use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::jwks_client::JwksClient;
+use super::jwt_keys::JwtKeySet;
+
+/// Registration of this service as a client of one OpenID Connect provider
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+    /// Name used in our URLs and stored with linked identities, e.g. `google`
+    pub name: String,
+    /// Issuer URL; its `/.well-known/openid-configuration` is read at startup
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    /// Where the provider sends the browser back to after sign-in
+    pub redirect_url: String,
+    pub scopes: Vec<String>,
+}
+
+/// Claims of a validated ID token
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdTokenClaims {
+    pub sub: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default, deserialize_with = "bool_or_string")]
+    pub email_verified: bool,
+    #[serde(default)]
+    pub nonce: Option<String>,
+}
+
+// Some providers send `email_verified` as the string "true"
+fn bool_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+
+    Ok(match BoolOrString::deserialize(deserializer)? {
+        BoolOrString::Bool(value) => value,
+        BoolOrString::String(value) => value.eq_ignore_ascii_case("true"),
+    })
+}
+
+// The parts of the provider's discovery document that the code flow needs
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+struct Provider {
+    config: OidcProviderConfig,
+    metadata: ProviderMetadata,
+    keys: JwtKeySet,
+    jwks: JwksClient,
+}
+
+/// OpenID Connect authorization code flow with PKCE against a set of configured providers.
+///
+/// Provider endpoints come from discovery and their signing keys from the published JWKS,
+/// which is fetched again whenever an ID token names a key that is not known yet.
+#[derive(Clone, Default)]
+pub struct OidcClient {
+    http: reqwest::Client,
+    providers: Arc<HashMap<String, Provider>>,
+}
+
+impl OidcClient {
+    /// Read the discovery document and signing keys of every provider
+    pub async fn discover(configs: Vec<OidcProviderConfig>) -> Result<Self, DomainError> {
+        let http = reqwest::Client::new();
+        let mut providers = HashMap::new();
+
+        for config in configs {
+            let discovery_url = format!(
+                "{}/.well-known/openid-configuration",
+                config.issuer.trim_end_matches('/')
+            );
+            let metadata: ProviderMetadata = http
+                .get(&discovery_url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(|e| {
+                    DomainError::InfraError(format!("Failed to discover {}: {}", config.name, e))
+                })?
+                .json()
+                .await
+                .map_err(|e| {
+                    DomainError::InfraError(format!(
+                        "Invalid discovery document for {}: {}",
+                        config.name, e
+                    ))
+                })?;
+
+            // The document must describe the issuer it was fetched from (OIDC Discovery 4.3)
+            if metadata.issuer.trim_end_matches('/') != config.issuer.trim_end_matches('/') {
+                return Err(DomainError::InfraError(format!(
+                    "Issuer mismatch for {}: discovery document names {}",
+                    config.name, metadata.issuer
+                )));
+            }
+
+            let keys = JwtKeySet::default();
+            let jwks = JwksClient::new(metadata.jwks_uri.clone());
+            jwks.refresh(&keys).await?;
+
+            providers.insert(
+                config.name.clone(),
+                Provider {
+                    config,
+                    metadata,
+                    keys,
+                    jwks,
+                },
+            );
+        }
+
+        Ok(Self {
+            http,
+            providers: Arc::new(providers),
+        })
+    }
+
+    /// Names of the configured providers
+    pub fn provider_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.providers.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// URL to send the browser to for signing in, carrying `state`, `nonce` and the S256
+    /// challenge of `code_verifier`
+    pub fn authorization_url(
+        &self,
+        provider: &str,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError> {
+        let provider = self.provider(provider)?;
+        let scopes = provider.config.scopes.join(" ");
+        let code_challenge = pkce_challenge(code_verifier);
+
+        let url = reqwest::Url::parse_with_params(
+            &provider.metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", provider.config.client_id.as_str()),
+                ("redirect_uri", provider.config.redirect_url.as_str()),
+                ("scope", scopes.as_str()),
+                ("state", state),
+                ("nonce", nonce),
+                ("code_challenge", code_challenge.as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| DomainError::InfraError(format!("Invalid authorization endpoint: {}", e)))?;
+
+        Ok(url.into())
+    }
+
+    /// Redeem an authorization code at the provider's token endpoint and validate the
+    /// ID token that comes back. The nonce is returned for the caller to compare.
+    pub async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<IdTokenClaims, DomainError> {
+        let provider = self.provider(provider)?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", provider.config.redirect_url.as_str()),
+            ("client_id", provider.config.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(client_secret) = &provider.config.client_secret {
+            form.push(("client_secret", client_secret.as_str()));
+        }
+
+        let response = self
+            .http
+            .post(&provider.metadata.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Token request failed: {}", e)))?;
+
+        // A rejected code is the user's problem (expired, reused), not ours
+        if response.status().is_client_error() {
+            let reason = match response.json::<TokenErrorResponse>().await {
+                Ok(error) => error.error_description.unwrap_or(error.error),
+                Err(_) => "invalid_grant".to_string(),
+            };
+            return Err(DomainError::Unauthorized(format!(
+                "Authorization code was rejected: {}",
+                reason
+            )));
+        }
+
+        let id_token = response
+            .error_for_status()
+            .map_err(|e| DomainError::InfraError(format!("Token request failed: {}", e)))?
+            .json::<TokenResponse>()
+            .await
+            .map_err(|e| DomainError::InfraError(format!("Invalid token response: {}", e)))?
+            .id_token
+            .ok_or_else(|| DomainError::InfraError("Token response has no ID token".into()))?;
+
+        provider.validate(&id_token).await
+    }
+
+    fn provider(&self, name: &str) -> Result<&Provider, DomainError> {
+        self.providers
+            .get(name)
+            .ok_or_else(|| DomainError::NotFound(format!("Unknown identity provider: {}", name)))
+    }
+}
+
+impl Provider {
+    /// Check the ID token's signature against the provider's keys, and its issuer,
+    /// audience and expiry
+    async fn validate(&self, id_token: &str) -> Result<IdTokenClaims, DomainError> {
+        let invalid =
+            |reason: String| DomainError::Unauthorized(format!("Invalid ID token: {}", reason));
+
+        let header = decode_header(id_token).map_err(|e| invalid(e.to_string()))?;
+        let kid = header.kid.ok_or_else(|| invalid("missing kid".into()))?;
+
+        // An unknown kid usually means the provider has rotated its keys
+        let (algorithm, decoding_key) = match self.keys.find(&kid) {
+            Some(key) => key,
+            None => {
+                self.jwks.refresh(&self.keys).await?;
+                self.keys
+                    .find(&kid)
+                    .ok_or_else(|| invalid(format!("unknown key {}", kid)))?
+            }
+        };
+
+        let mut validation = Validation::new(algorithm);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_issuer(&[&self.metadata.issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        decode::<IdTokenClaims>(id_token, &decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| invalid(e.to_string()))
+    }
+}
+
+/// S256 code challenge for a PKCE code verifier (RFC 7636)
+fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::JwtSigningKey;
+    use axum::extract::{Form, State};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use jsonwebtoken::{Header, encode};
+    use serde_json::{Value, json};
+
+    const CLIENT_ID: &str = "ferro";
+    const CODE: &str = "valid-code";
+    const VERIFIER: &str = "a-code-verifier-that-is-long-enough-for-pkce-0123456789";
+
+    #[derive(Clone)]
+    struct MockProvider {
+        issuer: String,
+        signing_key: Arc<JwtSigningKey>,
+        audience: String,
+    }
+
+    // Serves discovery, JWKS and a token endpoint that accepts one code with its verifier
+    async fn start_mock_provider(audience: &str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let state = MockProvider {
+            issuer: issuer.clone(),
+            signing_key: Arc::new(JwtSigningKey::generate().unwrap()),
+            audience: audience.to_string(),
+        };
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(state);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        issuer
+    }
+
+    async fn discovery(State(provider): State<MockProvider>) -> Json<Value> {
+        Json(json!({
+            "issuer": provider.issuer,
+            "authorization_endpoint": format!("{}/authorize", provider.issuer),
+            "token_endpoint": format!("{}/token", provider.issuer),
+            "jwks_uri": format!("{}/jwks", provider.issuer),
+        }))
+    }
+
+    async fn jwks(State(provider): State<MockProvider>) -> Json<Value> {
+        Json(json!({ "keys": [provider.signing_key.public_jwk()] }))
+    }
+
+    async fn token(
+        State(provider): State<MockProvider>,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> (axum::http::StatusCode, Json<Value>) {
+        if form.get("code").map(String::as_str) != Some(CODE)
+            || form.get("code_verifier").map(String::as_str) != Some(VERIFIER)
+        {
+            return (
+                axum::http::StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "invalid_grant" })),
+            );
+        }
+
+        let mut header = Header::new(provider.signing_key.algorithm());
+        header.kid = Some(provider.signing_key.kid().to_string());
+        let claims = json!({
+            "iss": provider.issuer,
+            "aud": provider.audience,
+            "sub": "mock-user-1",
+            "email": "oidc@example.com",
+            "email_verified": "true",
+            "nonce": "test-nonce",
+            "exp": chrono::Utc::now().timestamp() + 300,
+        });
+        let id_token = encode(&header, &claims, provider.signing_key.encoding_key()).unwrap();
+
+        (
+            axum::http::StatusCode::OK,
+            Json(json!({ "access_token": "unused", "id_token": id_token })),
+        )
+    }
+
+    async fn client_for(issuer: &str) -> OidcClient {
+        OidcClient::discover(vec![OidcProviderConfig {
+            name: "mock".into(),
+            issuer: issuer.to_string(),
+            client_id: CLIENT_ID.into(),
+            client_secret: Some("secret".into()),
+            redirect_url: "http://localhost:3000/auth/callback".into(),
+            scopes: vec!["openid".into(), "email".into()],
+        }])
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc7636_example() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authorization_url() {
+        let issuer = start_mock_provider(CLIENT_ID).await;
+        let client = client_for(&issuer).await;
+
+        let url = client
+            .authorization_url("mock", "the-state", "the-nonce", VERIFIER)
+            .unwrap();
+        let url = reqwest::Url::parse(&url).unwrap();
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+        assert_eq!(url.path(), "/authorize");
+        assert_eq!(params["client_id"], CLIENT_ID);
+        assert_eq!(params["scope"], "openid email");
+        assert_eq!(params["state"], "the-state");
+        assert_eq!(params["nonce"], "the-nonce");
+        assert_eq!(params["code_challenge"], pkce_challenge(VERIFIER));
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert!(matches!(
+            client.authorization_url("other", "s", "n", VERIFIER),
+            Err(DomainError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_validates_id_token() {
+        let issuer = start_mock_provider(CLIENT_ID).await;
+        let client = client_for(&issuer).await;
+
+        let claims = client.exchange_code("mock", CODE, VERIFIER).await.unwrap();
+        assert_eq!(claims.sub, "mock-user-1");
+        assert_eq!(claims.email.as_deref(), Some("oidc@example.com"));
+        assert!(claims.email_verified);
+        assert_eq!(claims.nonce.as_deref(), Some("test-nonce"));
+
+        let wrong_verifier = client.exchange_code("mock", CODE, "another-verifier").await;
+        assert!(matches!(wrong_verifier, Err(DomainError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_rejects_token_for_another_client() {
+        let issuer = start_mock_provider("someone-else").await;
+        let client = client_for(&issuer).await;
+
+        let result = client.exchange_code("mock", CODE, VERIFIER).await;
+        assert!(matches!(result, Err(DomainError::Unauthorized(msg)) if msg.contains("ID token")));
+    }
+}
@@ -0,0 +1,51 @@
+// crates/infrastructure/src/security/opaque_token.rs
+
+use rand::RngCore;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+
+/// Generates high-entropy random tokens and hashes them for storage.
+///
+/// The tokens carry 256 bits of randomness, so a fast SHA-256 digest is enough to
+/// keep them useless if the table leaks; a slow password hash is not needed.
+#[derive(Default)]
+pub struct OpaqueTokenService;
+
+impl OpaqueTokenService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate a new random token, hex encoded
+    pub fn generate(&self) -> String {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        to_hex(&bytes)
+    }
+
+    /// Hash a token for lookup and storage
+    pub fn hash(&self, token: &str) -> String {
+        to_hex(&Sha256::digest(token.as_bytes()))
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_unique_and_hash_is_stable() {
+        let service = OpaqueTokenService::new();
+        let a = service.generate();
+        let b = service.generate();
+
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, b);
+        assert_eq!(service.hash(&a), service.hash(&a));
+        assert_ne!(service.hash(&a), a);
+    }
+}
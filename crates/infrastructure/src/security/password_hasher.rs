@@ -1,46 +1,214 @@
 // crates/infrastructure/src/security/password_hasher.rs
 
 use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHasher as Argon2Trait, SaltString},
-    Argon2, PasswordHash, PasswordVerifier,
+    Algorithm, Argon2, KeyId, Params, PasswordHash, PasswordVerifier, Version,
+    password_hash::{PasswordHasher as Argon2Trait, SaltString, rand_core::OsRng},
 };
 use async_trait::async_trait;
 use domain::common::error::DomainError;
 use domain::users::{PasswordHash as DomainPasswordHash, PasswordHasher, PlainPassword};
+use std::sync::{Arc, OnceLock};
 
-pub struct Argon2PasswordHasher;
+use super::blocking_pool::BlockingPool;
+
+// Recorded as the `keyid` of peppered hashes, so verification knows to apply the pepper
+// and hashes from before a pepper was configured still verify
+const PEPPER_KEY_ID: &[u8] = b"pepper";
+
+/// Argon2id password hashing. Hashing and verification are CPU-heavy, so both run on a
+/// `BlockingPool` rather than on the async executor.
+#[derive(Clone)]
+pub struct Argon2PasswordHasher {
+    settings: Arc<Argon2Settings>,
+    pool: BlockingPool,
+}
+
+struct Argon2Settings {
+    params: Params,
+    pepper: Option<Vec<u8>>,
+    // Hash of a random password, verified against when there is no real hash to check
+    dummy_hash: OnceLock<String>,
+}
+
+impl Default for Argon2PasswordHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Argon2PasswordHasher {
+    /// Hasher with the Argon2 crate's recommended parameters, no pepper and a pool sized
+    /// to the machine
     pub fn new() -> Self {
-        Self
+        Self {
+            settings: Arc::new(Argon2Settings {
+                params: Params::default(),
+                pepper: None,
+                dummy_hash: OnceLock::new(),
+            }),
+            pool: BlockingPool::default(),
+        }
+    }
+
+    /// Hasher with explicit Argon2id cost parameters and an optional pepper, a secret
+    /// kept out of the database and mixed into every new hash
+    pub fn with_params(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+        pepper: Option<String>,
+        pool: BlockingPool,
+    ) -> Result<Self, DomainError> {
+        let keyid = match &pepper {
+            Some(_) => KeyId::new(PEPPER_KEY_ID)
+                .map_err(|e| DomainError::InfraError(format!("Invalid Argon2 key id: {}", e)))?,
+            None => KeyId::EMPTY,
+        };
+        let params = argon2::ParamsBuilder::new()
+            .m_cost(memory_kib)
+            .t_cost(iterations)
+            .p_cost(parallelism)
+            .keyid(keyid)
+            .build()
+            .map_err(|e| DomainError::Validation(format!("Invalid Argon2 parameters: {}", e)))?;
+
+        let pepper = pepper.map(String::into_bytes);
+        if let Some(pepper) = &pepper {
+            Argon2::new_with_secret(pepper, Algorithm::Argon2id, Version::V0x13, params.clone())
+                .map_err(|e| DomainError::Validation(format!("Invalid password pepper: {}", e)))?;
+        }
+
+        Ok(Self {
+            settings: Arc::new(Argon2Settings {
+                params,
+                pepper,
+                dummy_hash: OnceLock::new(),
+            }),
+            pool,
+        })
     }
 
     /// Verify a plain password against a hash
-    pub fn verify(
+    pub async fn verify(
         &self,
         plain_password: &str,
         password_hash: &str,
     ) -> Result<bool, DomainError> {
+        let settings = self.settings.clone();
+        let plain_password = plain_password.to_string();
+        let password_hash = password_hash.to_string();
+        self.pool
+            .run(move || settings.verify(&plain_password, &password_hash))
+            .await
+    }
+
+    /// Whether a hash was made with an older algorithm, weaker parameters or without the
+    /// pepper, and should be replaced the next time the plain password is known
+    pub fn needs_rehash(&self, password_hash: &str) -> bool {
+        self.settings.needs_rehash(password_hash)
+    }
+
+    /// Do the work of a verification without a real hash, so that logins for unknown
+    /// accounts take as long as logins with a wrong password
+    pub async fn verify_dummy(&self, plain_password: &str) -> Result<(), DomainError> {
+        let settings = self.settings.clone();
+        let plain_password = plain_password.to_string();
+        self.pool
+            .run(move || settings.verify_dummy(&plain_password))
+            .await
+    }
+}
+
+impl Argon2Settings {
+    /// Argon2 context for hashing with the configured parameters, or for verifying a hash
+    /// that was created with or without the pepper
+    fn argon2(&self, peppered: bool) -> Result<Argon2<'_>, DomainError> {
+        match (peppered, &self.pepper) {
+            (false, _) => Ok(Argon2::new(
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )),
+            (true, Some(pepper)) => Argon2::new_with_secret(
+                pepper,
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )
+            .map_err(|e| DomainError::InfraError(format!("Invalid password pepper: {}", e))),
+            (true, None) => Err(DomainError::InfraError(
+                "Password hash needs a pepper but none is configured".into(),
+            )),
+        }
+    }
+
+    fn hash(&self, plain_password: &str) -> Result<String, DomainError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = self
+            .argon2(self.pepper.is_some())?
+            .hash_password(plain_password.as_bytes(), &salt)
+            .map_err(|e| DomainError::InfraError(format!("Failed to hash password: {}", e)))?;
+
+        Ok(hash.to_string())
+    }
+
+    fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError> {
         let parsed_hash = PasswordHash::new(password_hash)
             .map_err(|e| DomainError::InfraError(format!("Invalid password hash: {}", e)))?;
 
-        Ok(Argon2::default()
+        // Algorithm, version and cost are taken from the hash itself
+        Ok(self
+            .argon2(is_peppered(&parsed_hash))?
             .verify_password(plain_password.as_bytes(), &parsed_hash)
             .is_ok())
     }
+
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(password_hash) else {
+            // Nothing can verify against it, so there will never be a chance to rehash
+            return false;
+        };
+        let Ok(params) = Params::try_from(&parsed_hash) else {
+            return true;
+        };
+
+        parsed_hash.algorithm != Algorithm::Argon2id.ident()
+            || parsed_hash.version != Some(Version::V0x13.into())
+            || params.m_cost() < self.params.m_cost()
+            || params.t_cost() < self.params.t_cost()
+            || params.p_cost() < self.params.p_cost()
+            || is_peppered(&parsed_hash) != self.pepper.is_some()
+    }
+
+    fn verify_dummy(&self, plain_password: &str) -> Result<(), DomainError> {
+        let dummy_hash = match self.dummy_hash.get() {
+            Some(hash) => hash,
+            None => {
+                let salt = SaltString::generate(&mut OsRng);
+                let hash = self.hash(salt.as_str())?;
+                self.dummy_hash.get_or_init(|| hash)
+            }
+        };
+
+        self.verify(plain_password, dummy_hash)?;
+        Ok(())
+    }
+}
+
+fn is_peppered(hash: &PasswordHash<'_>) -> bool {
+    Params::try_from(hash).is_ok_and(|params| params.keyid() == PEPPER_KEY_ID)
 }
 
 #[async_trait]
 impl PasswordHasher for Argon2PasswordHasher {
     async fn hash(&self, password: PlainPassword) -> Result<DomainPasswordHash, DomainError> {
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-
-        let hash = argon2
-            .hash_password(password.as_str().as_bytes(), &salt)
-            .map_err(|e| DomainError::InfraError(format!("Failed to hash password: {}", e)))?;
+        let settings = self.settings.clone();
+        let hash = self
+            .pool
+            .run(move || settings.hash(password.as_str()))
+            .await?;
 
-        Ok(DomainPasswordHash::new(hash.to_string()))
+        Ok(DomainPasswordHash::new(hash))
     }
 }
 
@@ -48,6 +216,18 @@ impl PasswordHasher for Argon2PasswordHasher {
 mod tests {
     use super::*;
 
+    fn hasher_with(memory_kib: u32, iterations: u32, pepper: Option<&str>) -> Argon2PasswordHasher {
+        let pepper = pepper.map(str::to_string);
+        Argon2PasswordHasher::with_params(
+            memory_kib,
+            iterations,
+            1,
+            pepper,
+            BlockingPool::default(),
+        )
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn test_password_hashing() {
         let hasher = Argon2PasswordHasher::new();
@@ -56,9 +236,96 @@ mod tests {
         let hash = hasher.hash(plain.clone()).await.unwrap();
 
         // Verify correct password
-        assert!(hasher.verify("testpassword123", hash.as_str()).unwrap());
+        assert!(
+            hasher
+                .verify("testpassword123", hash.as_str())
+                .await
+                .unwrap()
+        );
 
         // Verify incorrect password
-        assert!(!hasher.verify("wrongpassword", hash.as_str()).unwrap());
+        assert!(!hasher.verify("wrongpassword", hash.as_str()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_dummy() {
+        let hasher = Argon2PasswordHasher::new();
+
+        assert!(hasher.verify_dummy("testpassword123").await.is_ok());
+        assert!(hasher.verify_dummy("testpassword123").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_needs_rehash_after_raising_parameters() {
+        let weak = hasher_with(8192, 1, None);
+        let strong = hasher_with(16384, 2, None);
+        let plain = PlainPassword::new("testpassword123").unwrap();
+
+        let hash = weak.hash(plain.clone()).await.unwrap();
+
+        assert!(!weak.needs_rehash(hash.as_str()));
+        assert!(strong.needs_rehash(hash.as_str()));
+        // Old hashes still verify under the new parameters
+        assert!(
+            strong
+                .verify("testpassword123", hash.as_str())
+                .await
+                .unwrap()
+        );
+
+        let rehashed = strong.hash(plain).await.unwrap();
+        assert!(!strong.needs_rehash(rehashed.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_pepper() {
+        let plain_hasher = hasher_with(8192, 1, None);
+        let peppered = hasher_with(8192, 1, Some("pepper-secret"));
+        let other_pepper = hasher_with(8192, 1, Some("another-secret"));
+        let plain = PlainPassword::new("testpassword123").unwrap();
+
+        // Unpeppered hashes keep verifying once a pepper is configured, but get replaced
+        let old_hash = plain_hasher.hash(plain.clone()).await.unwrap();
+        assert!(
+            peppered
+                .verify("testpassword123", old_hash.as_str())
+                .await
+                .unwrap()
+        );
+        assert!(peppered.needs_rehash(old_hash.as_str()));
+
+        let hash = peppered.hash(plain).await.unwrap();
+        assert!(
+            peppered
+                .verify("testpassword123", hash.as_str())
+                .await
+                .unwrap()
+        );
+        assert!(!peppered.needs_rehash(hash.as_str()));
+        assert!(
+            !other_pepper
+                .verify("testpassword123", hash.as_str())
+                .await
+                .unwrap()
+        );
+        assert!(
+            plain_hasher
+                .verify("testpassword123", hash.as_str())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_needs_rehash_for_older_algorithm() {
+        let hasher = Argon2PasswordHasher::new();
+        let salt = SaltString::generate(&mut OsRng);
+        let argon2i = Argon2::new(Algorithm::Argon2i, Version::V0x13, Params::default())
+            .hash_password(b"testpassword123", &salt)
+            .unwrap()
+            .to_string();
+
+        assert!(hasher.verify("testpassword123", &argon2i).await.unwrap());
+        assert!(hasher.needs_rehash(&argon2i));
     }
 }
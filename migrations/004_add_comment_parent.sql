@@ -0,0 +1,15 @@
+-- Add parent reference to comments for threaded replies
+ALTER TABLE comments
+    ADD COLUMN IF NOT EXISTS parent_id UUID NULL;
+
+-- ADD CONSTRAINT has no IF NOT EXISTS, so check first to keep the migration re-runnable
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'fk_comments_parent') THEN
+        ALTER TABLE comments
+            ADD CONSTRAINT fk_comments_parent FOREIGN KEY (parent_id) REFERENCES comments(id) ON DELETE CASCADE;
+    END IF;
+END $$;
+
+-- Create index for fetching replies of a comment
+CREATE INDEX IF NOT EXISTS idx_comments_parent_id ON comments(parent_id);
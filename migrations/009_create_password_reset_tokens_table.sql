@@ -0,0 +1,22 @@
+-- Create password reset tokens table
+-- Only a SHA-256 hash of each token is stored; tokens are single use
+CREATE TABLE IF NOT EXISTS password_reset_tokens (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    used_at TIMESTAMPTZ NULL,
+    CONSTRAINT fk_password_reset_tokens_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Create index for looking up a user's tokens
+CREATE INDEX IF NOT EXISTS idx_password_reset_tokens_user_id ON password_reset_tokens(user_id);
+
+-- Per-user revocation cutoff: access tokens issued before revoked_before are rejected.
+-- One row per user, so there is nothing to purge.
+CREATE TABLE IF NOT EXISTS revoked_user_tokens (
+    user_id UUID PRIMARY KEY,
+    revoked_before TIMESTAMPTZ NOT NULL,
+    CONSTRAINT fk_revoked_user_tokens_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
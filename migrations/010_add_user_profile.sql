@@ -0,0 +1,5 @@
+-- Add public profile fields to users
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS display_name VARCHAR(50) NULL,
+    ADD COLUMN IF NOT EXISTS bio VARCHAR(500) NULL,
+    ADD COLUMN IF NOT EXISTS avatar_url VARCHAR(2048) NULL;
@@ -0,0 +1,11 @@
+-- Account deletion
+-- deletion_scheduled_for is set while an account waits out its grace period;
+-- deleted_at marks accounts that were anonymized and kept for their content
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS deletion_scheduled_for TIMESTAMPTZ NULL,
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ NULL;
+
+-- Create index for the purge job
+CREATE INDEX IF NOT EXISTS idx_users_deletion_scheduled_for
+    ON users(deletion_scheduled_for)
+    WHERE deletion_scheduled_for IS NOT NULL;
@@ -0,0 +1,34 @@
+-- Allow accounts without a password
+-- Users who sign up through an external identity provider have no password hash
+ALTER TABLE users ALTER COLUMN password_hash DROP NOT NULL;
+
+-- Create user identities table
+-- Links an account at an OpenID Connect provider (identified by its `sub` claim) to a user
+CREATE TABLE IF NOT EXISTS user_identities (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    provider VARCHAR(64) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    email VARCHAR(255) NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_user_identities_provider_subject UNIQUE (provider, subject),
+    CONSTRAINT fk_user_identities_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Create index for listing a user's identities
+CREATE INDEX IF NOT EXISTS idx_user_identities_user_id ON user_identities(user_id);
+
+-- Create OIDC authorizations table
+-- Sign-ins in progress, found by a SHA-256 hash of the state parameter; deleted when completed
+CREATE TABLE IF NOT EXISTS oidc_authorizations (
+    id UUID PRIMARY KEY,
+    state_hash TEXT NOT NULL UNIQUE,
+    provider VARCHAR(64) NOT NULL,
+    nonce TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create index for purging expired authorizations
+CREATE INDEX IF NOT EXISTS idx_oidc_authorizations_expires_at ON oidc_authorizations(expires_at);
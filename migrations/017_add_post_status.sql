@@ -0,0 +1,42 @@
+-- Add publication status to posts
+-- Existing posts were visible as soon as they were created, so they start out published.
+-- The backfill only runs together with adding the columns, so running this again
+-- cannot publish drafts.
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = current_schema() AND table_name = 'posts' AND column_name = 'status'
+    ) THEN
+        ALTER TABLE posts
+            ADD COLUMN status VARCHAR(20) NOT NULL DEFAULT 'draft',
+            ADD COLUMN published_at TIMESTAMPTZ NULL;
+
+        UPDATE posts SET status = 'published', published_at = created_at;
+    END IF;
+END $$;
+
+-- ADD CONSTRAINT has no IF NOT EXISTS, so check first to keep the migration re-runnable
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'chk_posts_status') THEN
+        ALTER TABLE posts
+            ADD CONSTRAINT chk_posts_status
+            CHECK (status IN ('draft', 'scheduled', 'published', 'archived'));
+    END IF;
+
+    -- Scheduled and published posts always have a publication time
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'chk_posts_published_at') THEN
+        ALTER TABLE posts
+            ADD CONSTRAINT chk_posts_published_at
+            CHECK (status = 'draft' OR published_at IS NOT NULL);
+    END IF;
+END $$;
+
+-- Create index for public listings
+CREATE INDEX IF NOT EXISTS idx_posts_published_created_at
+    ON posts(created_at DESC) WHERE status = 'published';
+
+-- Create index for finding scheduled posts that have come due
+CREATE INDEX IF NOT EXISTS idx_posts_scheduled_published_at
+    ON posts(published_at) WHERE status = 'scheduled';
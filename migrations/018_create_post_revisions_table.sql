@@ -0,0 +1,19 @@
+-- Create post revisions table
+-- Revisions are immutable snapshots of a post's title and content, numbered per post
+CREATE TABLE IF NOT EXISTS post_revisions (
+    id UUID PRIMARY KEY,
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    content TEXT NOT NULL,
+    editor_id UUID NOT NULL,
+    restored_from INTEGER NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_post_revisions_post_number UNIQUE (post_id, number)
+);
+
+-- Existing posts start their history with their current title and content
+INSERT INTO post_revisions (id, post_id, number, title, content, editor_id, created_at)
+SELECT gen_random_uuid(), id, 1, title, content, author_id, updated_at
+FROM posts
+WHERE NOT EXISTS (SELECT 1 FROM post_revisions WHERE post_revisions.post_id = posts.id);
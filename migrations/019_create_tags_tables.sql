@@ -0,0 +1,17 @@
+-- Create tags table
+-- Tags are stored as slugs (lowercase letters, digits and hyphens)
+CREATE TABLE IF NOT EXISTS tags (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    slug VARCHAR(32) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create post_tags join table
+CREATE TABLE IF NOT EXISTS post_tags (
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    tag_id UUID NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (post_id, tag_id)
+);
+
+-- Create index for finding the posts carrying a tag
+CREATE INDEX IF NOT EXISTS idx_post_tags_tag_id ON post_tags(tag_id);
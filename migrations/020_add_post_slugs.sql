@@ -0,0 +1,23 @@
+-- Add slugs to posts
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS slug VARCHAR(100) NULL;
+-- The number a slug ends in to tell it apart from another post's slug made from the same
+-- words, if it needed one
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS slug_suffix INTEGER NULL;
+
+-- Existing posts keep a NULL slug here. The post service gives them one when it starts,
+-- oldest first and with the same transliteration and suffixes as new posts; until then
+-- -- they read as having the slug made from their title. New posts always get a slug.
+CREATE INDEX IF NOT EXISTS idx_posts_without_slug ON posts(created_at, id) WHERE slug IS NULL;
+
+-- Create unique index for slug lookups
+CREATE UNIQUE INDEX IF NOT EXISTS idx_posts_slug ON posts(slug);
+
+-- Create slug history table
+-- Slugs a post had before its title changed, so that old links keep working
+CREATE TABLE IF NOT EXISTS post_slug_history (
+    slug VARCHAR(100) PRIMARY KEY,
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    retired_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_post_slug_history_post_id ON post_slug_history(post_id);
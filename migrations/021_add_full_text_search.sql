@@ -0,0 +1,58 @@
+-- Full-text search over posts and comments
+
+-- The text search configuration (language) used for both indexing and querying. The post
+-- service keeps it in line with SEARCH_LANGUAGE and re-indexes when that changes.
+CREATE TABLE IF NOT EXISTS search_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    language REGCONFIG NOT NULL DEFAULT 'english'
+);
+
+INSERT INTO search_settings (id) VALUES (TRUE) ON CONFLICT (id) DO NOTHING;
+
+-- Title words weigh more than content words
+CREATE OR REPLACE FUNCTION post_search_vector(title TEXT, content TEXT) RETURNS TSVECTOR AS $$
+    SELECT setweight(to_tsvector(s.language, COALESCE(title, '')), 'A')
+        || setweight(to_tsvector(s.language, COALESCE(content, '')), 'B')
+    FROM search_settings s
+$$ LANGUAGE SQL STABLE;
+
+CREATE OR REPLACE FUNCTION comment_search_vector(content TEXT) RETURNS TSVECTOR AS $$
+    SELECT to_tsvector(s.language, COALESCE(content, ''))
+    FROM search_settings s
+$$ LANGUAGE SQL STABLE;
+
+-- Add search vector columns, kept up to date by triggers
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+ALTER TABLE comments ADD COLUMN IF NOT EXISTS search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+
+CREATE OR REPLACE FUNCTION posts_search_vector_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector := post_search_vector(NEW.title, NEW.content);
+    RETURN NEW;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION comments_search_vector_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector := comment_search_vector(NEW.content);
+    RETURN NEW;
+END
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_posts_search_vector ON posts;
+CREATE TRIGGER trg_posts_search_vector
+    BEFORE INSERT OR UPDATE OF title, content ON posts
+    FOR EACH ROW EXECUTE FUNCTION posts_search_vector_trigger();
+
+DROP TRIGGER IF EXISTS trg_comments_search_vector ON comments;
+CREATE TRIGGER trg_comments_search_vector
+    BEFORE INSERT OR UPDATE OF content ON comments
+    FOR EACH ROW EXECUTE FUNCTION comments_search_vector_trigger();
+
+-- Index existing rows
+UPDATE posts SET search_vector = post_search_vector(title, content);
+UPDATE comments SET search_vector = comment_search_vector(content);
+
+-- Create GIN indexes for search
+CREATE INDEX IF NOT EXISTS idx_posts_search_vector ON posts USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_comments_search_vector ON comments USING GIN (search_vector);
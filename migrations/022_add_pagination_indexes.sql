@@ -0,0 +1,18 @@
+-- Indexes matching the (created_at, id) order that listings are paged by
+
+-- Create index for public listings
+DROP INDEX IF EXISTS idx_posts_published_created_at;
+CREATE INDEX IF NOT EXISTS idx_posts_published_created_at_id
+    ON posts(created_at DESC, id DESC) WHERE status = 'published';
+
+-- Create index for listing an author's posts
+CREATE INDEX IF NOT EXISTS idx_posts_author_created_at_id
+    ON posts(author_id, created_at DESC, id DESC);
+
+-- Create index for listing a post's comments
+CREATE INDEX IF NOT EXISTS idx_comments_post_created_at_id
+    ON comments(post_id, created_at, id);
+
+-- Create index for listing an author's comments
+CREATE INDEX IF NOT EXISTS idx_comments_author_created_at_id
+    ON comments(author_id, created_at DESC, id DESC);
@@ -1,9 +1,11 @@
 // services/auth/src/adapters/jwt_adapter.rs
 
-use application::users::TokenGenerator;
+use application::users::{AccessToken, TokenGenerator};
 use domain::common::error::DomainError;
-use infrastructure::security::{Claims, JwtService};
+use domain::users::User;
+use infrastructure::security::{Claims, JwkSet, JwtService};
 use std::sync::Arc;
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct JwtAdapter {
@@ -18,14 +20,15 @@ impl JwtAdapter {
     pub fn verify_token(&self, token: &str) -> Result<Claims, DomainError> {
         self.jwt_service.verify_token(token)
     }
+
+    pub fn jwks(&self) -> JwkSet {
+        self.jwt_service.jwks()
+    }
 }
 
 impl TokenGenerator for JwtAdapter {
-    fn generate(&self, user_id: uuid::Uuid, email: &str) -> Result<String, DomainError> {
-        self.jwt_service.generate_token(user_id, email)
+    fn generate(&self, user: &User, session_id: Uuid) -> Result<AccessToken, DomainError> {
+        let (token, expires_at) = self.jwt_service.generate_token(user, session_id)?;
+        Ok(AccessToken { token, expires_at })
     }
 }
-
-
-
-
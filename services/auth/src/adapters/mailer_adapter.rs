@@ -0,0 +1,36 @@
+// services/auth/src/adapters/mailer_adapter.rs
+
+use application::users::{EmailMessage, Mailer};
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use infrastructure::mail::{FileMailer, SmtpMailer};
+use std::sync::Arc;
+
+/// Outbound mail transport selected by `MAIL_TRANSPORT`
+#[derive(Clone)]
+pub enum MailerAdapter {
+    Smtp(Arc<SmtpMailer>),
+    File(Arc<FileMailer>),
+}
+
+#[async_trait]
+impl Mailer for MailerAdapter {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError> {
+        let result = match self {
+            MailerAdapter::Smtp(mailer) => {
+                mailer
+                    .send(&message.to, &message.subject, &message.body)
+                    .await
+            }
+            MailerAdapter::File(mailer) => mailer
+                .send(&message.to, &message.subject, &message.body)
+                .await
+                .map(|path| tracing::info!("Wrote email for {} to {}", message.to, path.display())),
+        };
+
+        if let Err(e) = &result {
+            tracing::error!("Failed to send email to {}: {}", message.to, e);
+        }
+        result
+    }
+}
@@ -1,8 +1,15 @@
 // services/auth/src/adapters/mod.rs
 
 mod jwt_adapter;
+mod mailer_adapter;
+mod oidc_adapter;
+mod opaque_token_adapter;
 mod password_adapter;
+mod totp_adapter;
 
 pub use jwt_adapter::JwtAdapter;
+pub use mailer_adapter::MailerAdapter;
+pub use oidc_adapter::OidcAdapter;
+pub use opaque_token_adapter::OpaqueTokenAdapter;
 pub use password_adapter::PasswordHasherAdapter;
-
+pub use totp_adapter::TotpAdapter;
@@ -0,0 +1,54 @@
+// services/auth/src/adapters/oidc_adapter.rs
+
+use application::users::{OidcClaims, OidcProvider};
+use async_trait::async_trait;
+use domain::common::error::DomainError;
+use infrastructure::security::OidcClient;
+
+#[derive(Clone)]
+pub struct OidcAdapter {
+    client: OidcClient,
+}
+
+impl OidcAdapter {
+    pub fn new(client: OidcClient) -> Self {
+        Self { client }
+    }
+
+    pub fn provider_names(&self) -> Vec<String> {
+        self.client.provider_names()
+    }
+}
+
+#[async_trait]
+impl OidcProvider for OidcAdapter {
+    fn authorization_url(
+        &self,
+        provider: &str,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String, DomainError> {
+        self.client
+            .authorization_url(provider, state, nonce, code_verifier)
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &str,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<OidcClaims, DomainError> {
+        let claims = self
+            .client
+            .exchange_code(provider, code, code_verifier)
+            .await?;
+
+        Ok(OidcClaims {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified: claims.email_verified,
+            nonce: claims.nonce,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+// services/auth/src/adapters/opaque_token_adapter.rs
+
+use application::users::OpaqueTokenGenerator;
+use infrastructure::security::OpaqueTokenService;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct OpaqueTokenAdapter {
+    service: Arc<OpaqueTokenService>,
+}
+
+impl OpaqueTokenAdapter {
+    pub fn new(service: Arc<OpaqueTokenService>) -> Self {
+        Self { service }
+    }
+}
+
+impl OpaqueTokenGenerator for OpaqueTokenAdapter {
+    fn generate(&self) -> String {
+        self.service.generate()
+    }
+
+    fn hash(&self, token: &str) -> String {
+        self.service.hash(token)
+    }
+}
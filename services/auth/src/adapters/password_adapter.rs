@@ -18,9 +18,18 @@ impl PasswordHasherAdapter {
     }
 }
 
+#[async_trait]
 impl PasswordVerifier for PasswordHasherAdapter {
-    fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError> {
-        self.hasher.verify(plain_password, password_hash)
+    async fn verify(&self, plain_password: &str, password_hash: &str) -> Result<bool, DomainError> {
+        self.hasher.verify(plain_password, password_hash).await
+    }
+
+    async fn verify_dummy(&self, plain_password: &str) -> Result<(), DomainError> {
+        self.hasher.verify_dummy(plain_password).await
+    }
+
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        self.hasher.needs_rehash(password_hash)
     }
 }
 
@@ -30,7 +39,3 @@ impl PasswordHasher for PasswordHasherAdapter {
         self.hasher.hash(password).await
     }
 }
-
-
-
-
@@ -0,0 +1,35 @@
+// services/auth/src/adapters/totp_adapter.rs
+
+use application::users::TotpProvider;
+use chrono::{DateTime, Utc};
+use infrastructure::security::TotpService;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TotpAdapter {
+    service: Arc<TotpService>,
+}
+
+impl TotpAdapter {
+    pub fn new(service: Arc<TotpService>) -> Self {
+        Self { service }
+    }
+}
+
+impl TotpProvider for TotpAdapter {
+    fn generate_secret(&self) -> String {
+        self.service.generate_secret()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> String {
+        self.service.provisioning_uri(secret, account_name)
+    }
+
+    fn verify(&self, secret: &str, code: &str, now: DateTime<Utc>) -> Option<i64> {
+        self.service.verify(secret, code, now)
+    }
+
+    fn generate_recovery_code(&self) -> String {
+        self.service.generate_recovery_code()
+    }
+}
@@ -1,45 +1,45 @@
 // services/auth/src/graphql/handler.rs
 
-use async_graphql::http::{playground_source, GraphQLPlaygroundConfig};
+use async_graphql::http::{GraphQLPlaygroundConfig, playground_source};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use axum::{
-    extract::State,
-    http::{header::AUTHORIZATION, HeaderMap},
-    response::{Html, IntoResponse},
     Extension,
+    extract::{ConnectInfo, State},
+    http::HeaderMap,
+    response::{Html, IntoResponse},
 };
-use uuid::Uuid;
+use std::net::SocketAddr;
 
-use crate::AppState;
+use crate::{
+    AppState,
+    middleware::{authenticate, client_info},
+};
 
 use super::schema::GraphQLSchema;
 
 /// GraphQL query handler
 pub async fn graphql_handler(
     State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     schema: Extension<GraphQLSchema>,
     headers: HeaderMap,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
     let mut request = req.into_inner();
-    
+
     // Add app state to context
     request = request.data(state.clone());
-    
-    // Try to extract and verify JWT token if present
-    if let Some(auth_header) = headers.get(AUTHORIZATION) {
-        if let Ok(auth_str) = auth_header.to_str() {
-            if let Some(token) = auth_str.strip_prefix("Bearer ") {
-                // Verify the token and extract user_id
-                if let Ok(claims) = state.jwt_service.verify_token(token) {
-                    if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                        request = request.data(user_id);
-                    }
-                }
-            }
-        }
+
+    // The caller's address and user agent, which login throttling counts failures
+    // against and new sessions record
+    request = request.data(client_info(&headers, peer, state.trust_forwarded_for));
+
+    // Attach the authenticated user if a valid token is present; operations that
+    // need one fail without it
+    if let Ok(auth_user) = authenticate(&state, &headers).await {
+        request = request.data(auth_user);
     }
-    
+
     schema.execute(request).await.into()
 }
 
@@ -8,3 +8,13 @@ mod types;
 
 pub use handler::{graphql_handler, graphql_playground};
 pub use schema::build_schema;
+
+use async_graphql::{Context, Result};
+
+use crate::middleware::AuthUser;
+
+/// The user attached by the GraphQL handler when the request carried a valid token
+fn authenticated_user<'a>(ctx: &Context<'a>) -> Result<&'a AuthUser> {
+    ctx.data::<AuthUser>()
+        .map_err(|_| async_graphql::Error::new("Unauthorized"))
+}
@@ -1,10 +1,21 @@
 // services/auth/src/graphql/mutations.rs
 
-use super::types::AuthResponse;
+use super::authenticated_user;
+use super::types::{
+    AuthResponse, ChangeEmailResponse, CreatedApiKey, CurrentUser, LoginResult, OidcAuthorization,
+    RecoveryCodes, TotpEnrollment,
+};
 use application::users::{
-    LoginUserRequest, LoginUserUseCase, RegisterUserRequest, RegisterUserUseCase,
+    ChangeEmailRequest, ChangeEmailUseCase, ChangePasswordRequest, ChangePasswordUseCase,
+    ClientInfo, ConfirmTotpRequest, ConfirmTotpUseCase, CreateApiKeyRequest, CreateApiKeyUseCase,
+    DisableTotpRequest, DisableTotpUseCase, EnrollTotpUseCase, LoginUserRequest, LoginUserUseCase,
+    LogoutEverywhereUseCase, OidcCallbackRequest, OidcLoginUseCase, RefreshTokenRequest,
+    RefreshTokenUseCase, RegisterUserRequest, RegisterUserUseCase, RevokeApiKeyUseCase,
+    RevokeSessionUseCase, StartOidcLoginUseCase, UpdateProfileRequest, UpdateProfileUseCase,
+    VerifyMfaRequest, VerifyMfaUseCase,
 };
 use async_graphql::{Context, Object, Result};
+use uuid::Uuid;
 
 use crate::AppState;
 
@@ -13,7 +24,12 @@ pub struct MutationRoot;
 #[Object]
 impl MutationRoot {
     /// Register a new user
-    async fn register(&self, ctx: &Context<'_>, email: String, password: String) -> Result<AuthResponse> {
+    async fn register(
+        &self,
+        ctx: &Context<'_>,
+        email: String,
+        password: String,
+    ) -> Result<AuthResponse> {
         let state = ctx.data::<AppState>()?;
 
         let request = RegisterUserRequest { email, password };
@@ -21,23 +37,35 @@ impl MutationRoot {
         let use_case = RegisterUserUseCase::new(
             state.user_repository.clone(),
             state.password_hasher.clone(),
-            state.jwt_service.clone(),
+            state.token_issuer.clone(),
+            state.email_verification.clone(),
         );
 
         let response = use_case
-            .execute(request)
+            .execute(request, &client_info(ctx))
             .await
             .map_err(|e| async_graphql::Error::new(format!("Registration failed: {}", e)))?;
 
         Ok(AuthResponse {
             user_id: response.user_id,
             email: response.email,
+            role: response.role,
+            email_verified: response.email_verified,
             token: response.token,
+            token_expires_at: response.token_expires_at,
+            refresh_token: response.refresh_token,
+            refresh_token_expires_at: response.refresh_token_expires_at,
         })
     }
 
-    /// Login an existing user
-    async fn login(&self, ctx: &Context<'_>, email: String, password: String) -> Result<AuthResponse> {
+    /// Login an existing user. Accounts with two-factor authentication get an
+    /// MfaChallenge to answer with verifyMfa instead of tokens.
+    async fn login(
+        &self,
+        ctx: &Context<'_>,
+        email: String,
+        password: String,
+    ) -> Result<LoginResult> {
         let state = ctx.data::<AppState>()?;
 
         let request = LoginUserRequest { email, password };
@@ -45,18 +73,397 @@ impl MutationRoot {
         let use_case = LoginUserUseCase::new(
             state.user_repository.clone(),
             state.password_hasher.clone(),
-            state.jwt_service.clone(),
+            state.token_issuer.clone(),
+            state.login_attempts.clone(),
+            state.mfa.clone(),
+            state.login_throttle,
+        );
+
+        let outcome = use_case
+            .execute(request, &client_info(ctx))
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Login failed: {}", e)))?;
+
+        Ok(outcome.into())
+    }
+
+    /// Start signing in with an external identity provider; send the browser to the
+    /// returned authorization_url
+    async fn start_oidc_login(
+        &self,
+        ctx: &Context<'_>,
+        provider: String,
+    ) -> Result<OidcAuthorization> {
+        let state = ctx.data::<AppState>()?;
+
+        let use_case = StartOidcLoginUseCase::new(
+            state.oidc_authorizations.clone(),
+            state.oidc.clone(),
+            state.opaque_tokens.clone(),
+            state.oidc_state_ttl,
         );
 
+        let response = use_case
+            .execute(&provider)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("OIDC login failed: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Finish signing in with an external identity provider, with the code and state it
+    /// redirected back with
+    async fn oidc_login(
+        &self,
+        ctx: &Context<'_>,
+        provider: String,
+        code: String,
+        #[graphql(name = "state")] oidc_state: String,
+    ) -> Result<LoginResult> {
+        let state = ctx.data::<AppState>()?;
+
+        let request = OidcCallbackRequest {
+            code,
+            state: oidc_state,
+        };
+
+        let use_case = OidcLoginUseCase::new(
+            state.user_repository.clone(),
+            state.user_identities.clone(),
+            state.oidc_authorizations.clone(),
+            state.oidc.clone(),
+            state.opaque_tokens.clone(),
+            state.token_issuer.clone(),
+            state.mfa.clone(),
+        );
+
+        let outcome = use_case
+            .execute(&provider, request, &client_info(ctx))
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("OIDC login failed: {}", e)))?;
+
+        Ok(outcome.into())
+    }
+
+    /// Finish a two-factor login with an authenticator or recovery code
+    async fn verify_mfa(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "mfa_token")] mfa_token: String,
+        code: String,
+    ) -> Result<AuthResponse> {
+        let state = ctx.data::<AppState>()?;
+
+        let request = VerifyMfaRequest { mfa_token, code };
+
+        let use_case = VerifyMfaUseCase::new(
+            state.user_repository.clone(),
+            state.mfa.clone(),
+            state.token_issuer.clone(),
+            state.login_attempts.clone(),
+            state.login_throttle,
+        );
+
+        let response = use_case
+            .execute(request, &client_info(ctx))
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("MFA verification failed: {}", e)))?;
+
+        Ok(AuthResponse {
+            user_id: response.user_id,
+            email: response.email,
+            role: response.role,
+            email_verified: response.email_verified,
+            token: response.token,
+            token_expires_at: response.token_expires_at,
+            refresh_token: response.refresh_token,
+            refresh_token_expires_at: response.refresh_token_expires_at,
+        })
+    }
+
+    /// Exchange a refresh token for a new token pair
+    async fn refresh_token(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "refresh_token")] refresh_token: String,
+    ) -> Result<AuthResponse> {
+        let state = ctx.data::<AppState>()?;
+
+        let request = RefreshTokenRequest { refresh_token };
+
+        let use_case =
+            RefreshTokenUseCase::new(state.user_repository.clone(), state.token_issuer.clone());
+
         let response = use_case
             .execute(request)
             .await
-            .map_err(|e| async_graphql::Error::new(format!("Login failed: {}", e)))?;
+            .map_err(|e| async_graphql::Error::new(format!("Token refresh failed: {}", e)))?;
 
         Ok(AuthResponse {
             user_id: response.user_id,
             email: response.email,
+            role: response.role,
+            email_verified: response.email_verified,
             token: response.token,
+            token_expires_at: response.token_expires_at,
+            refresh_token: response.refresh_token,
+            refresh_token_expires_at: response.refresh_token_expires_at,
+        })
+    }
+
+    /// Change the authenticated user's password; returns a fresh token pair
+    async fn change_password(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "current_password")] current_password: String,
+        #[graphql(name = "new_password")] new_password: String,
+    ) -> Result<AuthResponse> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = ChangePasswordRequest {
+            current_password,
+            new_password,
+        };
+
+        let use_case = ChangePasswordUseCase::new(
+            state.user_repository.clone(),
+            state.password_hasher.clone(),
+            state.password_hasher.clone(),
+            state.revoked_tokens.clone(),
+            state.token_issuer.clone(),
+        );
+
+        let response = use_case
+            .execute(request, auth_user.user_id, &client_info(ctx))
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Password change failed: {}", e)))?;
+
+        Ok(AuthResponse {
+            user_id: response.user_id,
+            email: response.email,
+            role: response.role,
+            email_verified: response.email_verified,
+            token: response.token,
+            token_expires_at: response.token_expires_at,
+            refresh_token: response.refresh_token,
+            refresh_token_expires_at: response.refresh_token_expires_at,
+        })
+    }
+
+    /// Change the authenticated user's email address
+    async fn change_email(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "new_email")] new_email: String,
+        password: String,
+    ) -> Result<ChangeEmailResponse> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = ChangeEmailRequest {
+            new_email,
+            password,
+        };
+
+        let use_case = ChangeEmailUseCase::new(
+            state.user_repository.clone(),
+            state.password_hasher.clone(),
+            state.email_verification.clone(),
+        );
+
+        let response = use_case
+            .execute(request, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Email change failed: {}", e)))?;
+
+        Ok(ChangeEmailResponse {
+            user_id: response.user_id,
+            email: response.email,
+            email_verified: response.email_verified,
         })
     }
+
+    /// Update the authenticated user's profile. Omitted fields are kept, empty
+    /// strings clear them.
+    #[graphql(name = "updateProfile")]
+    async fn update_profile(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "display_name")] display_name: Option<String>,
+        bio: Option<String>,
+        #[graphql(name = "avatar_url")] avatar_url: Option<String>,
+    ) -> Result<CurrentUser> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = UpdateProfileRequest {
+            display_name,
+            bio,
+            avatar_url,
+        };
+
+        let use_case = UpdateProfileUseCase::new(state.user_repository.clone());
+
+        let response = use_case
+            .execute(request, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Profile update failed: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Start TOTP enrolment for the authenticated user
+    async fn enroll_totp(&self, ctx: &Context<'_>) -> Result<TotpEnrollment> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let use_case = EnrollTotpUseCase::new(
+            state.user_repository.clone(),
+            state.totp_credentials.clone(),
+            state.totp.clone(),
+        );
+
+        let response = use_case
+            .execute(auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("TOTP enrolment failed: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Confirm TOTP enrolment with a first code; returns one-time recovery codes
+    async fn confirm_totp(&self, ctx: &Context<'_>, code: String) -> Result<RecoveryCodes> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = ConfirmTotpRequest { code };
+
+        let use_case = ConfirmTotpUseCase::new(
+            state.totp_credentials.clone(),
+            state.totp.clone(),
+            state.opaque_tokens.clone(),
+        );
+
+        let response = use_case
+            .execute(request, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("TOTP confirmation failed: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Turn off two-factor authentication for the authenticated user
+    async fn disable_totp(&self, ctx: &Context<'_>, password: String) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = DisableTotpRequest { password };
+
+        let use_case = DisableTotpUseCase::new(
+            state.user_repository.clone(),
+            state.password_hasher.clone(),
+            state.totp_credentials.clone(),
+        );
+
+        use_case
+            .execute(request, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Disabling TOTP failed: {}", e)))?;
+
+        Ok(true)
+    }
+
+    /// Create a personal API key; the key is only returned here
+    async fn create_api_key(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        scopes: Vec<String>,
+        #[graphql(name = "expires_in_days")] expires_in_days: Option<u32>,
+    ) -> Result<CreatedApiKey> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let request = CreateApiKeyRequest {
+            name,
+            scopes,
+            expires_in_days,
+        };
+
+        let use_case = CreateApiKeyUseCase::new(
+            state.user_repository.clone(),
+            state.api_keys.clone(),
+            state.opaque_tokens.clone(),
+        );
+
+        let response = use_case
+            .execute(request, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Creating API key failed: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Revoke one of the authenticated user's API keys
+    async fn revoke_api_key(&self, ctx: &Context<'_>, id: String) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+        let key_id =
+            Uuid::parse_str(&id).map_err(|_| async_graphql::Error::new("Invalid API key ID"))?;
+
+        let use_case = RevokeApiKeyUseCase::new(state.api_keys.clone());
+
+        use_case
+            .execute(key_id, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Revoking API key failed: {}", e)))?;
+
+        Ok(true)
+    }
+
+    /// Sign one of the authenticated user's devices out
+    async fn revoke_session(&self, ctx: &Context<'_>, id: String) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+        let session_id =
+            Uuid::parse_str(&id).map_err(|_| async_graphql::Error::new("Invalid session ID"))?;
+
+        let use_case = RevokeSessionUseCase::new(
+            state.sessions.clone(),
+            state.refresh_tokens.clone(),
+            state.revoked_tokens.clone(),
+            state.access_token_ttl,
+        );
+
+        use_case
+            .execute(session_id, auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Revoking session failed: {}", e)))?;
+
+        Ok(true)
+    }
+
+    /// Sign the authenticated user out of every device, including this one
+    async fn logout_everywhere(&self, ctx: &Context<'_>) -> Result<bool> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let use_case = LogoutEverywhereUseCase::new(
+            state.refresh_tokens.clone(),
+            state.revoked_tokens.clone(),
+        );
+
+        use_case
+            .execute(auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Logout failed: {}", e)))?;
+
+        Ok(true)
+    }
+}
+
+/// Where the request comes from, as put in the context by the GraphQL handler
+fn client_info(ctx: &Context<'_>) -> ClientInfo {
+    ctx.data_opt::<ClientInfo>().cloned().unwrap_or_default()
 }
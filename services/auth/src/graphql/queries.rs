@@ -1,6 +1,10 @@
 // services/auth/src/graphql/queries.rs
 
-use async_graphql::{Object, Result};
+use super::authenticated_user;
+use super::types::{ApiKey, CurrentUser, Profile, Session};
+use application::users::{GetProfileUseCase, ListApiKeysUseCase, ListSessionsUseCase};
+use async_graphql::{Context, Object, Result};
+use uuid::Uuid;
 
 use crate::AppState;
 
@@ -12,4 +16,70 @@ impl QueryRoot {
     async fn health(&self) -> &str {
         "healthy"
     }
+
+    /// The authenticated user's account
+    async fn me(&self, ctx: &Context<'_>) -> Result<CurrentUser> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let use_case = GetProfileUseCase::new(state.user_repository.clone());
+
+        let response = use_case
+            .execute_current(auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load user: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// Any user's public profile
+    async fn profile(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(name = "user_id")] user_id: String,
+    ) -> Result<Profile> {
+        let state = ctx.data::<AppState>()?;
+        let user_id =
+            Uuid::parse_str(&user_id).map_err(|_| async_graphql::Error::new("Invalid user ID"))?;
+
+        let use_case = GetProfileUseCase::new(state.user_repository.clone());
+
+        let response = use_case
+            .execute(user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load profile: {}", e)))?;
+
+        Ok(response.into())
+    }
+
+    /// The authenticated user's API keys, newest first
+    #[graphql(name = "api_keys")]
+    async fn api_keys(&self, ctx: &Context<'_>) -> Result<Vec<ApiKey>> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let use_case = ListApiKeysUseCase::new(state.api_keys.clone());
+
+        let keys = use_case
+            .execute(auth_user.user_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load API keys: {}", e)))?;
+
+        Ok(keys.into_iter().map(Into::into).collect())
+    }
+
+    /// The devices the authenticated user is signed in on, most recently seen first
+    async fn sessions(&self, ctx: &Context<'_>) -> Result<Vec<Session>> {
+        let state = ctx.data::<AppState>()?;
+        let auth_user = authenticated_user(ctx)?;
+
+        let use_case = ListSessionsUseCase::new(state.sessions.clone());
+
+        let sessions = use_case
+            .execute(auth_user.user_id, auth_user.session_id)
+            .await
+            .map_err(|e| async_graphql::Error::new(format!("Failed to load sessions: {}", e)))?;
+
+        Ok(sessions.into_iter().map(Into::into).collect())
+    }
 }
@@ -1,12 +1,268 @@
 // services/auth/src/graphql/types.rs
 
-use async_graphql::SimpleObject;
+use application::users::{
+    ApiKeyResponse, CreateApiKeyResponse, CurrentUserResponse, LoginOutcome, MfaChallengeResponse,
+    OidcAuthorizationResponse, ProfileResponse, RecoveryCodesResponse, SessionResponse,
+    TotpEnrollmentResponse,
+};
+use async_graphql::{SimpleObject, Union};
 
-/// Response for register and login mutations
+/// Response for register, login, refreshToken and changePassword mutations
 #[derive(SimpleObject, Clone)]
 pub struct AuthResponse {
     #[graphql(name = "user_id")]
     pub user_id: String,
     pub email: String,
+    pub role: String,
+    #[graphql(name = "email_verified")]
+    pub email_verified: bool,
     pub token: String,
+    #[graphql(name = "token_expires_at")]
+    pub token_expires_at: String,
+    #[graphql(name = "refresh_token")]
+    pub refresh_token: String,
+    #[graphql(name = "refresh_token_expires_at")]
+    pub refresh_token_expires_at: String,
+}
+
+/// Second step required by the login mutation, answered with verifyMfa
+#[derive(SimpleObject, Clone)]
+pub struct MfaChallenge {
+    #[graphql(name = "mfa_token")]
+    pub mfa_token: String,
+    #[graphql(name = "mfa_token_expires_at")]
+    pub mfa_token_expires_at: String,
+}
+
+impl From<MfaChallengeResponse> for MfaChallenge {
+    fn from(response: MfaChallengeResponse) -> Self {
+        Self {
+            mfa_token: response.mfa_token,
+            mfa_token_expires_at: response.mfa_token_expires_at,
+        }
+    }
+}
+
+/// Response for the login mutation: tokens, or a challenge for accounts with
+/// two-factor authentication
+#[derive(Union, Clone)]
+pub enum LoginResult {
+    Authenticated(AuthResponse),
+    MfaRequired(MfaChallenge),
+}
+
+impl From<LoginOutcome> for LoginResult {
+    fn from(outcome: LoginOutcome) -> Self {
+        match outcome {
+            LoginOutcome::Authenticated(response) => LoginResult::Authenticated(AuthResponse {
+                user_id: response.user_id,
+                email: response.email,
+                role: response.role,
+                email_verified: response.email_verified,
+                token: response.token,
+                token_expires_at: response.token_expires_at,
+                refresh_token: response.refresh_token,
+                refresh_token_expires_at: response.refresh_token_expires_at,
+            }),
+            LoginOutcome::MfaRequired(challenge) => LoginResult::MfaRequired(challenge.into()),
+        }
+    }
+}
+
+/// Response for the startOidcLogin mutation
+#[derive(SimpleObject, Clone)]
+pub struct OidcAuthorization {
+    #[graphql(name = "authorization_url")]
+    pub authorization_url: String,
+    pub state: String,
+    #[graphql(name = "expires_at")]
+    pub expires_at: String,
+}
+
+impl From<OidcAuthorizationResponse> for OidcAuthorization {
+    fn from(response: OidcAuthorizationResponse) -> Self {
+        Self {
+            authorization_url: response.authorization_url,
+            state: response.state,
+            expires_at: response.expires_at,
+        }
+    }
+}
+
+/// Response for the enrollTotp mutation
+#[derive(SimpleObject, Clone)]
+pub struct TotpEnrollment {
+    pub secret: String,
+    #[graphql(name = "otpauth_uri")]
+    pub otpauth_uri: String,
+}
+
+impl From<TotpEnrollmentResponse> for TotpEnrollment {
+    fn from(response: TotpEnrollmentResponse) -> Self {
+        Self {
+            secret: response.secret,
+            otpauth_uri: response.otpauth_uri,
+        }
+    }
+}
+
+/// Response for the confirmTotp mutation; the codes are shown only once
+#[derive(SimpleObject, Clone)]
+pub struct RecoveryCodes {
+    #[graphql(name = "recovery_codes")]
+    pub recovery_codes: Vec<String>,
+}
+
+impl From<RecoveryCodesResponse> for RecoveryCodes {
+    fn from(response: RecoveryCodesResponse) -> Self {
+        Self {
+            recovery_codes: response.recovery_codes,
+        }
+    }
+}
+
+/// Response for the changeEmail mutation
+#[derive(SimpleObject, Clone)]
+pub struct ChangeEmailResponse {
+    #[graphql(name = "user_id")]
+    pub user_id: String,
+    pub email: String,
+    #[graphql(name = "email_verified")]
+    pub email_verified: bool,
+}
+
+/// A user's public profile, returned by the profile query
+#[derive(SimpleObject, Clone)]
+pub struct Profile {
+    #[graphql(name = "user_id")]
+    pub user_id: String,
+    #[graphql(name = "display_name")]
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    #[graphql(name = "avatar_url")]
+    pub avatar_url: Option<String>,
+}
+
+impl From<ProfileResponse> for Profile {
+    fn from(response: ProfileResponse) -> Self {
+        Self {
+            user_id: response.user_id,
+            display_name: response.display_name,
+            bio: response.bio,
+            avatar_url: response.avatar_url,
+        }
+    }
+}
+
+/// The authenticated user's account, returned by the me query and updateProfile mutation
+#[derive(SimpleObject, Clone)]
+pub struct CurrentUser {
+    #[graphql(name = "user_id")]
+    pub user_id: String,
+    pub email: String,
+    pub role: String,
+    #[graphql(name = "email_verified")]
+    pub email_verified: bool,
+    #[graphql(name = "display_name")]
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    #[graphql(name = "avatar_url")]
+    pub avatar_url: Option<String>,
+    #[graphql(name = "deletion_scheduled_for")]
+    pub deletion_scheduled_for: Option<String>,
+    #[graphql(name = "created_at")]
+    pub created_at: String,
+}
+
+impl From<CurrentUserResponse> for CurrentUser {
+    fn from(response: CurrentUserResponse) -> Self {
+        Self {
+            user_id: response.user_id,
+            email: response.email,
+            role: response.role,
+            email_verified: response.email_verified,
+            display_name: response.display_name,
+            bio: response.bio,
+            avatar_url: response.avatar_url,
+            deletion_scheduled_for: response.deletion_scheduled_for,
+            created_at: response.created_at,
+        }
+    }
+}
+
+/// A personal API key, without the key itself
+#[derive(SimpleObject, Clone)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    #[graphql(name = "expires_at")]
+    pub expires_at: Option<String>,
+    #[graphql(name = "last_used_at")]
+    pub last_used_at: Option<String>,
+    #[graphql(name = "created_at")]
+    pub created_at: String,
+    #[graphql(name = "revoked_at")]
+    pub revoked_at: Option<String>,
+}
+
+impl From<ApiKeyResponse> for ApiKey {
+    fn from(response: ApiKeyResponse) -> Self {
+        Self {
+            id: response.id,
+            name: response.name,
+            prefix: response.prefix,
+            scopes: response.scopes,
+            expires_at: response.expires_at,
+            last_used_at: response.last_used_at,
+            created_at: response.created_at,
+            revoked_at: response.revoked_at,
+        }
+    }
+}
+
+/// Response for the createApiKey mutation; the key is shown only once
+#[derive(SimpleObject, Clone)]
+pub struct CreatedApiKey {
+    pub key: String,
+    #[graphql(name = "api_key")]
+    pub api_key: ApiKey,
+}
+
+impl From<CreateApiKeyResponse> for CreatedApiKey {
+    fn from(response: CreateApiKeyResponse) -> Self {
+        Self {
+            key: response.key,
+            api_key: response.api_key.into(),
+        }
+    }
+}
+
+/// A device the user is signed in on
+#[derive(SimpleObject, Clone)]
+pub struct Session {
+    pub id: String,
+    #[graphql(name = "user_agent")]
+    pub user_agent: Option<String>,
+    #[graphql(name = "ip_address")]
+    pub ip_address: Option<String>,
+    #[graphql(name = "created_at")]
+    pub created_at: String,
+    #[graphql(name = "last_seen_at")]
+    pub last_seen_at: String,
+    pub current: bool,
+}
+
+impl From<SessionResponse> for Session {
+    fn from(response: SessionResponse) -> Self {
+        Self {
+            id: response.id,
+            user_agent: response.user_agent,
+            ip_address: response.ip_address,
+            created_at: response.created_at,
+            last_seen_at: response.last_seen_at,
+            current: response.current,
+        }
+    }
 }
@@ -1,53 +1,211 @@
 // services/auth/src/handlers/auth_handlers.rs
 
 use application::users::{
-    LoginUserRequest, LoginUserUseCase, RegisterUserRequest, RegisterUserUseCase,
+    ChangeEmailRequest, ChangeEmailUseCase, ChangePasswordRequest, ChangePasswordUseCase,
+    ChangeRoleRequest, ChangeRoleUseCase, ConfirmTotpRequest, ConfirmTotpUseCase,
+    CreateApiKeyRequest, CreateApiKeyUseCase, DeleteAccountRequest, DeleteAccountUseCase,
+    DisableTotpRequest, DisableTotpUseCase, EnrollTotpUseCase, ExportUserDataUseCase,
+    GetProfileUseCase, ListApiKeysUseCase, ListSessionsUseCase, LoginOutcome, LoginUserRequest,
+    LoginUserUseCase, LogoutEverywhereUseCase, LogoutUserRequest, LogoutUserUseCase,
+    OidcCallbackRequest, OidcLoginUseCase, RefreshTokenRequest, RefreshTokenUseCase,
+    RegisterUserRequest, RegisterUserUseCase, RequestPasswordResetRequest,
+    RequestPasswordResetUseCase, ResendVerificationEmailUseCase, ResetPasswordRequest,
+    ResetPasswordUseCase, RevokeApiKeyUseCase, RevokeSessionUseCase, StartOidcLoginUseCase,
+    UpdateProfileRequest, UpdateProfileUseCase, VerifyEmailRequest, VerifyEmailUseCase,
+    VerifyMfaRequest, VerifyMfaUseCase,
 };
-use axum::{extract::State, http::StatusCode, Json};
-use serde_json::{json, Value};
+use axum::{
+    Extension, Json,
+    extract::{ConnectInfo, Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+};
+use serde_json::{Value, json};
+use std::net::SocketAddr;
+use uuid::Uuid;
 
 use super::error_response::AppError;
-use crate::AppState;
+use crate::{
+    AppState,
+    middleware::{AuthUser, client_info},
+};
 
 /// Health check endpoint
 pub async fn health_handler() -> (StatusCode, Json<Value>) {
     (StatusCode::OK, Json(json!({"status": "healthy"})))
 }
 
+/// Public keys for verifying access tokens (JWKS)
+pub async fn jwks_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(state.jwt_service.jwks()),
+    )
+}
+
 /// Register a new user
 pub async fn register_handler(
     State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(request): Json<RegisterUserRequest>,
 ) -> Result<(StatusCode, Json<Value>), AppError> {
     let use_case = RegisterUserUseCase::new(
         state.user_repository,
         state.password_hasher.clone(),
-        state.jwt_service.clone(),
+        state.token_issuer.clone(),
+        state.email_verification.clone(),
     );
 
-    let response = use_case.execute(request).await?;
+    let client = client_info(&headers, peer, state.trust_forwarded_for);
+    let response = use_case.execute(request, &client).await?;
 
     Ok((
         StatusCode::CREATED,
         Json(json!({
             "user_id": response.user_id,
             "email": response.email,
+            "role": response.role,
+            "email_verified": response.email_verified,
             "token": response.token,
+            "token_expires_at": response.token_expires_at,
+            "refresh_token": response.refresh_token,
+            "refresh_token_expires_at": response.refresh_token_expires_at,
         })),
     ))
 }
 
-/// Login an existing user
+/// Login an existing user. Accounts with two-factor authentication get an MFA token
+/// instead of tokens, to be exchanged at `/api/auth/login/mfa`.
 pub async fn login_handler(
     State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(request): Json<LoginUserRequest>,
 ) -> Result<(StatusCode, Json<Value>), AppError> {
+    let client = client_info(&headers, peer, state.trust_forwarded_for);
     let use_case = LoginUserUseCase::new(
         state.user_repository,
         state.password_hasher.clone(),
-        state.jwt_service.clone(),
+        state.token_issuer.clone(),
+        state.login_attempts.clone(),
+        state.mfa.clone(),
+        state.login_throttle,
+    );
+
+    let outcome = use_case.execute(request, &client).await?;
+
+    Ok((StatusCode::OK, Json(login_outcome_json(outcome))))
+}
+
+/// Tokens for a completed login, or the MFA token for one that still needs a code
+fn login_outcome_json(outcome: LoginOutcome) -> Value {
+    match outcome {
+        LoginOutcome::Authenticated(response) => json!({
+            "user_id": response.user_id,
+            "email": response.email,
+            "role": response.role,
+            "email_verified": response.email_verified,
+            "token": response.token,
+            "token_expires_at": response.token_expires_at,
+            "refresh_token": response.refresh_token,
+            "refresh_token_expires_at": response.refresh_token_expires_at,
+        }),
+        LoginOutcome::MfaRequired(challenge) => json!({
+            "mfa_required": true,
+            "mfa_token": challenge.mfa_token,
+            "mfa_token_expires_at": challenge.mfa_token_expires_at,
+        }),
+    }
+}
+
+/// Names of the identity providers that can be signed in with
+pub async fn oidc_providers_handler(State(state): State<AppState>) -> Json<Value> {
+    Json(json!({ "providers": state.oidc.provider_names() }))
+}
+
+/// Start signing in with an external identity provider
+pub async fn oidc_authorize_handler(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = StartOidcLoginUseCase::new(
+        state.oidc_authorizations,
+        state.oidc,
+        state.opaque_tokens,
+        state.oidc_state_ttl,
+    );
+
+    let response = use_case.execute(&provider).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Finish signing in with an external identity provider, with the `code` and `state`
+/// it redirected back with
+pub async fn oidc_callback_handler(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Path(provider): Path<String>,
+    Json(request): Json<OidcCallbackRequest>,
+) -> Result<(StatusCode, Json<Value>), AppError> {
+    let client = client_info(&headers, peer, state.trust_forwarded_for);
+    let use_case = OidcLoginUseCase::new(
+        state.user_repository,
+        state.user_identities,
+        state.oidc_authorizations,
+        state.oidc,
+        state.opaque_tokens,
+        state.token_issuer,
+        state.mfa,
+    );
+
+    let outcome = use_case.execute(&provider, request, &client).await?;
+
+    Ok((StatusCode::OK, Json(login_outcome_json(outcome))))
+}
+
+/// Finish a two-factor login with an authenticator or recovery code
+pub async fn verify_mfa_handler(
+    State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(request): Json<VerifyMfaRequest>,
+) -> Result<(StatusCode, Json<Value>), AppError> {
+    let client = client_info(&headers, peer, state.trust_forwarded_for);
+    let use_case = VerifyMfaUseCase::new(
+        state.user_repository,
+        state.mfa,
+        state.token_issuer,
+        state.login_attempts,
+        state.login_throttle,
     );
 
+    let response = use_case.execute(request, &client).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "user_id": response.user_id,
+            "email": response.email,
+            "role": response.role,
+            "email_verified": response.email_verified,
+            "token": response.token,
+            "token_expires_at": response.token_expires_at,
+            "refresh_token": response.refresh_token,
+            "refresh_token_expires_at": response.refresh_token_expires_at,
+        })),
+    ))
+}
+
+/// Exchange a refresh token for a new access/refresh token pair
+pub async fn refresh_token_handler(
+    State(state): State<AppState>,
+    Json(request): Json<RefreshTokenRequest>,
+) -> Result<(StatusCode, Json<Value>), AppError> {
+    let use_case = RefreshTokenUseCase::new(state.user_repository, state.token_issuer.clone());
+
     let response = use_case.execute(request).await?;
 
     Ok((
@@ -55,9 +213,389 @@ pub async fn login_handler(
         Json(json!({
             "user_id": response.user_id,
             "email": response.email,
+            "role": response.role,
+            "email_verified": response.email_verified,
             "token": response.token,
+            "token_expires_at": response.token_expires_at,
+            "refresh_token": response.refresh_token,
+            "refresh_token_expires_at": response.refresh_token_expires_at,
         })),
     ))
 }
 
+/// Log out: revoke the presented access token and, optionally, a refresh token chain
+pub async fn logout_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    request: Option<Json<LogoutUserRequest>>,
+) -> Result<StatusCode, AppError> {
+    let use_case = LogoutUserUseCase::new(state.revoked_tokens, state.token_issuer.clone());
+
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    use_case
+        .execute(
+            request,
+            auth_user.user_id,
+            auth_user.token_id,
+            auth_user.token_expires_at,
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Change a user's role (admin only)
+pub async fn change_role_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+    Json(request): Json<ChangeRoleRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ChangeRoleUseCase::new(state.user_repository);
+
+    let response = use_case
+        .execute(request, user_id, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Confirm an email address with the token from the verification email
+pub async fn verify_email_handler(
+    State(state): State<AppState>,
+    Json(request): Json<VerifyEmailRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = VerifyEmailUseCase::new(state.user_repository, state.email_verification);
+
+    let response = use_case.execute(request).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Send a new verification email to the authenticated user
+pub async fn resend_verification_email_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<StatusCode, AppError> {
+    let use_case =
+        ResendVerificationEmailUseCase::new(state.user_repository, state.email_verification);
+
+    use_case.execute(auth_user.user_id).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Email a password reset link
+///
+/// Always answers 202 and does the work in the background, so neither the
+/// response nor its timing reveals whether the email is registered.
+pub async fn request_password_reset_handler(
+    State(state): State<AppState>,
+    Json(request): Json<RequestPasswordResetRequest>,
+) -> StatusCode {
+    let use_case = RequestPasswordResetUseCase::new(
+        state.user_repository,
+        state.password_reset_tokens,
+        state.opaque_tokens,
+        state.mailer,
+        state.password_reset_url,
+        state.password_reset_ttl,
+    );
+
+    tokio::spawn(async move {
+        if let Err(e) = use_case.execute(request).await {
+            tracing::warn!("Password reset request failed: {}", e);
+        }
+    });
+
+    StatusCode::ACCEPTED
+}
 
+/// Set a new password with the token from the reset email
+pub async fn reset_password_handler(
+    State(state): State<AppState>,
+    Json(request): Json<ResetPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    let use_case = ResetPasswordUseCase::new(
+        state.user_repository,
+        state.password_hasher,
+        state.password_reset_tokens,
+        state.opaque_tokens,
+        state.revoked_tokens,
+        state.token_issuer,
+    );
+
+    use_case.execute(request).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Change the authenticated user's password
+///
+/// Signs out every other session and returns a fresh token pair.
+pub async fn change_password_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(request): Json<ChangePasswordRequest>,
+) -> Result<Json<Value>, AppError> {
+    let client = client_info(&headers, peer, state.trust_forwarded_for);
+    let use_case = ChangePasswordUseCase::new(
+        state.user_repository,
+        state.password_hasher.clone(),
+        state.password_hasher,
+        state.revoked_tokens,
+        state.token_issuer,
+    );
+
+    let response = use_case
+        .execute(request, auth_user.user_id, &client)
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Change the authenticated user's email address
+pub async fn change_email_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<ChangeEmailRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ChangeEmailUseCase::new(
+        state.user_repository,
+        state.password_hasher,
+        state.email_verification,
+    );
+
+    let response = use_case.execute(request, auth_user.user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// The authenticated user's own account
+pub async fn get_me_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = GetProfileUseCase::new(state.user_repository);
+
+    let response = use_case.execute_current(auth_user.user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Update the authenticated user's public profile
+pub async fn update_me_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<UpdateProfileRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = UpdateProfileUseCase::new(state.user_repository);
+
+    let response = use_case.execute(request, auth_user.user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Any user's public profile
+pub async fn get_profile_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = GetProfileUseCase::new(state.user_repository);
+
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Schedule the authenticated user's account for deletion
+pub async fn delete_account_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<DeleteAccountRequest>,
+) -> Result<(StatusCode, Json<Value>), AppError> {
+    let use_case = DeleteAccountUseCase::new(
+        state.user_repository,
+        state.password_hasher,
+        state.revoked_tokens,
+        state.token_issuer,
+        state.account_deletion_mode,
+        state.account_deletion_grace_period,
+    );
+
+    let response = use_case.execute(request, auth_user.user_id).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(json!(response))))
+}
+
+/// Undo a pending account deletion
+pub async fn cancel_account_deletion_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<StatusCode, AppError> {
+    let use_case = DeleteAccountUseCase::new(
+        state.user_repository,
+        state.password_hasher,
+        state.revoked_tokens,
+        state.token_issuer,
+        state.account_deletion_mode,
+        state.account_deletion_grace_period,
+    );
+
+    use_case.cancel(auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Everything stored about the authenticated user, served as a JSON download
+pub async fn export_user_data_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = ExportUserDataUseCase::new(
+        state.user_repository,
+        state.post_repository,
+        state.comment_repository,
+    );
+
+    let export = use_case.execute(auth_user.user_id).await?;
+
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"ferro-export-{}.json\"",
+                auth_user.user_id
+            ),
+        )],
+        Json(export),
+    ))
+}
+
+/// Start TOTP enrolment for the authenticated user
+pub async fn enroll_totp_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<Value>, AppError> {
+    let use_case =
+        EnrollTotpUseCase::new(state.user_repository, state.totp_credentials, state.totp);
+
+    let response = use_case.execute(auth_user.user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Confirm TOTP enrolment with a first code; returns one-time recovery codes
+pub async fn confirm_totp_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<ConfirmTotpRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ConfirmTotpUseCase::new(state.totp_credentials, state.totp, state.opaque_tokens);
+
+    let response = use_case.execute(request, auth_user.user_id).await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Turn off two-factor authentication for the authenticated user
+pub async fn disable_totp_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<DisableTotpRequest>,
+) -> Result<StatusCode, AppError> {
+    let use_case = DisableTotpUseCase::new(
+        state.user_repository,
+        state.password_hasher,
+        state.totp_credentials,
+    );
+
+    use_case.execute(request, auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Create a personal API key; the key is only shown in this response
+pub async fn create_api_key_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case =
+        CreateApiKeyUseCase::new(state.user_repository, state.api_keys, state.opaque_tokens);
+
+    let response = use_case.execute(request, auth_user.user_id).await?;
+
+    Ok((StatusCode::CREATED, Json(json!(response))))
+}
+
+/// List the authenticated user's API keys
+pub async fn list_api_keys_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ListApiKeysUseCase::new(state.api_keys);
+
+    let keys = use_case.execute(auth_user.user_id).await?;
+
+    Ok(Json(json!({ "api_keys": keys })))
+}
+
+/// Revoke one of the authenticated user's API keys
+pub async fn revoke_api_key_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(key_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let use_case = RevokeApiKeyUseCase::new(state.api_keys);
+
+    use_case.execute(key_id, auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the devices the authenticated user is signed in on
+pub async fn list_sessions_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ListSessionsUseCase::new(state.sessions);
+
+    let sessions = use_case
+        .execute(auth_user.user_id, auth_user.session_id)
+        .await?;
+
+    Ok(Json(json!({ "sessions": sessions })))
+}
+
+/// Sign one of the authenticated user's devices out
+pub async fn revoke_session_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(session_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let use_case = RevokeSessionUseCase::new(
+        state.sessions,
+        state.refresh_tokens,
+        state.revoked_tokens,
+        state.access_token_ttl,
+    );
+
+    use_case.execute(session_id, auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Sign the authenticated user out of every device, including this one
+pub async fn logout_everywhere_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<StatusCode, AppError> {
+    let use_case = LogoutEverywhereUseCase::new(state.refresh_tokens, state.revoked_tokens);
+
+    use_case.execute(auth_user.user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
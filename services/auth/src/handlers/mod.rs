@@ -3,5 +3,14 @@
 mod auth_handlers;
 mod error_response;
 
-pub use auth_handlers::{health_handler, login_handler, register_handler};
-pub use error_response::AppError;
+pub use auth_handlers::{
+    cancel_account_deletion_handler, change_email_handler, change_password_handler,
+    change_role_handler, confirm_totp_handler, create_api_key_handler, delete_account_handler,
+    disable_totp_handler, enroll_totp_handler, export_user_data_handler, get_me_handler,
+    get_profile_handler, health_handler, jwks_handler, list_api_keys_handler,
+    list_sessions_handler, login_handler, logout_everywhere_handler, logout_handler,
+    oidc_authorize_handler, oidc_callback_handler, oidc_providers_handler, refresh_token_handler,
+    register_handler, request_password_reset_handler, resend_verification_email_handler,
+    reset_password_handler, revoke_api_key_handler, revoke_session_handler, update_me_handler,
+    verify_email_handler, verify_mfa_handler,
+};
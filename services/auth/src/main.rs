@@ -6,27 +6,105 @@ mod graphql;
 mod handlers;
 mod middleware;
 
-use adapters::{JwtAdapter, PasswordHasherAdapter};
+use adapters::{
+    JwtAdapter, MailerAdapter, OidcAdapter, OpaqueTokenAdapter, PasswordHasherAdapter, TotpAdapter,
+};
 use anyhow::Result;
+use application::users::{
+    AccountDeletionMode, DeleteAccountUseCase, EmailVerificationService, LoginThrottlePolicy,
+    MfaService, TokenIssuer,
+};
 use axum::{
-    routing::{get, post},
-    Extension, Router,
+    Extension, Router, middleware as axum_middleware,
+    routing::{delete, get, post, put},
 };
 use config::Config;
+use domain::users::{
+    LoginAttemptRepository, MfaChallengeRepository, OidcAuthorizationRepository,
+    RevokedTokenRepository, SessionRepository,
+};
 use graphql::{build_schema, graphql_handler, graphql_playground};
-use handlers::{health_handler, login_handler, register_handler};
+use handlers::{
+    cancel_account_deletion_handler, change_email_handler, change_password_handler,
+    change_role_handler, confirm_totp_handler, create_api_key_handler, delete_account_handler,
+    disable_totp_handler, enroll_totp_handler, export_user_data_handler, get_me_handler,
+    get_profile_handler, health_handler, jwks_handler, list_api_keys_handler,
+    list_sessions_handler, login_handler, logout_everywhere_handler, logout_handler,
+    oidc_authorize_handler, oidc_callback_handler, oidc_providers_handler, refresh_token_handler,
+    register_handler, request_password_reset_handler, resend_verification_email_handler,
+    reset_password_handler, revoke_api_key_handler, revoke_session_handler, update_me_handler,
+    verify_email_handler, verify_mfa_handler,
+};
 use infrastructure::{
-    persistence::PostgresUserRepository,
-    security::{Argon2PasswordHasher, JwtService},
+    mail::{FileMailer, SmtpMailer},
+    persistence::{
+        PostgresApiKeyRepository, PostgresCommentRepository,
+        PostgresEmailVerificationTokenRepository, PostgresLoginAttemptRepository,
+        PostgresMfaChallengeRepository, PostgresOidcAuthorizationRepository,
+        PostgresPasswordResetTokenRepository, PostgresPostRepository,
+        PostgresRefreshTokenRepository, PostgresRevokedTokenRepository, PostgresSessionRepository,
+        PostgresTotpCredentialRepository, PostgresUserIdentityRepository, PostgresUserRepository,
+    },
+    security::{
+        Argon2PasswordHasher, BlockingPool, JwtService, JwtSigningKey, OidcClient,
+        OpaqueTokenService, TotpService, public_jwk_from_pem,
+    },
 };
+use middleware::auth_middleware;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+type AppTokenIssuer = TokenIssuer<
+    JwtAdapter,
+    PostgresRefreshTokenRepository,
+    PostgresSessionRepository,
+    OpaqueTokenAdapter,
+>;
+type AppEmailVerification = EmailVerificationService<
+    PostgresEmailVerificationTokenRepository,
+    OpaqueTokenAdapter,
+    MailerAdapter,
+>;
+type AppMfa = MfaService<
+    PostgresTotpCredentialRepository,
+    PostgresMfaChallengeRepository,
+    OpaqueTokenAdapter,
+    TotpAdapter,
+>;
+
 #[derive(Clone)]
 pub struct AppState {
     user_repository: PostgresUserRepository,
     password_hasher: PasswordHasherAdapter,
     jwt_service: JwtAdapter,
+    token_issuer: AppTokenIssuer,
+    email_verification: AppEmailVerification,
+    revoked_tokens: PostgresRevokedTokenRepository,
+    opaque_tokens: OpaqueTokenAdapter,
+    mailer: MailerAdapter,
+    password_reset_tokens: PostgresPasswordResetTokenRepository,
+    password_reset_url: String,
+    password_reset_ttl: chrono::Duration,
+    post_repository: PostgresPostRepository,
+    comment_repository: PostgresCommentRepository,
+    account_deletion_mode: AccountDeletionMode,
+    account_deletion_grace_period: chrono::Duration,
+    login_attempts: PostgresLoginAttemptRepository,
+    login_throttle: LoginThrottlePolicy,
+    trust_forwarded_for: bool,
+    totp_credentials: PostgresTotpCredentialRepository,
+    totp: TotpAdapter,
+    mfa: AppMfa,
+    user_identities: PostgresUserIdentityRepository,
+    oidc_authorizations: PostgresOidcAuthorizationRepository,
+    oidc: OidcAdapter,
+    oidc_state_ttl: chrono::Duration,
+    api_keys: PostgresApiKeyRepository,
+    refresh_tokens: PostgresRefreshTokenRepository,
+    sessions: PostgresSessionRepository,
+    access_token_ttl: chrono::Duration,
 }
 
 #[tokio::main]
@@ -42,7 +120,10 @@ async fn main() -> Result<()> {
 
     // Load configuration
     let config = Config::from_env()?;
-    tracing::info!("Starting auth service with config: {:?}", config.server_address());
+    tracing::info!(
+        "Starting auth service with config: {:?}",
+        config.server_address()
+    );
 
     // Connect to database
     let pool = sqlx::PgPool::connect(&config.database_url)
@@ -59,30 +140,260 @@ async fn main() -> Result<()> {
 
     // Create infrastructure components
     let user_repository = PostgresUserRepository::new(pool.clone());
-    let password_hasher = PasswordHasherAdapter::new(Arc::new(Argon2PasswordHasher::new()));
-    let jwt_service = JwtAdapter::new(Arc::new(JwtService::new(
-        config.jwt_secret.clone(),
-        config.jwt_expiration_hours,
-    )));
+    let password_hasher = PasswordHasherAdapter::new(Arc::new(Argon2PasswordHasher::with_params(
+        config.argon2_memory_kib,
+        config.argon2_iterations,
+        config.argon2_parallelism,
+        config.password_pepper.clone(),
+        BlockingPool::new(
+            config.password_hashing_concurrency,
+            config.password_hashing_queue_size,
+        ),
+    )?));
+    let jwt_service = JwtAdapter::new(Arc::new(load_jwt_service(&config)?));
+    let opaque_tokens = OpaqueTokenAdapter::new(Arc::new(OpaqueTokenService::new()));
+    let refresh_tokens = PostgresRefreshTokenRepository::new(pool.clone());
+    let sessions = PostgresSessionRepository::new(pool.clone());
+    let token_issuer = TokenIssuer::new(
+        jwt_service.clone(),
+        refresh_tokens.clone(),
+        sessions.clone(),
+        opaque_tokens.clone(),
+        chrono::Duration::days(config.refresh_token_expiration_days),
+    );
+    let mailer = build_mailer(&config)?;
+    let email_verification = EmailVerificationService::new(
+        PostgresEmailVerificationTokenRepository::new(pool.clone()),
+        opaque_tokens.clone(),
+        mailer.clone(),
+        config.email_verification_url.clone(),
+        chrono::Duration::hours(config.email_verification_expiration_hours),
+    );
+    let revoked_tokens = PostgresRevokedTokenRepository::new(pool.clone());
+
+    // Periodically drop revocation entries for tokens that have expired anyway
+    let purge_target = revoked_tokens.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match purge_target.purge_expired().await {
+                Ok(purged) => tracing::debug!("Purged {} expired revoked tokens", purged),
+                Err(e) => tracing::warn!("Failed to purge revoked tokens: {}", e),
+            }
+        }
+    });
+
+    // Periodically forget sessions that have no usable refresh token left
+    let purge_target = sessions.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match purge_target.purge_inactive().await {
+                Ok(purged) => tracing::debug!("Purged {} inactive sessions", purged),
+                Err(e) => tracing::warn!("Failed to purge sessions: {}", e),
+            }
+        }
+    });
+
+    // Periodically forget failed logins that no longer count towards a lockout
+    let login_attempts = PostgresLoginAttemptRepository::new(pool.clone());
+    let login_throttle = LoginThrottlePolicy {
+        max_account_failures: config.login_max_failures_per_account,
+        max_ip_failures: config.login_max_failures_per_ip,
+        lockout: chrono::Duration::seconds(config.login_lockout_seconds),
+        max_lockout: chrono::Duration::seconds(config.login_max_lockout_seconds),
+        failure_window: chrono::Duration::minutes(config.login_failure_window_minutes),
+    };
+    let purge_target = login_attempts.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            let before = chrono::Utc::now() - login_throttle.failure_window;
+            match purge_target.purge_stale(before).await {
+                Ok(purged) => tracing::debug!("Purged {} stale login attempt records", purged),
+                Err(e) => tracing::warn!("Failed to purge login attempts: {}", e),
+            }
+        }
+    });
+
+    // Periodically drop two-factor login challenges that were never answered
+    let totp_credentials = PostgresTotpCredentialRepository::new(pool.clone());
+    let totp = TotpAdapter::new(Arc::new(TotpService::new(config.mfa_issuer.clone())));
+    let mfa_challenges = PostgresMfaChallengeRepository::new(pool.clone());
+    let mfa = MfaService::new(
+        totp_credentials.clone(),
+        mfa_challenges.clone(),
+        opaque_tokens.clone(),
+        totp.clone(),
+        chrono::Duration::minutes(config.mfa_token_expiration_minutes),
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match mfa_challenges.purge_expired().await {
+                Ok(purged) => tracing::debug!("Purged {} expired MFA challenges", purged),
+                Err(e) => tracing::warn!("Failed to purge MFA challenges: {}", e),
+            }
+        }
+    });
+
+    // Read the endpoints and keys of the configured identity providers, and periodically
+    // drop sign-ins that were started there but never completed
+    let oidc = OidcAdapter::new(OidcClient::discover(config.oidc_providers.clone()).await?);
+    if !config.oidc_providers.is_empty() {
+        tracing::info!("OIDC providers: {}", oidc.provider_names().join(", "));
+    }
+    let oidc_authorizations = PostgresOidcAuthorizationRepository::new(pool.clone());
+    let purge_target = oidc_authorizations.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match purge_target.purge_expired().await {
+                Ok(purged) => tracing::debug!("Purged {} expired OIDC authorizations", purged),
+                Err(e) => tracing::warn!("Failed to purge OIDC authorizations: {}", e),
+            }
+        }
+    });
 
     // Create app state
     let state = AppState {
         user_repository,
         password_hasher,
         jwt_service: jwt_service.clone(),
+        token_issuer,
+        email_verification,
+        revoked_tokens,
+        opaque_tokens,
+        mailer,
+        password_reset_tokens: PostgresPasswordResetTokenRepository::new(pool.clone()),
+        password_reset_url: config.password_reset_url.clone(),
+        password_reset_ttl: chrono::Duration::minutes(config.password_reset_expiration_minutes),
+        post_repository: PostgresPostRepository::new(pool.clone()),
+        comment_repository: PostgresCommentRepository::new(pool.clone()),
+        account_deletion_mode: config.account_deletion_mode,
+        account_deletion_grace_period: chrono::Duration::days(config.account_deletion_grace_days),
+        login_attempts,
+        login_throttle,
+        trust_forwarded_for: config.trust_forwarded_for,
+        totp_credentials,
+        totp,
+        mfa,
+        user_identities: PostgresUserIdentityRepository::new(pool.clone()),
+        oidc_authorizations,
+        oidc,
+        oidc_state_ttl: chrono::Duration::minutes(config.oidc_state_expiration_minutes),
+        api_keys: PostgresApiKeyRepository::new(pool.clone()),
+        refresh_tokens,
+        sessions,
+        access_token_ttl: chrono::Duration::minutes(config.jwt_expiration_minutes),
     };
 
+    // Periodically carry out account deletions whose grace period has ended
+    let account_deletion = DeleteAccountUseCase::new(
+        state.user_repository.clone(),
+        state.password_hasher.clone(),
+        state.revoked_tokens.clone(),
+        state.token_issuer.clone(),
+        state.account_deletion_mode,
+        state.account_deletion_grace_period,
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match account_deletion.purge_due().await {
+                Ok(purged) => tracing::debug!("Purged {} deleted accounts", purged),
+                Err(e) => tracing::warn!("Failed to purge deleted accounts: {}", e),
+            }
+        }
+    });
+
     // Build GraphQL schema
     let schema = build_schema();
     tracing::info!("GraphQL schema built successfully");
 
+    // Build router with protected routes
+    let protected_routes = Router::new()
+        .route("/api/auth/logout", post(logout_handler))
+        .route(
+            "/api/auth/verify-email/resend",
+            post(resend_verification_email_handler),
+        )
+        .route("/api/auth/password", put(change_password_handler))
+        .route("/api/auth/email", put(change_email_handler))
+        .route(
+            "/api/auth/me",
+            get(get_me_handler)
+                .patch(update_me_handler)
+                .delete(delete_account_handler),
+        )
+        .route(
+            "/api/auth/me/restore",
+            post(cancel_account_deletion_handler),
+        )
+        .route("/api/auth/me/export", get(export_user_data_handler))
+        .route(
+            "/api/auth/mfa/totp",
+            post(enroll_totp_handler).delete(disable_totp_handler),
+        )
+        .route("/api/auth/mfa/totp/confirm", post(confirm_totp_handler))
+        .route(
+            "/api/auth/api-keys",
+            get(list_api_keys_handler).post(create_api_key_handler),
+        )
+        .route(
+            "/api/auth/api-keys/{key_id}",
+            delete(revoke_api_key_handler),
+        )
+        .route(
+            "/api/auth/sessions",
+            get(list_sessions_handler).delete(logout_everywhere_handler),
+        )
+        .route(
+            "/api/auth/sessions/{session_id}",
+            delete(revoke_session_handler),
+        )
+        .route("/api/auth/users/{user_id}/role", put(change_role_handler))
+        .route_layer(axum_middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
     let app = Router::new()
         .route("/health", get(health_handler))
+        .route("/.well-known/jwks.json", get(jwks_handler))
         .route("/api/auth/register", post(register_handler))
         .route("/api/auth/login", post(login_handler))
+        .route("/api/auth/login/mfa", post(verify_mfa_handler))
+        .route("/api/auth/oidc/providers", get(oidc_providers_handler))
+        .route(
+            "/api/auth/oidc/{provider}/authorize",
+            post(oidc_authorize_handler),
+        )
+        .route(
+            "/api/auth/oidc/{provider}/callback",
+            post(oidc_callback_handler),
+        )
+        .route("/api/auth/refresh", post(refresh_token_handler))
+        .route("/api/auth/verify-email", post(verify_email_handler))
+        .route(
+            "/api/auth/password-reset",
+            post(request_password_reset_handler),
+        )
+        .route(
+            "/api/auth/password-reset/confirm",
+            post(reset_password_handler),
+        )
+        .route("/api/auth/users/{user_id}", get(get_profile_handler))
         // GraphQL endpoints
         .route("/graphql", post(graphql_handler))
         .route("/graphql/playground", get(graphql_playground))
+        .merge(protected_routes)
         .layer(Extension(schema))
         .with_state(state);
 
@@ -93,12 +404,63 @@ async fn main() -> Result<()> {
     tracing::info!("GraphQL Playground: http://{}/graphql/playground", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
 
+/// Load the signing key and any extra public keys to publish from the configured PEM files
+fn load_jwt_service(config: &Config) -> Result<JwtService> {
+    let signing_key = match &config.jwt_private_key_path {
+        Some(path) => JwtSigningKey::from_pem(&std::fs::read_to_string(path)?)?,
+        None => {
+            tracing::warn!(
+                "JWT_PRIVATE_KEY_PATH not set, signing with a temporary key; \
+                 tokens will not survive a restart"
+            );
+            JwtSigningKey::generate()?
+        }
+    };
+    tracing::info!("Signing tokens with key {}", signing_key.kid());
 
+    let additional_keys = config
+        .jwt_additional_public_key_paths
+        .iter()
+        .map(|path| Ok(public_jwk_from_pem(&std::fs::read_to_string(path)?)?))
+        .collect::<Result<Vec<_>>>()?;
 
+    Ok(JwtService::new(
+        signing_key,
+        additional_keys,
+        config.jwt_expiration_minutes,
+    ))
+}
 
-
+/// Pick the outbound mail transport from `MAIL_TRANSPORT`
+fn build_mailer(config: &Config) -> Result<MailerAdapter> {
+    match config.mail_transport.as_str() {
+        "smtp" => {
+            let credentials = config
+                .smtp_username
+                .clone()
+                .zip(config.smtp_password.clone());
+            let mailer = SmtpMailer::new(
+                &config.smtp_host,
+                config.smtp_port,
+                credentials,
+                &config.mail_from,
+            )?;
+            Ok(MailerAdapter::Smtp(Arc::new(mailer)))
+        }
+        "file" => {
+            let mailer = FileMailer::new(&config.mail_outbox_dir, &config.mail_from);
+            tracing::info!("Writing outbound email to {}", mailer.dir().display());
+            Ok(MailerAdapter::File(Arc::new(mailer)))
+        }
+        other => Err(anyhow::anyhow!("Invalid MAIL_TRANSPORT: {}", other)),
+    }
+}
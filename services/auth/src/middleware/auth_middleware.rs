@@ -1,11 +1,14 @@
 // services/auth/src/middleware/auth_middleware.rs
 
+use application::authorization::Actor;
 use axum::{
     extract::{Request, State},
-    http::{header::AUTHORIZATION, StatusCode},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
     middleware::Next,
     response::Response,
 };
+use chrono::{DateTime, Utc};
+use domain::users::{RevokedTokenRepository, Role};
 use uuid::Uuid;
 
 use crate::AppState;
@@ -14,7 +17,19 @@ use crate::AppState;
 #[derive(Clone, Debug)]
 pub struct AuthUser {
     pub user_id: Uuid,
-    pub email: String,
+    pub role: Role,
+    pub email_verified: bool,
+    pub token_id: Uuid,
+    pub token_expires_at: DateTime<Utc>,
+    // Absent on tokens issued before sessions were tracked
+    pub session_id: Option<Uuid>,
+}
+
+impl AuthUser {
+    /// The user as seen by the authorization policy
+    pub fn actor(&self) -> Actor {
+        Actor::new(self.user_id, self.role, self.email_verified)
+    }
 }
 
 /// Middleware to verify JWT token and extract user information
@@ -23,9 +38,20 @@ pub async fn auth_middleware(
     mut request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    let auth_user = authenticate(&state, request.headers()).await?;
+
+    // Insert the authenticated user into request extensions
+    request.extensions_mut().insert(auth_user);
+
+    Ok(next.run(request).await)
+}
+
+/// Verify the bearer token in `headers`. Shared by the middleware and the GraphQL
+/// handler, which cannot sit behind the middleware because some of its operations
+/// are public.
+pub async fn authenticate(state: &AppState, headers: &HeaderMap) -> Result<AuthUser, StatusCode> {
     // Extract the Authorization header
-    let auth_header = request
-        .headers()
+    let auth_header = headers
         .get(AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
         .ok_or(StatusCode::UNAUTHORIZED)?;
@@ -41,15 +67,38 @@ pub async fn auth_middleware(
         .verify_token(token)
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    // Parse user_id from claims
+    // Parse user_id and token id from claims
     let user_id = Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let token_id = Uuid::parse_str(&claims.jti).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let issued_at = DateTime::from_timestamp(claims.iat, 0).ok_or(StatusCode::UNAUTHORIZED)?;
+    let token_expires_at =
+        DateTime::from_timestamp(claims.exp, 0).ok_or(StatusCode::UNAUTHORIZED)?;
+    let session_id = claims
+        .sid
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    // Insert the authenticated user into request extensions
-    let auth_user = AuthUser {
-        user_id,
-        email: claims.email,
-    };
-    request.extensions_mut().insert(auth_user);
+    // Reject tokens revoked through logout, a revoked session or a password reset
+    let revoked = state
+        .revoked_tokens
+        .is_revoked(token_id, user_id, session_id, issued_at)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check token revocation: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if revoked {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
 
-    Ok(next.run(request).await)
+    Ok(AuthUser {
+        user_id,
+        role: claims.role,
+        email_verified: claims.email_verified,
+        token_id,
+        token_expires_at,
+        session_id,
+    })
 }
@@ -0,0 +1,34 @@
+// services/auth/src/middleware/client_ip.rs
+
+use application::users::ClientInfo;
+use axum::http::{HeaderMap, header::USER_AGENT};
+use std::net::{IpAddr, SocketAddr};
+
+/// The address a request came from. Behind a reverse proxy the peer is the proxy
+/// itself, so when `trust_forwarded_for` is set the first `X-Forwarded-For` entry is
+/// used instead. Only enable that when a proxy overwrites the header, since clients
+/// can otherwise pick any address they like.
+fn client_ip(headers: &HeaderMap, peer: SocketAddr, trust_forwarded_for: bool) -> IpAddr {
+    if trust_forwarded_for
+        && let Some(forwarded) = headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .and_then(|value| value.trim().parse().ok())
+    {
+        return forwarded;
+    }
+
+    peer.ip()
+}
+
+/// Where a login comes from, as recorded with the session it starts
+pub fn client_info(headers: &HeaderMap, peer: SocketAddr, trust_forwarded_for: bool) -> ClientInfo {
+    ClientInfo {
+        ip_address: Some(client_ip(headers, peer, trust_forwarded_for)),
+        user_agent: headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+    }
+}
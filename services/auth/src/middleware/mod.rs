@@ -1,5 +1,7 @@
 // services/auth/src/middleware/mod.rs
 
 mod auth_middleware;
+mod client_ip;
 
-pub use auth_middleware::{auth_middleware, AuthUser};
+pub use auth_middleware::{AuthUser, auth_middleware, authenticate};
+pub use client_ip::client_info;
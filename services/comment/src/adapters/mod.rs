@@ -1,5 +1,7 @@
 // services/comment/src/adapters/mod.rs
 
 mod jwt_adapter;
+mod opaque_token_adapter;
 
 pub use jwt_adapter::JwtAdapter;
+pub use opaque_token_adapter::OpaqueTokenAdapter;
@@ -1,19 +1,21 @@
 // services/comment/src/handlers/comment_handlers.rs
 
+use application::authorization::Policy;
 use application::comments::{
-    CreateCommentRequest, CreateCommentUseCase, ListCommentsUseCase,
+    CreateCommentRequest, CreateCommentUseCase, DeleteCommentUseCase, ListCommentsUseCase,
 };
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
     Extension, Json,
+    extract::{Path, Query, State},
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
 };
-use serde_json::{json, Value};
+use serde_json::{Value, json};
 use uuid::Uuid;
 
-use super::common::PaginationParams;
+use super::common::{PaginationParams, ThreadParams, pagination_links};
 use super::error_response::AppError;
-use crate::{middleware::AuthUser, AppState};
+use crate::{AppState, middleware::AuthUser};
 
 /// Health check endpoint
 pub async fn health_handler() -> Json<Value> {
@@ -30,25 +32,73 @@ pub async fn create_comment_handler(
     Path(post_id): Path<Uuid>,
     Json(request): Json<CreateCommentRequest>,
 ) -> Result<(StatusCode, Json<Value>), AppError> {
+    Policy::create_content(&auth_user.actor(), state.require_verified_email)?;
+
     let use_case =
         CreateCommentUseCase::new(state.comment_repository, state.post_repository.clone());
 
-    let response = use_case.execute(request, post_id, auth_user.user_id).await?;
+    let response = use_case
+        .execute(request, post_id, auth_user.user_id)
+        .await?;
 
     Ok((StatusCode::CREATED, Json(json!(response))))
 }
 
-/// List comments for a post with pagination
+/// List comments for a post with pagination. Comments on a post that is not published
+/// are only listed for its author.
+///
+/// With `?threaded=true` the page is over top-level comments and replies are
+/// nested under their parents. Both kinds of page carry a `Link` header to their
+/// neighbours.
 pub async fn list_comments_handler(
     State(state): State<AppState>,
+    Extension(viewer): Extension<Option<AuthUser>>,
     Path(post_id): Path<Uuid>,
+    uri: Uri,
     Query(params): Query<PaginationParams>,
-) -> Result<Json<Value>, AppError> {
-    let use_case = ListCommentsUseCase::new(state.comment_repository);
+    Query(thread): Query<ThreadParams>,
+) -> Result<Response, AppError> {
+    let page = params.page(state.max_page_size)?;
+    let use_case = ListCommentsUseCase::new(
+        state.comment_repository,
+        state.post_repository,
+        state.user_repository,
+    );
+    let viewer = viewer.map(|user| user.user_id);
 
-    let response = use_case
-        .execute(post_id, params.limit, params.offset)
-        .await?;
+    if thread.threaded {
+        let response = use_case
+            .execute_tree(post_id, viewer, page, state.comment_max_depth)
+            .await?;
+        let links = pagination_links(
+            &uri,
+            response.limit,
+            response.prev_cursor.as_deref(),
+            response.next_cursor.as_deref(),
+        );
+        return Ok((links, Json(json!(response))).into_response());
+    }
+
+    let response = use_case.execute(post_id, viewer, page).await?;
+    let links = pagination_links(
+        &uri,
+        response.limit,
+        response.prev_cursor.as_deref(),
+        response.next_cursor.as_deref(),
+    );
+
+    Ok((links, Json(json!(response))).into_response())
+}
+
+/// Delete a comment and its replies (author, moderators and admins)
+pub async fn delete_comment_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let use_case = DeleteCommentUseCase::new(state.comment_repository);
+
+    use_case.execute(comment_id, &auth_user.actor()).await?;
 
-    Ok(Json(json!(response)))
+    Ok(StatusCode::NO_CONTENT)
 }
@@ -1,15 +1,84 @@
 // services/comment/src/handlers/common.rs
 
+use axum::http::{HeaderMap, HeaderValue, Uri, header};
+use domain::common::error::DomainError;
+use domain::common::pagination::PageRequest;
 use serde::Deserialize;
 
+/// Either `offset` or one of the `after` and `before` cursors from a previous page
 #[derive(Debug, Deserialize)]
 pub struct PaginationParams {
     #[serde(default = "default_limit")]
     pub limit: i64,
     #[serde(default)]
     pub offset: i64,
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub before: Option<String>,
+}
+
+impl PaginationParams {
+    /// The requested page, with `limit` brought down to `max_limit`
+    pub fn page(&self, max_limit: i64) -> Result<PageRequest, DomainError> {
+        PageRequest::parse(
+            self.limit,
+            max_limit,
+            self.offset,
+            self.after.as_deref(),
+            self.before.as_deref(),
+        )
+    }
 }
 
 fn default_limit() -> i64 {
     20
 }
+
+/// RFC 8288 `Link` header for a page of the list at `uri`, pointing at the first page and
+/// at the pages either side of it. Other query parameters, such as filters, are kept.
+pub fn pagination_links(
+    uri: &Uri,
+    limit: i64,
+    prev_cursor: Option<&str>,
+    next_cursor: Option<&str>,
+) -> HeaderMap {
+    // The query without its paging parameters, still percent-encoded
+    let kept: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| {
+            let name = pair.split('=').next().unwrap_or_default();
+            !name.is_empty() && !matches!(name, "limit" | "offset" | "after" | "before")
+        })
+        .collect();
+    let link = |position: Option<String>, rel: &str| {
+        let limit = format!("limit={}", limit);
+        let mut params = kept.clone();
+        params.push(&limit);
+        params.extend(position.as_deref());
+        format!("<{}?{}>; rel=\"{}\"", uri.path(), params.join("&"), rel)
+    };
+
+    // Cursors are URL-safe base64, so they need no escaping
+    let mut links = vec![link(None, "first")];
+    if let Some(cursor) = prev_cursor {
+        links.push(link(Some(format!("before={}", cursor)), "prev"));
+    }
+    if let Some(cursor) = next_cursor {
+        links.push(link(Some(format!("after={}", cursor)), "next"));
+    }
+
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&links.join(", ")) {
+        headers.insert(header::LINK, value);
+    }
+    headers
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadParams {
+    #[serde(default)]
+    pub threaded: bool,
+}
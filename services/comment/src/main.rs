@@ -5,28 +5,40 @@ mod config;
 mod handlers;
 mod middleware;
 
-use adapters::JwtAdapter;
+use adapters::{JwtAdapter, OpaqueTokenAdapter};
 use anyhow::Result;
 use axum::{
-    middleware as axum_middleware,
-    routing::{get, post},
-    Router,
+    Router, middleware as axum_middleware,
+    routing::{delete, get, post},
 };
 use config::Config;
-use handlers::{create_comment_handler, health_handler, list_comments_handler};
+use handlers::{
+    create_comment_handler, delete_comment_handler, health_handler, list_comments_handler,
+};
 use infrastructure::{
-    persistence::{PostgresCommentRepository, PostgresPostRepository},
-    security::JwtService,
+    persistence::{
+        PostgresApiKeyRepository, PostgresCommentRepository, PostgresPostRepository,
+        PostgresRevokedTokenRepository, PostgresUserRepository,
+    },
+    security::{JwksClient, JwtKeySet, JwtService, OpaqueTokenService},
 };
-use middleware::auth_middleware;
+use middleware::{auth_middleware, optional_auth_middleware};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[derive(Clone)]
 pub struct AppState {
     comment_repository: PostgresCommentRepository,
     post_repository: PostgresPostRepository,
+    user_repository: PostgresUserRepository,
     jwt_service: JwtAdapter,
+    revoked_tokens: PostgresRevokedTokenRepository,
+    api_keys: PostgresApiKeyRepository,
+    opaque_tokens: OpaqueTokenAdapter,
+    require_verified_email: bool,
+    max_page_size: i64,
+    comment_max_depth: usize,
 }
 
 #[tokio::main]
@@ -42,7 +54,10 @@ async fn main() -> Result<()> {
 
     // Load configuration
     let config = Config::from_env()?;
-    tracing::info!("Starting comment service with config: {:?}", config.server_address());
+    tracing::info!(
+        "Starting comment service with config: {:?}",
+        config.server_address()
+    );
 
     // Connect to database
     let pool = sqlx::PgPool::connect(&config.database_url)
@@ -60,29 +75,69 @@ async fn main() -> Result<()> {
     // Create infrastructure components
     let comment_repository = PostgresCommentRepository::new(pool.clone());
     let post_repository = PostgresPostRepository::new(pool.clone());
-    let jwt_service = JwtAdapter::new(Arc::new(JwtService::new(
-        config.jwt_secret.clone(),
-        24, // Default expiration hours
-    )));
+    let user_repository = PostgresUserRepository::new(pool.clone());
+    let revoked_tokens = PostgresRevokedTokenRepository::new(pool.clone());
+    let api_keys = PostgresApiKeyRepository::new(pool.clone());
+    let opaque_tokens = OpaqueTokenAdapter::new(Arc::new(OpaqueTokenService::new()));
+    let jwt_keys = JwtKeySet::default();
+    let jwt_service = JwtAdapter::new(Arc::new(JwtService::verifier(jwt_keys.clone())));
+
+    // Keep the verification keys in sync with the keys published by the auth service.
+    // Until the first fetch succeeds every token is rejected, so retry quickly.
+    let jwks_client = JwksClient::new(config.jwks_url.clone());
+    let refresh_interval = Duration::from_secs(config.jwks_refresh_seconds);
+    tokio::spawn(async move {
+        loop {
+            match jwks_client.refresh(&jwt_keys).await {
+                Ok(count) => tracing::debug!("Loaded {} token verification keys", count),
+                Err(e) => tracing::warn!("Failed to refresh token verification keys: {}", e),
+            }
+            let delay = if jwt_keys.is_empty() {
+                Duration::from_secs(5)
+            } else {
+                refresh_interval
+            };
+            tokio::time::sleep(delay).await;
+        }
+    });
 
     // Create app state
     let state = AppState {
         comment_repository,
         post_repository,
+        user_repository,
         jwt_service,
+        revoked_tokens,
+        api_keys,
+        opaque_tokens,
+        require_verified_email: config.require_verified_email,
+        max_page_size: config.max_page_size,
+        comment_max_depth: config.comment_max_depth,
     };
 
     // Build router with protected routes
     let protected_routes = Router::new()
-        .route("/api/posts/{post_id}/comments", post(create_comment_handler))
+        .route(
+            "/api/posts/{post_id}/comments",
+            post(create_comment_handler),
+        )
+        .route("/api/comments/{comment_id}", delete(delete_comment_handler))
         .route_layer(axum_middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
         ));
 
+    // Public routes that show authors the comments on their own unpublished posts
+    let viewer_routes = Router::new()
+        .route("/api/posts/{post_id}/comments", get(list_comments_handler))
+        .route_layer(axum_middleware::from_fn_with_state(
+            state.clone(),
+            optional_auth_middleware,
+        ));
+
     let app = Router::new()
         .route("/health", get(health_handler))
-        .route("/api/posts/{post_id}/comments", get(list_comments_handler))
+        .merge(viewer_routes)
         .merge(protected_routes)
         .with_state(state);
 
@@ -1,15 +1,136 @@
 // services/post/src/handlers/common.rs
 
+use axum::http::{HeaderMap, HeaderValue, Uri, header};
+use domain::common::error::DomainError;
+use domain::common::pagination::PageRequest;
 use serde::Deserialize;
 
+/// Either `offset` or one of the `after` and `before` cursors from a previous page
 #[derive(Debug, Deserialize)]
 pub struct PaginationParams {
     #[serde(default = "default_limit")]
     pub limit: i64,
     #[serde(default)]
     pub offset: i64,
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub before: Option<String>,
+}
+
+impl PaginationParams {
+    /// The requested page, with `limit` brought down to `max_limit`
+    pub fn page(&self, max_limit: i64) -> Result<PageRequest, DomainError> {
+        PageRequest::parse(
+            self.limit,
+            max_limit,
+            self.offset,
+            self.after.as_deref(),
+            self.before.as_deref(),
+        )
+    }
 }
 
 fn default_limit() -> i64 {
     20
 }
+
+/// RFC 8288 `Link` header for a page of the list at `uri`, pointing at the first page and
+/// at the pages either side of it. Other query parameters, such as filters, are kept.
+pub fn pagination_links(
+    uri: &Uri,
+    limit: i64,
+    prev_cursor: Option<&str>,
+    next_cursor: Option<&str>,
+) -> HeaderMap {
+    // Cursors are URL-safe base64, so they need no escaping
+    link_header(
+        uri,
+        limit,
+        prev_cursor.map(|cursor| format!("before={}", cursor)),
+        next_cursor.map(|cursor| format!("after={}", cursor)),
+    )
+}
+
+/// `pagination_links` for lists that can only be paged by offset, such as search results
+pub fn offset_links(uri: &Uri, limit: i64, offset: i64, has_more: bool) -> HeaderMap {
+    link_header(
+        uri,
+        limit,
+        (offset > 0).then(|| format!("offset={}", (offset - limit).max(0))),
+        has_more.then(|| format!("offset={}", offset + limit)),
+    )
+}
+
+// `prev` and `next` are the query parameters that position those pages
+fn link_header(uri: &Uri, limit: i64, prev: Option<String>, next: Option<String>) -> HeaderMap {
+    // The query without its paging parameters, still percent-encoded
+    let kept: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| {
+            let name = pair.split('=').next().unwrap_or_default();
+            !name.is_empty() && !matches!(name, "limit" | "offset" | "after" | "before")
+        })
+        .collect();
+    let link = |position: Option<String>, rel: &str| {
+        let limit = format!("limit={}", limit);
+        let mut params = kept.clone();
+        params.push(&limit);
+        params.extend(position.as_deref());
+        format!("<{}?{}>; rel=\"{}\"", uri.path(), params.join("&"), rel)
+    };
+
+    let mut links = vec![link(None, "first")];
+    if prev.is_some() {
+        links.push(link(prev, "prev"));
+    }
+    if next.is_some() {
+        links.push(link(next, "next"));
+    }
+
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&links.join(", ")) {
+        headers.insert(header::LINK, value);
+    }
+    headers
+}
+
+/// Full-text search: `q` in web search syntax, and whether comments count as matches
+#[derive(Debug, Deserialize)]
+pub struct SearchParams {
+    pub q: String,
+    #[serde(default)]
+    pub in_comments: bool,
+}
+
+/// The two revisions to compare, by number
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffParams {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// Tag filter for post listings: `tag` is a comma-separated list of tags and
+/// `tag_match` is "any" (the default) or "all"
+#[derive(Debug, Deserialize)]
+pub struct TagFilterParams {
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub tag_match: Option<String>,
+}
+
+impl TagFilterParams {
+    pub fn tags(&self) -> Vec<String> {
+        self.tag
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
@@ -1,9 +1,9 @@
 // services/post/src/handlers/error_response.rs
 
 use axum::{
-    http::StatusCode,
-    response::{IntoResponse, Response},
     Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
 };
 use domain::common::error::DomainError;
 use serde_json::json;
@@ -15,8 +15,25 @@ impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, message) = match self.0 {
             DomainError::Validation(msg) => (StatusCode::BAD_REQUEST, msg),
+            DomainError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+            DomainError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
             DomainError::AlreadyExists(msg) => (StatusCode::CONFLICT, msg),
             DomainError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            DomainError::TooManyRequests {
+                message,
+                retry_after_secs,
+            } => {
+                let body = Json(json!({
+                    "error": message,
+                }));
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_after_secs.to_string())],
+                    body,
+                )
+                    .into_response();
+            }
+            DomainError::Overloaded(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
             DomainError::InfraError(msg) => {
                 tracing::error!("Infrastructure error: {}", msg);
                 (
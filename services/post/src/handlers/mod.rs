@@ -4,5 +4,9 @@ mod common;
 mod error_response;
 mod post_handlers;
 
-pub use error_response::AppError;
-pub use post_handlers::{create_post_handler, get_post_handler, health_handler, list_posts_handler};
+pub use post_handlers::{
+    change_post_status_handler, create_post_handler, delete_post_handler,
+    diff_post_revisions_handler, get_post_by_slug_handler, get_post_handler, health_handler,
+    list_my_posts_handler, list_post_revisions_handler, list_posts_handler, list_tags_handler,
+    replace_post_handler, restore_post_revision_handler, search_posts_handler, update_post_handler,
+};
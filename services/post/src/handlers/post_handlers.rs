@@ -1,19 +1,27 @@
 // services/post/src/handlers/post_handlers.rs
 
+use application::authorization::Policy;
 use application::posts::{
-    CreatePostRequest, CreatePostUseCase, GetPostUseCase, ListPostsUseCase,
+    ChangePostStatusRequest, ChangePostStatusUseCase, CreatePostRequest, CreatePostUseCase,
+    DeletePostUseCase, DiffPostRevisionsUseCase, GetPostBySlugUseCase, GetPostUseCase,
+    ListPostRevisionsUseCase, ListPostsUseCase, ListTagsUseCase, PostBySlugResponse,
+    RestorePostRevisionUseCase, SearchPostsUseCase, UpdatePostRequest, UpdatePostUseCase,
 };
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
     Extension, Json,
+    extract::{Path, Query, State},
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Redirect, Response},
 };
-use serde_json::{json, Value};
+use serde_json::{Value, json};
 use uuid::Uuid;
 
-use super::common::PaginationParams;
+use super::common::{
+    PaginationParams, RevisionDiffParams, SearchParams, TagFilterParams, offset_links,
+    pagination_links,
+};
 use super::error_response::AppError;
-use crate::{middleware::AuthUser, AppState};
+use crate::{AppState, middleware::AuthUser};
 
 /// Health check endpoint
 pub async fn health_handler() -> Json<Value> {
@@ -29,6 +37,8 @@ pub async fn create_post_handler(
     Extension(auth_user): Extension<AuthUser>,
     Json(request): Json<CreatePostRequest>,
 ) -> Result<(StatusCode, Json<Value>), AppError> {
+    Policy::create_content(&auth_user.actor(), state.require_verified_email)?;
+
     let use_case = CreatePostUseCase::new(state.post_repository);
 
     let response = use_case.execute(request, auth_user.user_id).await?;
@@ -36,26 +46,227 @@ pub async fn create_post_handler(
     Ok((StatusCode::CREATED, Json(json!(response))))
 }
 
-/// Get a single post by ID
+/// Get a single post by ID; posts that are not published are only shown to their author
 pub async fn get_post_handler(
     State(state): State<AppState>,
+    Extension(viewer): Extension<Option<AuthUser>>,
     Path(post_id): Path<Uuid>,
 ) -> Result<Json<Value>, AppError> {
-    let use_case = GetPostUseCase::new(state.post_repository);
+    let use_case = GetPostUseCase::new(state.post_repository, state.user_repository);
 
-    let response = use_case.execute(post_id).await?;
+    let viewer = viewer.map(|user| user.user_id);
+    let response = use_case.execute(post_id, viewer).await?;
 
     Ok(Json(json!(response)))
 }
 
-/// List all posts with pagination
+/// Get a single post by slug. Old slugs of a post redirect to its current one.
+pub async fn get_post_by_slug_handler(
+    State(state): State<AppState>,
+    Extension(viewer): Extension<Option<AuthUser>>,
+    Path(slug): Path<String>,
+) -> Result<Response, AppError> {
+    let use_case = GetPostBySlugUseCase::new(state.post_repository, state.user_repository);
+
+    let viewer = viewer.map(|user| user.user_id);
+    let response = match use_case.execute(&slug, viewer).await? {
+        PostBySlugResponse::Found(post) => Json(json!(post)).into_response(),
+        PostBySlugResponse::Moved { slug } => {
+            Redirect::permanent(&format!("/api/posts/by-slug/{}", slug)).into_response()
+        }
+    };
+
+    Ok(response)
+}
+
+/// List published posts with pagination, optionally filtered by tag. A `Link` header
+/// points at the neighbouring pages.
 pub async fn list_posts_handler(
     State(state): State<AppState>,
+    uri: Uri,
+    Query(params): Query<PaginationParams>,
+    Query(filter): Query<TagFilterParams>,
+) -> Result<Response, AppError> {
+    let use_case = ListPostsUseCase::new(state.post_repository, state.user_repository);
+
+    let response = use_case
+        .execute(
+            filter.tags(),
+            filter.tag_match,
+            params.page(state.max_page_size)?,
+        )
+        .await?;
+    let links = pagination_links(
+        &uri,
+        response.limit,
+        response.prev_cursor.as_deref(),
+        response.next_cursor.as_deref(),
+    );
+
+    Ok((links, Json(json!(response))).into_response())
+}
+
+/// Full-text search over published posts, best matches first, with highlighted snippets
+/// and a `Link` header to the neighbouring pages by offset
+pub async fn search_posts_handler(
+    State(state): State<AppState>,
+    uri: Uri,
     Query(params): Query<PaginationParams>,
+    Query(search): Query<SearchParams>,
+) -> Result<Response, AppError> {
+    let use_case = SearchPostsUseCase::new(state.post_repository, state.user_repository);
+
+    let response = use_case
+        .execute(
+            search.q,
+            search.in_comments,
+            params.page(state.max_page_size)?,
+        )
+        .await?;
+    let links = offset_links(&uri, response.limit, response.offset, response.has_more);
+
+    Ok((links, Json(json!(response))).into_response())
+}
+
+/// List the tags in use on published posts with their post counts
+pub async fn list_tags_handler(State(state): State<AppState>) -> Result<Json<Value>, AppError> {
+    let use_case = ListTagsUseCase::new(state.post_repository);
+
+    let response = use_case.execute().await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// List the authenticated user's own posts, whatever their status, with a `Link` header
+/// like the public listing
+pub async fn list_my_posts_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    uri: Uri,
+    Query(params): Query<PaginationParams>,
+) -> Result<Response, AppError> {
+    let use_case = ListPostsUseCase::new(state.post_repository, state.user_repository);
+
+    let response = use_case
+        .execute_for_author(auth_user.user_id, params.page(state.max_page_size)?)
+        .await?;
+    let links = pagination_links(
+        &uri,
+        response.limit,
+        response.prev_cursor.as_deref(),
+        response.next_cursor.as_deref(),
+    );
+
+    Ok((links, Json(json!(response))).into_response())
+}
+
+/// Replace a post's title, content and tags (author only)
+pub async fn replace_post_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
+    Json(request): Json<CreatePostRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = UpdatePostUseCase::new(state.post_repository);
+
+    // Status changes go through the status endpoint; leaving out tags removes them
+    let request = UpdatePostRequest {
+        title: Some(request.title),
+        content: Some(request.content),
+        tags: Some(request.tags.unwrap_or_default()),
+    };
+    let response = use_case
+        .execute(request, post_id, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Partially update a post (author only)
+pub async fn update_post_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
+    Json(request): Json<UpdatePostRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = UpdatePostUseCase::new(state.post_repository);
+
+    let response = use_case
+        .execute(request, post_id, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Publish, schedule, unschedule or archive a post (author only)
+pub async fn change_post_status_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
+    Json(request): Json<ChangePostStatusRequest>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = ChangePostStatusUseCase::new(state.post_repository);
+
+    let response = use_case
+        .execute(request, post_id, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// List a post's revisions, oldest first (author, moderators and admins)
+pub async fn list_post_revisions_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
 ) -> Result<Json<Value>, AppError> {
-    let use_case = ListPostsUseCase::new(state.post_repository);
+    let use_case = ListPostRevisionsUseCase::new(state.post_repository);
 
-    let response = use_case.execute(params.limit, params.offset).await?;
+    let response = use_case.execute(post_id, &auth_user.actor()).await?;
 
     Ok(Json(json!(response)))
 }
+
+/// Line-level diff between two revisions of a post (author, moderators and admins)
+pub async fn diff_post_revisions_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
+    Query(params): Query<RevisionDiffParams>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = DiffPostRevisionsUseCase::new(state.post_repository);
+
+    let response = use_case
+        .execute(post_id, params.from, params.to, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Restore an earlier revision of a post as a new revision (author only)
+pub async fn restore_post_revision_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((post_id, number)): Path<(Uuid, i32)>,
+) -> Result<Json<Value>, AppError> {
+    let use_case = RestorePostRevisionUseCase::new(state.post_repository);
+
+    let response = use_case
+        .execute(post_id, number, &auth_user.actor())
+        .await?;
+
+    Ok(Json(json!(response)))
+}
+
+/// Delete a post (author, moderators and admins)
+pub async fn delete_post_handler(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(post_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let use_case = DeletePostUseCase::new(state.post_repository);
+
+    use_case.execute(post_id, &auth_user.actor()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -5,27 +5,42 @@ mod config;
 mod handlers;
 mod middleware;
 
-use adapters::JwtAdapter;
+use adapters::{JwtAdapter, OpaqueTokenAdapter};
 use anyhow::Result;
+use application::posts::{BackfillPostSlugsUseCase, PublishScheduledPostsUseCase};
 use axum::{
-    middleware as axum_middleware,
-    routing::{get, post},
-    Router,
+    Router, middleware as axum_middleware,
+    routing::{get, post, put},
 };
 use config::Config;
-use handlers::{create_post_handler, get_post_handler, health_handler, list_posts_handler};
+use handlers::{
+    change_post_status_handler, create_post_handler, delete_post_handler,
+    diff_post_revisions_handler, get_post_by_slug_handler, get_post_handler, health_handler,
+    list_my_posts_handler, list_post_revisions_handler, list_posts_handler, list_tags_handler,
+    replace_post_handler, restore_post_revision_handler, search_posts_handler, update_post_handler,
+};
 use infrastructure::{
-    persistence::PostgresPostRepository,
-    security::JwtService,
+    persistence::{
+        PostgresApiKeyRepository, PostgresPostRepository, PostgresRevokedTokenRepository,
+        PostgresUserRepository,
+    },
+    security::{JwksClient, JwtKeySet, JwtService, OpaqueTokenService},
 };
-use middleware::auth_middleware;
+use middleware::{auth_middleware, optional_auth_middleware};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[derive(Clone)]
 pub struct AppState {
     post_repository: PostgresPostRepository,
+    user_repository: PostgresUserRepository,
     jwt_service: JwtAdapter,
+    revoked_tokens: PostgresRevokedTokenRepository,
+    api_keys: PostgresApiKeyRepository,
+    opaque_tokens: OpaqueTokenAdapter,
+    require_verified_email: bool,
+    max_page_size: i64,
 }
 
 #[tokio::main]
@@ -41,7 +56,10 @@ async fn main() -> Result<()> {
 
     // Load configuration
     let config = Config::from_env()?;
-    tracing::info!("Starting post service with config: {:?}", config.server_address());
+    tracing::info!(
+        "Starting post service with config: {:?}",
+        config.server_address()
+    );
 
     // Connect to database
     let pool = sqlx::PgPool::connect(&config.database_url)
@@ -58,29 +76,123 @@ async fn main() -> Result<()> {
 
     // Create infrastructure components
     let post_repository = PostgresPostRepository::new(pool.clone());
-    let jwt_service = JwtAdapter::new(Arc::new(JwtService::new(
-        config.jwt_secret.clone(),
-        24, // Default expiration hours
-    )));
+    let user_repository = PostgresUserRepository::new(pool.clone());
+    let revoked_tokens = PostgresRevokedTokenRepository::new(pool.clone());
+    let api_keys = PostgresApiKeyRepository::new(pool.clone());
+    let opaque_tokens = OpaqueTokenAdapter::new(Arc::new(OpaqueTokenService::new()));
+    let jwt_keys = JwtKeySet::default();
+    let jwt_service = JwtAdapter::new(Arc::new(JwtService::verifier(jwt_keys.clone())));
+
+    // Index and search in the configured language, re-indexing if it changed
+    if post_repository
+        .set_search_language(&config.search_language)
+        .await
+        .expect("Failed to set search language")
+    {
+        tracing::info!("Re-indexed posts for search in {}", config.search_language);
+    }
+
+    // Give posts from before slugs existed the slugs new posts get
+    let backfilled = BackfillPostSlugsUseCase::new(post_repository.clone())
+        .execute()
+        .await
+        .expect("Failed to give existing posts slugs");
+    if backfilled > 0 {
+        tracing::info!("Gave {} existing posts a slug", backfilled);
+    }
+
+    // Keep the verification keys in sync with the keys published by the auth service.
+    // Until the first fetch succeeds every token is rejected, so retry quickly.
+    let jwks_client = JwksClient::new(config.jwks_url.clone());
+    let refresh_interval = Duration::from_secs(config.jwks_refresh_seconds);
+    tokio::spawn(async move {
+        loop {
+            match jwks_client.refresh(&jwt_keys).await {
+                Ok(count) => tracing::debug!("Loaded {} token verification keys", count),
+                Err(e) => tracing::warn!("Failed to refresh token verification keys: {}", e),
+            }
+            let delay = if jwt_keys.is_empty() {
+                Duration::from_secs(5)
+            } else {
+                refresh_interval
+            };
+            tokio::time::sleep(delay).await;
+        }
+    });
+
+    // Publish scheduled posts as they come due
+    let publisher = PublishScheduledPostsUseCase::new(post_repository.clone());
+    let publish_interval = Duration::from_secs(config.scheduled_publish_interval_seconds);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(publish_interval);
+        loop {
+            interval.tick().await;
+            match publisher.execute().await {
+                Ok(0) => {}
+                Ok(published) => tracing::info!("Published {} scheduled posts", published),
+                Err(e) => tracing::warn!("Failed to publish scheduled posts: {}", e),
+            }
+        }
+    });
 
     // Create app state
     let state = AppState {
         post_repository,
+        user_repository,
         jwt_service,
+        revoked_tokens,
+        api_keys,
+        opaque_tokens,
+        require_verified_email: config.require_verified_email,
+        max_page_size: config.max_page_size,
     };
 
     // Build router with protected routes
     let protected_routes = Router::new()
         .route("/api/posts", post(create_post_handler))
+        .route("/api/posts/mine", get(list_my_posts_handler))
+        .route(
+            "/api/posts/{post_id}/status",
+            put(change_post_status_handler),
+        )
+        .route(
+            "/api/posts/{post_id}/revisions",
+            get(list_post_revisions_handler),
+        )
+        .route(
+            "/api/posts/{post_id}/revisions/diff",
+            get(diff_post_revisions_handler),
+        )
+        .route(
+            "/api/posts/{post_id}/revisions/{number}/restore",
+            post(restore_post_revision_handler),
+        )
+        .route(
+            "/api/posts/{post_id}",
+            put(replace_post_handler)
+                .patch(update_post_handler)
+                .delete(delete_post_handler),
+        )
         .route_layer(axum_middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
         ));
 
+    // Public routes that show authors more of their own posts
+    let viewer_routes = Router::new()
+        .route("/api/posts/{post_id}", get(get_post_handler))
+        .route("/api/posts/by-slug/{slug}", get(get_post_by_slug_handler))
+        .route_layer(axum_middleware::from_fn_with_state(
+            state.clone(),
+            optional_auth_middleware,
+        ));
+
     let app = Router::new()
         .route("/health", get(health_handler))
         .route("/api/posts", get(list_posts_handler))
-        .route("/api/posts/{post_id}", get(get_post_handler))
+        .route("/api/tags", get(list_tags_handler))
+        .route("/api/search", get(search_posts_handler))
+        .merge(viewer_routes)
         .merge(protected_routes)
         .with_state(state);
 
@@ -1,11 +1,16 @@
 // services/post/src/middleware/auth_middleware.rs
 
+use application::authorization::Actor;
+use application::users::AuthenticateApiKeyUseCase;
 use axum::{
     extract::{Request, State},
-    http::{header::AUTHORIZATION, StatusCode},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
     middleware::Next,
     response::Response,
 };
+use chrono::DateTime;
+use domain::common::error::DomainError;
+use domain::users::{ApiKeyScope, RevokedTokenRepository, Role};
 use uuid::Uuid;
 
 use crate::AppState;
@@ -14,42 +19,125 @@ use crate::AppState;
 #[derive(Clone, Debug)]
 pub struct AuthUser {
     pub user_id: Uuid,
-    pub email: String,
+    pub role: Role,
+    pub email_verified: bool,
 }
 
-/// Middleware to verify JWT token and extract user information
+impl AuthUser {
+    /// The user as seen by the authorization policy
+    pub fn actor(&self) -> Actor {
+        Actor::new(self.user_id, self.role, self.email_verified)
+    }
+}
+
+/// Middleware to authenticate the request and extract user information.
+/// Accepts an access token (`Bearer`) or a personal API key (`ApiKey`).
 pub async fn auth_middleware(
     State(state): State<AppState>,
     mut request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    let auth_user = authenticate(&state, request.headers()).await?;
+
+    // Insert the authenticated user into request extensions
+    request.extensions_mut().insert(auth_user);
+
+    Ok(next.run(request).await)
+}
+
+/// Middleware for public routes whose answer depends on who is asking, such as an
+/// author viewing their own drafts. Inserts `Option<AuthUser>`; requests without valid
+/// credentials are served as anonymous.
+pub async fn optional_auth_middleware(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let auth_user = authenticate(&state, request.headers()).await.ok();
+
+    request.extensions_mut().insert(auth_user);
+
+    next.run(request).await
+}
+
+async fn authenticate(state: &AppState, headers: &HeaderMap) -> Result<AuthUser, StatusCode> {
     // Extract the Authorization header
-    let auth_header = request
-        .headers()
+    let auth_header = headers
         .get(AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
         .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    // Check for Bearer token format
-    let token = auth_header
-        .strip_prefix("Bearer ")
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if let Some(token) = auth_header.strip_prefix("Bearer ") {
+        authenticate_token(state, token).await
+    } else if let Some(key) = auth_header.strip_prefix("ApiKey ") {
+        authenticate_api_key(state, key).await
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
 
+/// Verify an access token issued by the auth service
+async fn authenticate_token(state: &AppState, token: &str) -> Result<AuthUser, StatusCode> {
     // Verify the token
     let claims = state
         .jwt_service
         .verify_token(token)
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    // Parse user_id from claims
+    // Parse user_id and token id from claims
     let user_id = Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let token_id = Uuid::parse_str(&claims.jti).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let issued_at = DateTime::from_timestamp(claims.iat, 0).ok_or(StatusCode::UNAUTHORIZED)?;
+    let session_id = claims
+        .sid
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    // Insert the authenticated user into request extensions
-    let auth_user = AuthUser {
+    // Reject tokens revoked through logout, a revoked session or a password reset
+    let revoked = state
+        .revoked_tokens
+        .is_revoked(token_id, user_id, session_id, issued_at)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check token revocation: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if revoked {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(AuthUser {
         user_id,
-        email: claims.email,
-    };
-    request.extensions_mut().insert(auth_user);
+        role: claims.role,
+        email_verified: claims.email_verified,
+    })
+}
 
-    Ok(next.run(request).await)
+/// Look up a personal API key; it has to grant the `posts:write` scope
+async fn authenticate_api_key(state: &AppState, key: &str) -> Result<AuthUser, StatusCode> {
+    let use_case = AuthenticateApiKeyUseCase::new(
+        state.user_repository.clone(),
+        state.api_keys.clone(),
+        state.opaque_tokens.clone(),
+    );
+
+    let user = use_case
+        .execute(key, ApiKeyScope::PostsWrite)
+        .await
+        .map_err(|e| match e {
+            DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
+            e => {
+                tracing::error!("Failed to check API key: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })?;
+
+    Ok(AuthUser {
+        user_id: user.id,
+        role: user.role,
+        email_verified: user.email_verified,
+    })
 }
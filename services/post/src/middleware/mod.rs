@@ -2,4 +2,4 @@
 
 mod auth_middleware;
 
-pub use auth_middleware::{auth_middleware, AuthUser};
+pub use auth_middleware::{AuthUser, auth_middleware, optional_auth_middleware};